@@ -28,7 +28,11 @@ pub fn start_cli(global_frame: ScopeFrame<Variable>) {
         match readline {
             Ok(mut line) => {
                 line.push('\n');
-                match intprt.eval_line(&line) {
+                match intprt.eval_line(&line, |warnings, scope| {
+                    if let Err(e) = lu_error_reporting::report_to_term(warnings, scope) {
+                        eprintln!("Ups: An error happend, while printing errors: {}", e)
+                    }
+                }) {
                     Ok(_) => {
                         // Value will already be printed
                     }
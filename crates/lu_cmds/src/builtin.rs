@@ -1,14 +1,22 @@
+mod bg;
 mod cd;
+mod error;
+mod fg;
 mod is_set;
+mod jobs;
+mod kill;
 mod select;
 mod ty_of;
+mod wait;
 use std::rc::Rc;
 
 use lu_interpreter_structs::Command;
 use vec_rc::vec_rc;
 
 use crate::builtin::{
-    cd::CdBuiltin, is_set::IsSetBuiltin, select::SelectBuiltin, ty_of::TyOfBuiltin,
+    bg::BgBuiltin, cd::CdBuiltin, error::ErrorBuiltin, fg::FgBuiltin, is_set::IsSetBuiltin,
+    jobs::JobsBuiltin, kill::KillBuiltin, select::SelectBuiltin, ty_of::TyOfBuiltin,
+    wait::WaitBuiltin,
 };
 
 pub fn all_builtin_cmds() -> Vec<Rc<dyn Command>> {
@@ -16,6 +24,12 @@ pub fn all_builtin_cmds() -> Vec<Rc<dyn Command>> {
         TyOfBuiltin::new(),
         IsSetBuiltin::new(),
         SelectBuiltin::new(),
-        CdBuiltin::new()
+        CdBuiltin::new(),
+        ErrorBuiltin::new(),
+        JobsBuiltin::new(),
+        FgBuiltin::new(),
+        BgBuiltin::new(),
+        WaitBuiltin::new(),
+        KillBuiltin::new()
     ]
 }
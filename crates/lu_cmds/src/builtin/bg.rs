@@ -0,0 +1,62 @@
+use lu_interpreter_structs::job_table::JobState;
+use nix::sys::signal::Signal;
+
+use super::jobs::{job_id_arg, job_id_arg_sign, job_table_of};
+use crate::cmd_prelude::*;
+
+/// Continues a stopped job in the background
+#[derive(Debug, Clone)]
+pub struct BgBuiltin {
+    sign: Signature,
+}
+
+static BG_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Impure, lu_source_code_item!())]);
+
+impl BgBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![job_id_arg_sign()]);
+        BgBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for BgBuiltin {
+    fn name(&self) -> &str {
+        "bg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let job_id = job_id_arg(self, scope);
+        let job_table = job_table_of(scope);
+        let mut l_job_table = job_table.lock();
+        l_job_table.poll();
+
+        let job = l_job_table.find_job(job_id)?;
+        if job.state() == JobState::Stopped {
+            job.signal(Signal::SIGCONT)?;
+        }
+
+        Ok(Value::Nil)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &BG_BUILTIN_ATTRS
+    }
+}
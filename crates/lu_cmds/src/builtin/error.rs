@@ -0,0 +1,82 @@
+use lu_error::EvalErr;
+
+use crate::cmd_prelude::*;
+
+/// Raises a user defined error. The error ends the script, if not caught by a try-catch stmt
+#[derive(Debug, Clone)]
+pub struct ErrorBuiltin {
+    sign: Signature,
+}
+
+const MESSAGE_ARG: &str = "message";
+const KIND_ARG: &str = "kind";
+/// The kind of a raised error, if none is passed
+const DEFAULT_ERR_KIND: &str = "Error";
+static ERROR_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Pure, lu_source_code_item!())]);
+
+impl ErrorBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder.decl(lu_source_code_item!()).args(vec![
+            ArgSignature::req(
+                MESSAGE_ARG.to_string(),
+                ValueType::String,
+                lu_source_code_item!(-3),
+            ),
+            ArgSignature::opt(
+                KIND_ARG.to_string(),
+                ValueType::String,
+                lu_source_code_item!(-3),
+            ),
+        ]);
+        ErrorBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for ErrorBuiltin {
+    fn name(&self) -> &str {
+        "error"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let l_scope = scope.lock();
+        let message = self
+            .expect_arg(&l_scope, MESSAGE_ARG)
+            .coerce_to_string()
+            .unwrap()
+            .clone();
+        let kind = self
+            .expect_arg(&l_scope, KIND_ARG)
+            .expect_optional_inner_val()
+            .as_ref()
+            .map(|kind| kind.coerce_to_string().unwrap().clone())
+            .unwrap_or_else(|| DEFAULT_ERR_KIND.to_string());
+
+        Err(EvalErr::Raised {
+            kind,
+            message,
+            // The call site is filled in by the caller
+            item: None,
+        }
+        .into())
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &ERROR_BUILTIN_ATTRS
+    }
+}
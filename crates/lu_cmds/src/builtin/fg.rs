@@ -0,0 +1,67 @@
+use lu_interpreter_structs::job_table::JobState;
+use nix::sys::signal::Signal;
+
+use super::jobs::{job_id_arg, job_id_arg_sign, job_table_of, set_status_of_done_job};
+use crate::cmd_prelude::*;
+
+/// Continues a job in the foreground and waits for it
+#[derive(Debug, Clone)]
+pub struct FgBuiltin {
+    sign: Signature,
+}
+
+static FG_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Impure, lu_source_code_item!())]);
+
+impl FgBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![job_id_arg_sign()]);
+        FgBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for FgBuiltin {
+    fn name(&self) -> &str {
+        "fg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let job_id = job_id_arg(self, scope);
+        let job_table = job_table_of(scope);
+        let mut l_job_table = job_table.lock();
+        l_job_table.poll();
+
+        let job = l_job_table.find_job(job_id)?;
+        if job.state() == JobState::Stopped {
+            job.signal(Signal::SIGCONT)?;
+        }
+        let job_id = job.id;
+        if let JobState::Done(exit_code) = job.wait_in_foreground()? {
+            l_job_table.remove_done();
+            set_status_of_done_job(job_id, exit_code, scope)?;
+        }
+
+        Ok(Value::Nil)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &FG_BUILTIN_ATTRS
+    }
+}
@@ -0,0 +1,109 @@
+use lu_error::EvalErr;
+use lu_interpreter_structs::job_table::{job_strct_ty, job_strct_val, JobTable};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+use crate::cmd_prelude::*;
+
+/// Lists all jobs (cmds run in the background)
+#[derive(Debug, Clone)]
+pub struct JobsBuiltin {
+    sign: Signature,
+}
+
+static JOBS_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Impure, lu_source_code_item!())]);
+
+impl JobsBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .ret_arg(ArgSignature::req(
+                "jobs".into(),
+                ValueType::Array {
+                    inner_ty: Box::new(job_strct_ty()),
+                    inner_ty_decl: lu_source_code_item!(),
+                },
+                lu_source_code_item!(),
+            ));
+        JobsBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for JobsBuiltin {
+    fn name(&self) -> &str {
+        "jobs"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let job_table = job_table_of(scope);
+        let mut l_job_table = job_table.lock();
+        l_job_table.poll();
+        let jobs = l_job_table.jobs().iter().map(job_strct_val).collect();
+        // Like in other shells, finished jobs are only listed once
+        l_job_table.remove_done();
+
+        Ok(Value::new_array(jobs))
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &JOBS_BUILTIN_ATTRS
+    }
+}
+
+pub(super) const JOB_ID_ARG: &str = "job_id";
+
+/// The optional job id arg of the job control builtins
+pub(super) fn job_id_arg_sign() -> ArgSignature {
+    ArgSignature::opt(
+        JOB_ID_ARG.to_string(),
+        ValueType::Number,
+        lu_source_code_item!(),
+    )
+}
+
+/// Returns the passed job id (if any)
+pub(super) fn job_id_arg(cmd: &dyn Command, scope: &SyScope) -> Option<u32> {
+    cmd.expect_arg(&scope.lock(), JOB_ID_ARG)
+        .expect_optional_inner_val()
+        .as_ref()
+        .map(|id| id.as_number().expect("Always a number").into_inner() as u32)
+}
+
+pub(super) fn job_table_of(scope: &SyScope) -> Arc<Mutex<JobTable>> {
+    get_job_table(&scope.lock())
+        .expect("Job table is always present")
+        .clone()
+}
+
+/// Sets $status to the exit code of a done job. Errors if the exit code has been lost
+pub(super) fn set_status_of_done_job(
+    job_id: u32,
+    exit_code: Option<i32>,
+    scope: &mut SyScope,
+) -> LuResult<()> {
+    match exit_code {
+        Some(exit_code) => {
+            set_status_var(exit_code, &mut scope.lock());
+            Ok(())
+        }
+        None => {
+            Err(EvalErr::Message(format!("The exit status of job {} has been lost", job_id)).into())
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use lu_error::EvalErr;
+use lu_interpreter_structs::job_table::JobState;
+use nix::sys::signal::Signal;
+
+use super::jobs::{job_id_arg, job_id_arg_sign, job_table_of};
+use crate::cmd_prelude::*;
+
+/// Sends a signal (default: TERM) to all processes of a job
+#[derive(Debug, Clone)]
+pub struct KillBuiltin {
+    sign: Signature,
+}
+
+const SIGNAL_FLAG: &str = "signal";
+static KILL_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Impure, lu_source_code_item!())]);
+
+impl KillBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![job_id_arg_sign()])
+            .flags(vec![FlagSignature::opt(
+                Some(SIGNAL_FLAG.into()),
+                Some('s'),
+                ValueType::String,
+                lu_source_code_item!(-4),
+            )]);
+        KillBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for KillBuiltin {
+    fn name(&self) -> &str {
+        "kill"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let job_id = job_id_arg(self, scope);
+        let signal = match self.expect_arg(&scope.lock(), SIGNAL_FLAG) {
+            // Not passed flags are None
+            Value::Optional { val, .. } => val.as_ref().map(|val| val.to_string()),
+            val => Some(val.to_string()),
+        };
+        let signal = match signal {
+            Some(name) => {
+                let name = name.to_uppercase();
+                let name = if name.starts_with("SIG") {
+                    name
+                } else {
+                    format!("SIG{}", name)
+                };
+                Signal::from_str(&name)
+                    .map_err(|_| EvalErr::Message(format!("Unknown signal {}", name)))?
+            }
+            None => Signal::SIGTERM,
+        };
+
+        let job_table = job_table_of(scope);
+        let mut l_job_table = job_table.lock();
+        l_job_table.poll();
+        let job = l_job_table.find_job(job_id)?;
+        let was_stopped = job.state() == JobState::Stopped;
+        job.signal(signal)?;
+        // A stopped job only handles the signal, once it continues
+        if was_stopped && signal != Signal::SIGKILL {
+            job.signal(Signal::SIGCONT)?;
+        }
+
+        Ok(Value::Nil)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &KILL_BUILTIN_ATTRS
+    }
+}
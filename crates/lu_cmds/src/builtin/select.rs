@@ -95,7 +95,7 @@ impl Command for SelectBuiltin {
                     })
                     .collect();
 
-                Value::new_strct(l_gen_strct_decl.name.clone(), selected_vals)
+                Value::new_strct(gen_strct_decl.clone(), selected_vals)
             })
             .collect();
 
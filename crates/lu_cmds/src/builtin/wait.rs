@@ -0,0 +1,67 @@
+use lu_interpreter_structs::job_table::JobState;
+
+use super::jobs::{job_id_arg, job_id_arg_sign, job_table_of, set_status_of_done_job};
+use crate::cmd_prelude::*;
+
+/// Waits until the given job (or all jobs) are done. $status is set to the exit code of the
+/// last job waited for
+#[derive(Debug, Clone)]
+pub struct WaitBuiltin {
+    sign: Signature,
+}
+
+static WAIT_BUILTIN_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Impure, lu_source_code_item!())]);
+
+impl WaitBuiltin {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![job_id_arg_sign()]);
+        WaitBuiltin {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for WaitBuiltin {
+    fn name(&self) -> &str {
+        "wait"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        None
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let job_table = job_table_of(scope);
+        let mut l_job_table = job_table.lock();
+        let job_ids = match job_id_arg(self, scope) {
+            Some(job_id) => vec![job_id],
+            None => l_job_table.jobs().iter().map(|job| job.id).collect(),
+        };
+
+        let mut result = Ok(());
+        for job_id in job_ids {
+            if let JobState::Done(exit_code) = l_job_table.find_job(Some(job_id))?.wait()? {
+                result = result.and(set_status_of_done_job(job_id, exit_code, scope));
+            }
+        }
+        l_job_table.remove_done();
+
+        result.map(|_| Value::Nil)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &WAIT_BUILTIN_ATTRS
+    }
+}
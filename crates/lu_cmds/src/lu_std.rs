@@ -2,6 +2,8 @@ mod array;
 mod fs;
 mod iter_funcs;
 mod lu_native_std_mod;
+mod map;
+mod process;
 mod test;
 
 use log::debug;
@@ -11,7 +13,7 @@ pub(crate) use lu_native_std_mod::{LuNativeStdMod, LuRustStdMod, LuStdMod};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
-use crate::lu_std::{array::StdArrayMod, fs::StdFsMod};
+use crate::lu_std::{array::StdArrayMod, fs::StdFsMod, map::StdMapMod, process::StdProcessMod};
 
 use self::iter_funcs::IterFuncsMod;
 
@@ -21,6 +23,8 @@ static STD_MODULES: Lazy<HashMap<String, LuStdMod>> = Lazy::new(|| {
         LuStdMod::Native(Box::new(IterFuncsMod {})),
         LuStdMod::Rust(Box::new(StdArrayMod {})),
         LuStdMod::Rust(Box::new(StdFsMod {})),
+        LuStdMod::Rust(Box::new(StdMapMod {})),
+        LuStdMod::Rust(Box::new(StdProcessMod {})),
     ];
     for std_mod in std_mods.into_iter() {
         map.insert(std_mod.id(), std_mod);
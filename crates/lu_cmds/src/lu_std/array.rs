@@ -29,4 +29,8 @@ impl LuRustStdMod for StdArrayMod {
     fn strcts(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Strct>>> {
         vec![]
     }
+
+    fn enums(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Enum>>> {
+        vec![]
+    }
 }
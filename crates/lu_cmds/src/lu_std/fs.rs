@@ -2,7 +2,7 @@ mod ls;
 
 use crate::{
     cmd_prelude::*,
-    lu_std::fs::ls::{FsLsCmd, LS_ENTRY_STRCT},
+    lu_std::fs::ls::{FsLsCmd, ENTRY_KIND_ENUM, LS_ENTRY_STRCT},
 };
 
 use super::LuRustStdMod;
@@ -29,4 +29,8 @@ impl LuRustStdMod for StdFsMod {
     fn strcts(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Strct>>> {
         vec![LS_ENTRY_STRCT.clone()]
     }
+
+    fn enums(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Enum>>> {
+        vec![ENTRY_KIND_ENUM.clone()]
+    }
 }
@@ -29,14 +29,50 @@ pub(crate) static LS_ENTRY_STRCT: Lazy<Arc<RwLock<Strct>>> = Lazy::new(|| {
         LS_ENTRY_STRCT_NAME.into(),
         vec![
             StrctField::new(LS_ENTRY_FIELD_0.into(), ValueType::String, 0, decl.clone()),
-            StrctField::new(LS_ENTRY_FIELD_1.into(), ValueType::String, 1, decl.clone()),
+            StrctField::new(
+                LS_ENTRY_FIELD_1.into(),
+                ValueType::Enum(Arc::downgrade(&*ENTRY_KIND_ENUM)),
+                1,
+                decl.clone(),
+            ),
             StrctField::new(LS_ENTRY_FIELD_2.into(), ValueType::Number, 2, decl.clone()),
         ],
         decl,
+        super::FS_MOD_PATH.clone(),
     );
     Arc::new(RwLock::new(ls_entry_strct))
 });
 
+const ENTRY_KIND_ENUM_NAME: &str = "EntryKind";
+const ENTRY_KIND_FILE: &str = "File";
+const ENTRY_KIND_DIRECTORY: &str = "Directory";
+const ENTRY_KIND_SYMLINK: &str = "Symlink";
+const ENTRY_KIND_SYMLINK_FIELD_0: &str = "target";
+
+pub(crate) static ENTRY_KIND_ENUM: Lazy<Arc<RwLock<Enum>>> = Lazy::new(|| {
+    let decl = lu_source_code_item!(-1);
+    let entry_kind_enum = Enum::new(
+        ENTRY_KIND_ENUM_NAME.into(),
+        vec![
+            EnumVariant::new(ENTRY_KIND_FILE.into(), vec![], decl.clone()),
+            EnumVariant::new(ENTRY_KIND_DIRECTORY.into(), vec![], decl.clone()),
+            EnumVariant::new(
+                ENTRY_KIND_SYMLINK.into(),
+                vec![StrctField::new(
+                    ENTRY_KIND_SYMLINK_FIELD_0.into(),
+                    ValueType::FileName,
+                    0,
+                    decl.clone(),
+                )],
+                decl.clone(),
+            ),
+        ],
+        decl,
+        super::FS_MOD_PATH.clone(),
+    );
+    Arc::new(RwLock::new(entry_kind_enum))
+});
+
 impl FsLsCmd {
     pub fn new() -> Self {
         let ls_decl = lu_source_code_item!();
@@ -139,23 +175,32 @@ impl Command for FsLsCmd {
                 .expect("Pattern includes pwd")
                 .to_string();
             let md = path
-                .metadata()
+                .symlink_metadata()
                 .map_err(|e| EvalErr::Message(e.to_string()))?;
 
-            let path_type = if md.file_type().is_dir() {
-                "Directory"
-            } else if md.file_type().is_file() {
-                "File"
+            let path_type = if md.file_type().is_symlink() {
+                let target = path
+                    .read_link()
+                    .map_err(|e| EvalErr::Message(e.to_string()))?;
+                Value::new_enum(
+                    ENTRY_KIND_ENUM.clone(),
+                    ENTRY_KIND_SYMLINK.into(),
+                    vec![(
+                        ENTRY_KIND_SYMLINK_FIELD_0.into(),
+                        Value::FileName(target.display().to_string()),
+                    )],
+                )
+            } else if md.file_type().is_dir() {
+                Value::new_enum(ENTRY_KIND_ENUM.clone(), ENTRY_KIND_DIRECTORY.into(), vec![])
             } else {
-                "Symlink"
-            }
-            .to_string();
+                Value::new_enum(ENTRY_KIND_ENUM.clone(), ENTRY_KIND_FILE.into(), vec![])
+            };
 
             entries.push(Value::new_strct(
-                LS_ENTRY_STRCT_NAME.into(),
+                LS_ENTRY_STRCT.clone(),
                 vec![
                     (LS_ENTRY_FIELD_0.into(), path_name.into()),
-                    (LS_ENTRY_FIELD_1.into(), path_type.into()),
+                    (LS_ENTRY_FIELD_1.into(), path_type),
                     (LS_ENTRY_FIELD_2.into(), md.len().into()),
                 ],
             ))
@@ -25,6 +25,7 @@ pub(crate) trait LuRustStdMod: Send + Sync {
     }
     fn cmds(&self) -> Vec<Rc<dyn Command>>;
     fn strcts(&self) -> Vec<Arc<RwLock<Strct>>>;
+    fn enums(&self) -> Vec<Arc<RwLock<Enum>>>;
     fn frame(&self) -> ScopeFrame<Variable> {
         let self_mod_path = ModPath::StdPath(self.id());
         let modi = ModInfo::new_std_module(
@@ -41,6 +42,9 @@ pub(crate) trait LuRustStdMod: Send + Sync {
         for strct in self.strcts() {
             frame.insert_var(Variable::new_strct_decl_arc(strct));
         }
+        for enm in self.enums() {
+            frame.insert_var(Variable::new_enum_decl_arc(enm));
+        }
 
         frame
     }
@@ -0,0 +1,58 @@
+mod get;
+mod insert;
+mod keys;
+mod remove;
+mod values;
+
+use crate::cmd_prelude::*;
+use get::MapGetCmd;
+use insert::MapInsertCmd;
+use keys::MapKeysCmd;
+use remove::MapRemoveCmd;
+use values::MapValuesCmd;
+
+use vec_rc::vec_rc;
+
+use super::LuRustStdMod;
+
+static MAP_MOD_PATH: Lazy<ModPath> = Lazy::new(|| ModPath::StdPath("std:map".into()));
+
+const MAP_ARG_NAME: &str = "map";
+const KEY_ARG_NAME: &str = "key";
+
+/// The ty {str: T}
+fn generic_map_ty() -> ValueType {
+    ValueType::new_map(ValueType::Generic("T".to_string()), lu_source_code_item!())
+}
+
+pub(crate) struct StdMapMod {}
+
+impl LuRustStdMod for StdMapMod {
+    fn id(&self) -> String {
+        MAP_MOD_PATH.as_std_path().unwrap().clone()
+    }
+    fn rust_decl(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn uses(&self) -> Vec<ModPath> {
+        vec![]
+    }
+    fn cmds(&self) -> Vec<Rc<dyn Command>> {
+        vec_rc![
+            MapGetCmd::new(),
+            MapInsertCmd::new(),
+            MapRemoveCmd::new(),
+            MapKeysCmd::new(),
+            MapValuesCmd::new()
+        ]
+    }
+
+    fn strcts(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Strct>>> {
+        vec![]
+    }
+
+    fn enums(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Enum>>> {
+        vec![]
+    }
+}
@@ -0,0 +1,84 @@
+use crate::cmd_prelude::*;
+use lu_interpreter_structs::{ModPath, SyScope};
+use once_cell::sync::Lazy;
+
+use super::{generic_map_ty, KEY_ARG_NAME, MAP_ARG_NAME};
+
+#[derive(Debug, Clone)]
+pub struct MapGetCmd {
+    sign: Signature,
+}
+
+static GET_CMD_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Pure, lu_source_code_item!())]);
+
+impl MapGetCmd {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![
+                ArgSignature::req(
+                    MAP_ARG_NAME.into(),
+                    generic_map_ty(),
+                    lu_source_code_item!(-1),
+                ),
+                ArgSignature::req(
+                    KEY_ARG_NAME.into(),
+                    ValueType::String,
+                    lu_source_code_item!(-1),
+                ),
+            ])
+            .in_arg(ArgSignature::void(lu_source_code_item!()))
+            .ret_arg(ArgSignature::req(
+                "value".into(),
+                ValueType::new_optional(
+                    ValueType::Generic("T".to_string()),
+                    lu_source_code_item!(),
+                ),
+                lu_source_code_item!(-4),
+            ));
+
+        MapGetCmd {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for MapGetCmd {
+    fn name(&self) -> &str {
+        "get"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        Some(&super::MAP_MOD_PATH)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &GET_CMD_ATTRS
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let l_scope = scope.lock();
+        let key = self
+            .expect_arg(&l_scope, KEY_ARG_NAME)
+            .coerce_to_string()
+            .unwrap();
+        let map = self.expect_arg(&l_scope, MAP_ARG_NAME).as_map().unwrap();
+
+        let val = map.get(key).cloned();
+        let inner_ty = val
+            .as_ref()
+            .map(|val| val.get_ty())
+            .unwrap_or(ValueType::Unspecified);
+        Ok(Value::new_optional(inner_ty, val))
+    }
+}
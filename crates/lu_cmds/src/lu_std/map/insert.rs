@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use crate::cmd_prelude::*;
+use lu_interpreter_structs::{ModPath, SyScope};
+use once_cell::sync::Lazy;
+
+use super::{generic_map_ty, KEY_ARG_NAME, MAP_ARG_NAME};
+
+#[derive(Debug, Clone)]
+pub struct MapInsertCmd {
+    sign: Signature,
+}
+
+const VALUE_ARG_NAME: &str = "value";
+static INSERT_CMD_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Pure, lu_source_code_item!())]);
+
+impl MapInsertCmd {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        let map_arg_ty = ArgSignature::req(
+            MAP_ARG_NAME.into(),
+            generic_map_ty(),
+            lu_source_code_item!(-1),
+        );
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![
+                map_arg_ty.clone(),
+                ArgSignature::req(
+                    KEY_ARG_NAME.into(),
+                    ValueType::String,
+                    lu_source_code_item!(-1),
+                ),
+                ArgSignature::req(
+                    VALUE_ARG_NAME.into(),
+                    ValueType::Generic("T".to_string()),
+                    lu_source_code_item!(-1),
+                ),
+            ])
+            .in_arg(ArgSignature::void(lu_source_code_item!()))
+            .ret_arg(map_arg_ty);
+
+        MapInsertCmd {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for MapInsertCmd {
+    fn name(&self) -> &str {
+        "insert"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        Some(&super::MAP_MOD_PATH)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &INSERT_CMD_ATTRS
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let mut l_scope = scope.lock();
+        let key = self
+            .expect_arg(&l_scope, KEY_ARG_NAME)
+            .coerce_to_string()
+            .unwrap()
+            .clone();
+        let value = self.expect_arg(&l_scope, VALUE_ARG_NAME).clone();
+
+        if let Value::Map(map) = self.expect_mut_arg(&mut l_scope, MAP_ARG_NAME) {
+            Rc::make_mut(map).insert(key, value);
+            Ok(Value::Map(map.clone()))
+        } else {
+            unreachable!("MAP_ARG_NAME is of map type");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::cmd_prelude::*;
+use lu_interpreter_structs::{ModPath, SyScope};
+use once_cell::sync::Lazy;
+
+use super::{generic_map_ty, MAP_ARG_NAME};
+
+#[derive(Debug, Clone)]
+pub struct MapValuesCmd {
+    sign: Signature,
+}
+
+static VALUES_CMD_ATTRS: Lazy<Vec<CmdAttribute>> =
+    Lazy::new(|| vec![CmdAttribute::new(Pure, lu_source_code_item!())]);
+
+impl MapValuesCmd {
+    pub fn new() -> Self {
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(lu_source_code_item!())
+            .args(vec![ArgSignature::req(
+                MAP_ARG_NAME.into(),
+                generic_map_ty(),
+                lu_source_code_item!(-1),
+            )])
+            .in_arg(ArgSignature::void(lu_source_code_item!()))
+            .ret_arg(ArgSignature::req(
+                "values".into(),
+                ValueType::new_array(ValueType::Generic("T".to_string()), lu_source_code_item!()),
+                lu_source_code_item!(-1),
+            ));
+
+        MapValuesCmd {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for MapValuesCmd {
+    fn name(&self) -> &str {
+        "values"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        Some(&super::MAP_MOD_PATH)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &VALUES_CMD_ATTRS
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let l_scope = scope.lock();
+        let map = self.expect_arg(&l_scope, MAP_ARG_NAME).as_map().unwrap();
+        // Values are sorted by their key
+        let values = map.values().cloned().collect();
+        Ok(Value::new_array(values))
+    }
+}
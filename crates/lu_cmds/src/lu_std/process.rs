@@ -0,0 +1,36 @@
+mod complete;
+
+use crate::{
+    cmd_prelude::*,
+    lu_std::process::complete::{ProcessCompleteCmd, PROCESS_RESULT_STRCT},
+};
+
+use super::LuRustStdMod;
+
+static PROCESS_MOD_PATH: Lazy<ModPath> = Lazy::new(|| ModPath::StdPath("std:process".into()));
+
+pub(crate) struct StdProcessMod {}
+
+impl LuRustStdMod for StdProcessMod {
+    fn id(&self) -> String {
+        PROCESS_MOD_PATH.as_std_path().unwrap().clone()
+    }
+    fn rust_decl(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn uses(&self) -> Vec<ModPath> {
+        vec![]
+    }
+    fn cmds(&self) -> Vec<Rc<dyn Command>> {
+        vec_rc![ProcessCompleteCmd::new()]
+    }
+
+    fn strcts(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Strct>>> {
+        vec![PROCESS_RESULT_STRCT.clone()]
+    }
+
+    fn enums(&self) -> Vec<std::sync::Arc<parking_lot::RwLock<Enum>>> {
+        vec![]
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use crate::cmd_prelude::*;
+use lu_error::EvalErr;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+/// Runs an external cmd to completion and returns its stdout, stderr and exit code. Other than
+/// calling the cmd directly, a failing cmd is not an error
+#[derive(Debug, Clone)]
+pub struct ProcessCompleteCmd {
+    sign: Signature,
+}
+
+const CMD_VAR_ARG_NAME: &str = "cmd";
+static COMPLETE_CMD_ATTRS: Lazy<Vec<CmdAttribute>> = Lazy::new(|| {
+    vec![
+        CmdAttribute::new(PurityUnknown, lu_source_code_item!()),
+        // All flags are passed to the called cmd
+        CmdAttribute::new(DontParseArguments, lu_source_code_item!()),
+    ]
+});
+
+const PROCESS_RESULT_STRCT_NAME: &str = "ProcessResult";
+const PROCESS_RESULT_FIELD_0: &str = "stdout";
+const PROCESS_RESULT_FIELD_1: &str = "stderr";
+const PROCESS_RESULT_FIELD_2: &str = "exit_code";
+
+pub(crate) static PROCESS_RESULT_STRCT: Lazy<Arc<RwLock<Strct>>> = Lazy::new(|| {
+    let decl = lu_source_code_item!(-1);
+    let process_result_strct = Strct::new(
+        PROCESS_RESULT_STRCT_NAME.into(),
+        vec![
+            StrctField::new(
+                PROCESS_RESULT_FIELD_0.into(),
+                ValueType::String,
+                0,
+                decl.clone(),
+            ),
+            StrctField::new(
+                PROCESS_RESULT_FIELD_1.into(),
+                ValueType::String,
+                1,
+                decl.clone(),
+            ),
+            StrctField::new(
+                PROCESS_RESULT_FIELD_2.into(),
+                ValueType::Number,
+                2,
+                decl.clone(),
+            ),
+        ],
+        decl,
+        super::PROCESS_MOD_PATH.clone(),
+    );
+    Arc::new(RwLock::new(process_result_strct))
+});
+
+impl ProcessCompleteCmd {
+    pub fn new() -> Self {
+        let complete_decl = lu_source_code_item!();
+        let mut sign_builder = SignatureBuilder::default();
+        sign_builder
+            .decl(complete_decl.clone())
+            .var_arg(ArgSignature::req(
+                CMD_VAR_ARG_NAME.to_string(),
+                ValueType::Any,
+                complete_decl.clone(),
+            ))
+            .in_arg(ArgSignature::in_(ValueType::Any, complete_decl.clone()))
+            .ret_arg(ArgSignature::ret(
+                ValueType::Strct(Arc::downgrade(&*PROCESS_RESULT_STRCT)),
+                complete_decl,
+            ));
+
+        ProcessCompleteCmd {
+            sign: sign_builder.build().unwrap(),
+        }
+    }
+}
+
+impl Command for ProcessCompleteCmd {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.sign
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!()
+    }
+
+    fn parent_module(&self) -> Option<&ModPath> {
+        Some(&super::PROCESS_MOD_PATH)
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        &COMPLETE_CMD_ATTRS
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let (cmd_var, stdin) = {
+            let l_scope = scope.lock();
+            let cmd_var = l_scope.find_var(CMD_VAR_ARG_NAME).unwrap().clone();
+            let stdin = self.get_in(&l_scope).cloned().unwrap_or(Value::Nil);
+            (cmd_var, stdin)
+        };
+        let cmd_and_args = RunExternalCmd::args_to_strs(cmd_var.val.as_array().unwrap())?;
+        let (cmd_name, args) = match cmd_and_args.split_first() {
+            Some(cmd_and_args) => cmd_and_args,
+            None => {
+                return Err(EvalErr::Message("complete expects a cmd to run".into()).into());
+            }
+        };
+
+        let ext_cmd = RunExternalCmd::new(cmd_var.decl.clone(), cmd_name.clone());
+        let output = ext_cmd.run_captured(args, &stdin)?;
+        let exit_code = exit_code_of(&output.status);
+        set_status_var(exit_code, &mut scope.lock());
+
+        Ok(Value::new_strct(
+            PROCESS_RESULT_STRCT.clone(),
+            vec![
+                (
+                    PROCESS_RESULT_FIELD_0.into(),
+                    Value::String(String::from_utf8(output.stdout)?),
+                ),
+                (
+                    PROCESS_RESULT_FIELD_1.into(),
+                    Value::String(String::from_utf8(output.stderr)?),
+                ),
+                (
+                    PROCESS_RESULT_FIELD_2.into(),
+                    Value::Number((exit_code as f64).into()),
+                ),
+            ],
+        ))
+    }
+}
@@ -17,6 +17,7 @@ pub enum AstErr {
     Message(String),
     VarNotInScope(SourceCodeItem),
     StrctNotInScope(SourceCodeItem),
+    EnumNotInScope(SourceCodeItem),
     CmdNotInScope(SourceCodeItem),
     CantUseRelativeInclude(SourceCodeItem),
     PatternError {
@@ -35,6 +36,8 @@ pub enum AstErr {
         path: String,
         path_usage: SourceCodeItem,
     },
+    /// break or continue used outside of a for or while loop
+    LoopCtrlOutsideOfLoop(SourceCodeItem),
 }
 
 impl<S: Into<String>> From<S> for AstErr {
@@ -8,20 +8,32 @@ use codespan_reporting::term::termcolor::StandardStream;
 use codespan_reporting::term::{self, ColorArg};
 use std::ops::Range;
 
+use strum_macros::IntoStaticStr;
 use thiserror::Error;
 
 use crate::{LuErr, LuResult, SourceCodeItem};
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, IntoStaticStr)]
 pub enum EvalErr {
     Message(String),
     VarNotFound(SourceCodeItem),
     NotConvertibleToBool(SourceCodeItem),
+    NotConvertibleToString {
+        item: SourceCodeItem,
+        value_ty: String,
+    },
 
     SpawningExternalProcessFailed(SourceCodeItem, String),
     ExternalCmdStdinWriteErr(SourceCodeItem, String),
     ExternalCmdStdoutReadErr(SourceCodeItem, String),
-    ExternalCmdFailed(SourceCodeItem),
+    ExternalCmdFailed {
+        cmd: SourceCodeItem,
+        /// The exit code of the cmd. Like in other shells, a cmd killed by a signal has the
+        /// exit code 128 + signal
+        exit_code: i32,
+        /// The signal which killed the cmd (if any)
+        signal: Option<i32>,
+    },
 
     // Pseudo err to conveniently return execution. Does not print anything
     BadCast {
@@ -35,6 +47,141 @@ pub enum EvalErr {
         path: String,
         path_item: SourceCodeItem,
     },
+    /// Indexing or slicing an array out of its bounds
+    IndexOutOfBounds {
+        /// The (possibly negative) index as written by the user, saturated to the i64 range
+        index: i64,
+        item: SourceCodeItem,
+        len: usize,
+    },
+    /// The end of a slice lies before its start
+    SliceEndBeforeStart {
+        /// The (possibly negative) bounds as written by the user
+        end: i64,
+        item: SourceCodeItem,
+        start: i64,
+    },
+    /// The operator of expr can not be applied to values of the operand_tys
+    OperatorNotApplicable {
+        expr: SourceCodeItem,
+        operand_tys: Vec<String>,
+    },
+    /// Accessing a key, which is not present in a map
+    KeyNotFound {
+        item: SourceCodeItem,
+        key: String,
+    },
+    /// A range with a step of 0 would never end. Contains the step expr
+    RangeStepIsZero(SourceCodeItem),
+    /// Only external cmds can be run in the background. Contains the offending value/cmd
+    NotRunnableInBackground(SourceCodeItem),
+    /// Error raised by the user (through the `error` builtin)
+    Raised {
+        /// The call of the `error` builtin. Filled in, when the error leaves the cmd
+        item: Option<SourceCodeItem>,
+        kind: String,
+        message: String,
+    },
+}
+
+impl EvalErr {
+    /// The kind of the error. For user raised errors the user given kind, otherwise the name
+    /// of the variant
+    pub fn kind(&self) -> String {
+        match self {
+            EvalErr::Raised { kind, .. } => kind.clone(),
+            _ => {
+                let kind: &'static str = self.into();
+                kind.to_string()
+            }
+        }
+    }
+
+    /// A short human readable description of the error
+    pub fn message(&self) -> String {
+        match self {
+            EvalErr::Message(m) => m.clone(),
+            EvalErr::VarNotFound(item) => format!("Variable {} not found", item.content),
+            EvalErr::NotConvertibleToBool(_) => "Value is not convertible to bool".into(),
+            EvalErr::NotConvertibleToString { value_ty, .. } => format!(
+                "Value of type {} can not be interpolated into a string",
+                value_ty
+            ),
+            EvalErr::SpawningExternalProcessFailed(_, e) => {
+                format!("Could not spawn external command: {}", e)
+            }
+            EvalErr::ExternalCmdStdinWriteErr(_, e) => {
+                format!("Could not write to stdin of external cmd: {}", e)
+            }
+            EvalErr::ExternalCmdStdoutReadErr(_, e) => {
+                format!("Could not read from stdout of external cmd: {}", e)
+            }
+            EvalErr::ExternalCmdFailed {
+                exit_code, signal, ..
+            } => match signal {
+                Some(signal) => format!("External command was killed by signal {}", signal),
+                None => format!("External command failed with exit code {}", exit_code),
+            },
+            EvalErr::BadCast {
+                expected_ty,
+                value_ty,
+                ..
+            } => format!(
+                "Typecast failed. Value of type {} is not compatible with {}",
+                value_ty, expected_ty
+            ),
+            EvalErr::DbgAbort => "Abort through user intervention".into(),
+            EvalErr::PathIsNotDirectory { path, .. } => {
+                format!("Provided path {} is not a directory", path)
+            }
+            EvalErr::IndexOutOfBounds { index, len, .. } => format!(
+                "Index {} is out of bounds for array of length {}",
+                index, len
+            ),
+            EvalErr::SliceEndBeforeStart { start, end, .. } => format!(
+                "The end {} of the slice lies before its start {}",
+                end, start
+            ),
+            EvalErr::OperatorNotApplicable { operand_tys, .. } => format!(
+                "Operator can not be applied to values of type {}",
+                operand_tys.join(" and ")
+            ),
+            EvalErr::KeyNotFound { key, .. } => format!("Key {} is not present in map", key),
+            EvalErr::RangeStepIsZero(_) => "The step of a range must not be 0".into(),
+            EvalErr::NotRunnableInBackground(_) => {
+                "Only external commands can be run in the background".into()
+            }
+            EvalErr::Raised { message, .. } => message.clone(),
+        }
+    }
+
+    /// The place in the source code, where the error occurred (if known)
+    pub fn item(&self) -> Option<&SourceCodeItem> {
+        match self {
+            EvalErr::Message(_) | EvalErr::DbgAbort => None,
+            EvalErr::VarNotFound(item)
+            | EvalErr::NotConvertibleToBool(item)
+            | EvalErr::NotConvertibleToString { item, .. }
+            | EvalErr::SpawningExternalProcessFailed(item, _)
+            | EvalErr::ExternalCmdStdinWriteErr(item, _)
+            | EvalErr::ExternalCmdStdoutReadErr(item, _)
+            | EvalErr::ExternalCmdFailed { cmd: item, .. }
+            | EvalErr::NotRunnableInBackground(item)
+            | EvalErr::IndexOutOfBounds { item, .. }
+            | EvalErr::SliceEndBeforeStart { item, .. }
+            | EvalErr::KeyNotFound { item, .. }
+            | EvalErr::RangeStepIsZero(item)
+            | EvalErr::OperatorNotApplicable { expr: item, .. }
+            | EvalErr::BadCast {
+                cast_math_expr: item,
+                ..
+            }
+            | EvalErr::PathIsNotDirectory {
+                path_item: item, ..
+            } => Some(item),
+            EvalErr::Raised { item, .. } => item.as_ref(),
+        }
+    }
 }
 
 impl<S: Into<String>> From<S> for EvalErr {
@@ -37,6 +37,15 @@ pub enum LuErr {
     Internal(String),
 }
 
+impl LuErr {
+    pub fn is_warning(&self) -> bool {
+        match self {
+            LuErr::Ty(e) => e.is_warning(),
+            _ => false,
+        }
+    }
+}
+
 impl<E: Error> From<E> for LuErr {
     fn from(e: E) -> Self {
         LuErr::Internal(e.to_string())
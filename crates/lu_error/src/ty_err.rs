@@ -37,11 +37,23 @@ pub enum TyErr {
     ItemExpectedToBeStruct(SourceCodeItem),
     ItemExpectedToBeArray(SourceCodeItem),
     ItemExpectedToBeOptional(SourceCodeItem),
+    ItemExpectedToBeMap(SourceCodeItem),
     StructDoesNotHaveField {
         field_name: String,
         strct_decl: SourceCodeItem,
         usage: SourceCodeItem,
     },
+    /// A field of a struct (or enum variant) is not given in the ctor
+    StructMissesField {
+        field_name: String,
+        strct_decl: SourceCodeItem,
+        usage: SourceCodeItem,
+    },
+    EnumDoesNotHaveVariant {
+        enum_decl: SourceCodeItem,
+        usage: SourceCodeItem,
+        variant_name: String,
+    },
     FlagWithoutArgument(SourceCodeItem),
     PassingOfNotDeclaredFlag(SourceCodeItem),
     NotPassedRequiredFlag {
@@ -64,6 +76,87 @@ pub enum TyErr {
     SelectArgMustBeBareWordOrString {
         arg: SourceCodeItem,
     },
+    NotConvertibleToString {
+        found_ty: String,
+        item: SourceCodeItem,
+    },
+    /// The operator of expr can not be applied to values of the operand_tys
+    OperatorNotApplicable {
+        expr: SourceCodeItem,
+        operand_tys: Vec<String>,
+    },
+    /// The number of vars of a for stmt does not fit the iterated value. Maps are iterated
+    /// by key and value, arrays of structs by a single var or one var per field, all other
+    /// values by a single var
+    ForVarCountMismatch {
+        expected: usize,
+        for_stmt: SourceCodeItem,
+        iterated_ty: String,
+    },
+    /// A match over an optional neither covers None nor Some (Warning)
+    NonExhaustiveOptMatch {
+        match_stmt: SourceCodeItem,
+        missing_arm: String,
+    },
+    /// A destructuring let with a pattern, which does not match every value (e.g. `let Ip{a: 1}`)
+    RefutablePatternInLet {
+        pattern: SourceCodeItem,
+    },
+    /// A flag requiring an argument, which is not the last one of combined short flags
+    /// (e.g. `-nv 3` if `-n` requires an argument)
+    FlagWithArgumentNotLastInGroup(SourceCodeItem),
+    /// A value passed to a flag of type bool (`--verbose=true`)
+    ValueAssignedToSwitch(SourceCodeItem),
+    /// None of the overloads of the called cmd matches the passed arguments
+    NoMatchingOverload {
+        cmd_stmt: SourceCodeItem,
+        overloads: Vec<SourceCodeItem>,
+    },
+    /// More than one overload of the called cmd matches the passed arguments
+    AmbiguousOverloadCall {
+        cmd_stmt: SourceCodeItem,
+        matching_overloads: Vec<SourceCodeItem>,
+    },
+    /// Methods can only be implemented for strcts declared in the same module
+    ImplOfStrctOfOtherModule {
+        impl_stmt: SourceCodeItem,
+        strct_decl: SourceCodeItem,
+    },
+    /// Only vars, fields and array elements can be assigned to (not `$arr.1..3` or a method
+    /// call). Contains the offending expr
+    NotAssignable(SourceCodeItem),
+    /// A destructuring let with an array pattern, which binds more elements than the array
+    /// literal assigned to it has (e.g. `let [a b c] = [1 2]`)
+    ArrayPatternLongerThanArray {
+        arr: SourceCodeItem,
+        arr_len: usize,
+        pattern: SourceCodeItem,
+    },
+    /// A generic param of a fn, which has the name of a strct or enum in scope
+    /// (e.g. `fn first<Elem>` while `struct Elem` exists)
+    GenericShadowsTy {
+        generic: SourceCodeItem,
+        ty_decl: SourceCodeItem,
+    },
+    /// A func cast with `as` to a fn ty, which it is not compatible with
+    FnNotCastable {
+        cast_expr: SourceCodeItem,
+        expected_ty: String,
+        found_ty: String,
+    },
+    /// A generic of a fn, which the fn uses as a specific ty (e.g. `$a - 1` for `a: T`)
+    GenericUsedAsTy {
+        generic_decl: SourceCodeItem,
+        generic_name: String,
+        used_as_ty: String,
+    },
+}
+
+impl TyErr {
+    /// Warnings are reported, but don't stop the evaluation
+    pub fn is_warning(&self) -> bool {
+        matches!(self, TyErr::NonExhaustiveOptMatch { .. })
+    }
 }
 
 impl<S: Into<String>> From<S> for TyErr {
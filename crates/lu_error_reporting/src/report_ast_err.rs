@@ -22,6 +22,14 @@ pub(crate) fn ast_err_to_diagnostic(err: &AstErr) -> Diagnostic<usize> {
                 byte_range_of_item(&(strct_usage)),
             )
             .with_message("Struct not found")]),
+        AstErr::EnumNotInScope(enum_usage) => Diagnostic::error()
+            .with_message("Enum not in scope")
+            .with_code("E-Ast0009")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(enum_usage),
+                byte_range_of_item(enum_usage),
+            )
+            .with_message("Enum not found")]),
         AstErr::CmdNotInScope(cmd_usage) => Diagnostic::error()
             .with_message("Command not in scope")
             .with_code("E-Ast0004")
@@ -70,5 +78,13 @@ pub(crate) fn ast_err_to_diagnostic(err: &AstErr) -> Diagnostic<usize> {
                 f_id_of_item(&path_usage),
                 byte_range_of_item(&path_usage),
             )]),
+        AstErr::LoopCtrlOutsideOfLoop(stmt) => Diagnostic::error()
+            .with_message("break and continue can only be used within a loop")
+            .with_code("E-Ast0008")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(stmt),
+                byte_range_of_item(stmt),
+            )
+            .with_message("Used outside of a for or while loop")]),
     }
 }
@@ -12,6 +12,14 @@ pub(crate) fn eval_err_to_diagnostic(err: &EvalErr) -> Diagnostic<usize> {
         EvalErr::NotConvertibleToBool(_) => {
             todo!("This error should be caught in ty checking");
         }
+        EvalErr::NotConvertibleToString { item, value_ty } => Diagnostic::error()
+            .with_message("Value can not be interpolated into a string")
+            .with_code("E-Eval0011")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message(format!("Value is of type: {}", value_ty))]),
         EvalErr::SpawningExternalProcessFailed(term, err_message) => Diagnostic::error()
             .with_message(&format!(
                 "Could not spawn external command: {}",
@@ -45,12 +53,19 @@ pub(crate) fn eval_err_to_diagnostic(err: &EvalErr) -> Diagnostic<usize> {
                 byte_range_of_item(&(term)),
             )
             .with_message("External command called here")]),
-        EvalErr::ExternalCmdFailed(term) => Diagnostic::error()
-            .with_message("External command failed")
+        EvalErr::ExternalCmdFailed {
+            cmd,
+            exit_code,
+            signal,
+        } => Diagnostic::error()
+            .with_message(match signal {
+                Some(signal) => format!("External command was killed by signal {}", signal),
+                None => format!("External command failed with exit code {}", exit_code),
+            })
             .with_code("E-Eval0006")
             .with_labels(vec![Label::primary(
-                f_id_of_item(&term),
-                byte_range_of_item(&(term)),
+                f_id_of_item(cmd),
+                byte_range_of_item(cmd),
             )
             .with_message("External command called here")]),
         EvalErr::DbgAbort => Diagnostic::note().with_message("Abort through user intervention"),
@@ -79,5 +94,71 @@ pub(crate) fn eval_err_to_diagnostic(err: &EvalErr) -> Diagnostic<usize> {
                 f_id_of_item(&path_item),
                 byte_range_of_item(&path_item),
             )]),
+        EvalErr::IndexOutOfBounds { item, len, .. } => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0014")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message(format!("Array has {} elements", len))]),
+        EvalErr::SliceEndBeforeStart { item, .. } => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0018")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message("Slices can not be reversed")]),
+        EvalErr::OperatorNotApplicable { expr, operand_tys } => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0015")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(expr),
+                byte_range_of_item(expr),
+            )
+            .with_message(format!(
+                "Operands are of type: {}",
+                operand_tys.join(", ")
+            ))]),
+        EvalErr::KeyNotFound { item, .. } => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0016")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message("Key not found")]),
+        EvalErr::RangeStepIsZero(item) => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0017")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message("Step is 0")]),
+        EvalErr::NotRunnableInBackground(item) => Diagnostic::error()
+            .with_message(err.message())
+            .with_code("E-Eval0013")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message("Not an external command")]),
+        EvalErr::Raised {
+            kind,
+            message,
+            item,
+        } => Diagnostic::error()
+            .with_message(format!("{}: {}", kind, message))
+            .with_code("E-Eval0012")
+            .with_labels(
+                item.iter()
+                    .map(|item| {
+                        Label::primary(f_id_of_item(item), byte_range_of_item(item))
+                            .with_message("Error raised here")
+                    })
+                    .collect(),
+            ),
     }
 }
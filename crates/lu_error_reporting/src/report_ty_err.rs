@@ -1,5 +1,6 @@
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use lu_error::TyErr;
+use std::iter;
 
 use crate::{byte_range_of_item, f_id_of_item};
 pub(crate) fn ty_err_to_diagnostic(err: &TyErr) -> Diagnostic<usize> {
@@ -89,6 +90,14 @@ pub(crate) fn ty_err_to_diagnostic(err: &TyErr) -> Diagnostic<usize> {
                 byte_range_of_item(&(item)),
             )
             .with_message("Item declared here")]),
+        TyErr::ItemExpectedToBeMap(item) => Diagnostic::error()
+            .with_message("Statement expected to be a map")
+            .with_code("E-Ty0010")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message("Item declared here")]),
         TyErr::StructDoesNotHaveField {
             field_name,
             strct_decl,
@@ -102,6 +111,40 @@ pub(crate) fn ty_err_to_diagnostic(err: &TyErr) -> Diagnostic<usize> {
                 Label::secondary(f_id_of_item(&strct_decl), byte_range_of_item(&(strct_decl)))
                     .with_message("Struct declared here"),
             ]),
+        TyErr::NotAssignable(expr) => Diagnostic::error()
+            .with_message("Only variables, fields and array elements can be assigned to")
+            .with_code("E-Ty0072")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(expr),
+                byte_range_of_item(expr),
+            )
+            .with_message("Assigned to here")]),
+        TyErr::StructMissesField {
+            field_name,
+            strct_decl,
+            usage,
+        } => Diagnostic::error()
+            .with_message(format!("Field is not initialized: {}", field_name))
+            .with_code("E-Ty0031")
+            .with_labels(vec![
+                Label::primary(f_id_of_item(usage), byte_range_of_item(usage))
+                    .with_message("Field missing here"),
+                Label::secondary(f_id_of_item(strct_decl), byte_range_of_item(strct_decl))
+                    .with_message("Declared here"),
+            ]),
+        TyErr::EnumDoesNotHaveVariant {
+            enum_decl,
+            usage,
+            variant_name,
+        } => Diagnostic::error()
+            .with_message(format!("Enum does not contain variant: {}", variant_name))
+            .with_code("E-Ty0032")
+            .with_labels(vec![
+                Label::primary(f_id_of_item(usage), byte_range_of_item(usage))
+                    .with_message("Wrong enum usage here"),
+                Label::secondary(f_id_of_item(enum_decl), byte_range_of_item(enum_decl))
+                    .with_message("Enum declared here"),
+            ]),
         TyErr::FlagWithoutArgument(flag) => Diagnostic::error()
             .with_message("Flag used without providing the necessary argument")
             .with_code("E-Ty0040")
@@ -171,5 +214,171 @@ pub(crate) fn ty_err_to_diagnostic(err: &TyErr) -> Diagnostic<usize> {
                 f_id_of_item(&arg),
                 byte_range_of_item(&arg),
             )]),
+        TyErr::NotConvertibleToString { found_ty, item } => Diagnostic::error()
+            .with_message("Value can not be interpolated into a string")
+            .with_code("E-Ty0063")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(item),
+                byte_range_of_item(item),
+            )
+            .with_message(format!("Value is of type: {}", found_ty))]),
+        TyErr::OperatorNotApplicable { expr, operand_tys } => Diagnostic::error()
+            .with_message("Operator can not be applied to the operands")
+            .with_code("E-Ty0064")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(expr),
+                byte_range_of_item(expr),
+            )
+            .with_message(format!(
+                "Operands are of type: {}",
+                operand_tys.join(", ")
+            ))]),
+        TyErr::ForVarCountMismatch {
+            expected,
+            for_stmt,
+            iterated_ty,
+        } => Diagnostic::error()
+            .with_message(format!(
+                "Expected {} variable(s) to iterate over a value of type {}",
+                expected, iterated_ty
+            ))
+            .with_code("E-Ty0065")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(for_stmt),
+                byte_range_of_item(for_stmt),
+            )]),
+        TyErr::NonExhaustiveOptMatch {
+            match_stmt,
+            missing_arm,
+        } => Diagnostic::warning()
+            .with_message("Match over optional value misses an arm")
+            .with_code("W-Ty0001")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(match_stmt),
+                byte_range_of_item(match_stmt),
+            )
+            .with_message(format!("Arm for {} is missing", missing_arm))]),
+        TyErr::RefutablePatternInLet { pattern } => Diagnostic::error()
+            .with_message("Pattern of let must match every value")
+            .with_code("E-Ty0066")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(pattern),
+                byte_range_of_item(pattern),
+            )
+            .with_message("Pattern may not match")]),
+        TyErr::FlagWithArgumentNotLastInGroup(flag) => Diagnostic::error()
+            .with_message("Only the last of combined short flags may take an argument")
+            .with_code("E-Ty0067")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(flag),
+                byte_range_of_item(flag),
+            )
+            .with_message("Flags passed here")]),
+        TyErr::ValueAssignedToSwitch(flag) => Diagnostic::error()
+            .with_message("Value passed to a flag, which does not take an argument")
+            .with_code("E-Ty0068")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(flag),
+                byte_range_of_item(flag),
+            )
+            .with_message("Flag passed here")]),
+        TyErr::NoMatchingOverload {
+            cmd_stmt,
+            overloads,
+        } => Diagnostic::error()
+            .with_message("No overload of the command matches the passed arguments")
+            .with_code("E-Ty0069")
+            .with_labels(
+                iter::once(
+                    Label::primary(f_id_of_item(cmd_stmt), byte_range_of_item(cmd_stmt))
+                        .with_message("Command called here"),
+                )
+                .chain(overloads.iter().map(|overload| {
+                    Label::secondary(f_id_of_item(overload), byte_range_of_item(overload))
+                        .with_message("Overload declared here")
+                }))
+                .collect(),
+            ),
+        TyErr::AmbiguousOverloadCall {
+            cmd_stmt,
+            matching_overloads,
+        } => Diagnostic::error()
+            .with_message("More than one overload of the command matches the passed arguments")
+            .with_code("E-Ty0070")
+            .with_labels(
+                iter::once(
+                    Label::primary(f_id_of_item(cmd_stmt), byte_range_of_item(cmd_stmt))
+                        .with_message("Command called here"),
+                )
+                .chain(matching_overloads.iter().map(|overload| {
+                    Label::secondary(f_id_of_item(overload), byte_range_of_item(overload))
+                        .with_message("Matching overload declared here")
+                }))
+                .collect(),
+            ),
+        TyErr::ImplOfStrctOfOtherModule {
+            impl_stmt,
+            strct_decl,
+        } => Diagnostic::error()
+            .with_message("Methods can only be implemented in the module declaring the struct")
+            .with_code("E-Ty0071")
+            .with_labels(vec![
+                Label::primary(f_id_of_item(impl_stmt), byte_range_of_item(impl_stmt))
+                    .with_message("Methods implemented here"),
+                Label::secondary(f_id_of_item(strct_decl), byte_range_of_item(strct_decl))
+                    .with_message("Struct declared here"),
+            ]),
+        TyErr::ArrayPatternLongerThanArray {
+            arr,
+            arr_len,
+            pattern,
+        } => Diagnostic::error()
+            .with_message("Pattern of let binds more elements than the array has")
+            .with_code("E-Ty0073")
+            .with_labels(vec![
+                Label::primary(f_id_of_item(pattern), byte_range_of_item(pattern))
+                    .with_message("Pattern declared here"),
+                Label::secondary(f_id_of_item(arr), byte_range_of_item(arr))
+                    .with_message(format!("Array has {} elements", arr_len)),
+            ]),
+        TyErr::GenericShadowsTy { generic, ty_decl } => Diagnostic::error()
+            .with_message("Generic parameters can not have the name of a struct or enum")
+            .with_code("E-Ty0074")
+            .with_labels(vec![
+                Label::primary(f_id_of_item(generic), byte_range_of_item(generic))
+                    .with_message("Generic declared here"),
+                Label::secondary(f_id_of_item(ty_decl), byte_range_of_item(ty_decl))
+                    .with_message("Type declared here"),
+            ]),
+        TyErr::FnNotCastable {
+            cast_expr,
+            expected_ty,
+            found_ty,
+        } => Diagnostic::error()
+            .with_message("Function can not be cast to the function type")
+            .with_code("E-Ty0075")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(cast_expr),
+                byte_range_of_item(cast_expr),
+            )
+            .with_message(format!(
+                "Function of type {} is not compatible with {}",
+                found_ty, expected_ty
+            ))]),
+        TyErr::GenericUsedAsTy {
+            generic_decl,
+            generic_name,
+            used_as_ty,
+        } => Diagnostic::error()
+            .with_message("Generic is used as a specific type")
+            .with_code("E-Ty0076")
+            .with_labels(vec![Label::primary(
+                f_id_of_item(generic_decl),
+                byte_range_of_item(generic_decl),
+            )
+            .with_message(format!(
+                "{} stands for any type, but the function uses it as {}",
+                generic_name, used_as_ty
+            ))]),
     }
 }
@@ -1,5 +1,5 @@
 use lu_error::{FsErr, LuResult};
-use std::{fs, path::Path};
+use std::{fs, io::Write, path::Path};
 
 pub fn read_to_string<P: AsRef<Path>>(path: P) -> LuResult<String> {
     fs::read_to_string(path.as_ref())
@@ -12,3 +12,13 @@ pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> LuResult<(
         .map_err(|e| FsErr::WriteFailed(path.as_ref().into(), e.to_string()))
         .map_err(|e| e.into())
 }
+
+pub fn append<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> LuResult<()> {
+    fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path.as_ref())
+        .and_then(|mut file| file.write_all(contents.as_ref()))
+        .map_err(|e| FsErr::WriteFailed(path.as_ref().into(), e.to_string()))
+        .map_err(|e| e.into())
+}
@@ -6,15 +6,18 @@ use log::debug;
 use lu_error::{LuErr, LuResult, LuResults, SourceCodeItem};
 use lu_interpreter_structs::{EvalResult, RetValOrErr, SyScope, Value};
 
+mod background_stmt;
 mod block_stmt;
 mod cmd_stmt;
 mod condition;
+mod enum_stmt;
 mod eval_prelude;
 mod expr;
 mod fn_stmt;
 mod for_stmt;
 mod if_stmt;
 mod let_stmt;
+mod match_stmt;
 mod math_expr;
 mod piped_cmds_stmt;
 mod cls_expr;
@@ -24,6 +27,8 @@ mod statement;
 mod strct_stmt;
 mod table_expr;
 mod test;
+mod try_stmt;
+mod while_stmt;
 
 macro_rules! handle_dbg_intervention_before {
     ($dbg_result: ident, $scope: ident) => {{
@@ -64,6 +69,9 @@ pub enum EvalArg {
     ExternalCmdName(String),
     CmdInVal { val: Value, decl: SourceCodeItem },
     BlockNoPushFrame,
+    /// The value of the evaluated node is not used (only printed). External cmds may therefore
+    /// write directly to the terminal
+    OutputUnused,
 }
 
 /// The settings a Evaluable node can have
@@ -142,6 +150,9 @@ impl Evaluator {
                 unreachable!("Ret val ({:?}) should always be catched by fn_stmt", v)
             }
             RetValOrErr::Err(e) => e,
+            RetValOrErr::Break | RetValOrErr::Continue => {
+                unreachable!("break / continue outside of loop should be catched by ty checker")
+            }
         })
     }
 
@@ -0,0 +1,47 @@
+use crate::evaluate::eval_prelude::*;
+use crate::evaluate::piped_cmds_stmt::{as_streamable_external_cmd, spawn_piped};
+use lu_syntax::ast::{BackgroundStmtNode, PipeOrValueExprElement, ValueExprElement};
+use std::process::Child;
+
+impl Evaluable for BackgroundStmtNode {
+    fn dbg_settings(&self) -> &'static [DbgSetting] {
+        &[DbgSetting::StopDbgBeforeEval]
+    }
+
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let piped_args: Vec<ValueExprElement> = match self.stmt().unwrap() {
+            PipeOrValueExprElement::PipedCmdsStmt(n) => n.piped_args().collect(),
+            PipeOrValueExprElement::CmdStmt(n) => vec![ValueExprElement::CmdStmt(n)],
+            PipeOrValueExprElement::ValueExpr(n) => vec![n],
+        };
+        // Lush values can't be computed, while the script continues. Only external cmds can
+        let mut cmds = Vec::new();
+        for arg in &piped_args {
+            match as_streamable_external_cmd(arg, scope) {
+                Some(cmd) => cmds.push(cmd),
+                None => {
+                    return Err(LuErr::Eval(EvalErr::NotRunnableInBackground(arg.to_item())).into())
+                }
+            }
+        }
+
+        let mut children: Vec<Child> = Vec::new();
+        if let Err(e) = spawn_piped(&cmds, Value::Nil, true, true, &mut children, scope) {
+            for child in &mut children {
+                let _ = child.kill();
+            }
+            return Err(e);
+        }
+
+        let job_table = get_job_table(&scope.lock())
+            .expect("Job table is always present")
+            .clone();
+        let mut l_job_table = job_table.lock();
+        let job = l_job_table.add(self.stmt().unwrap().to_item().content, &children);
+        if !get_silence_stmt_returns(&scope.lock()).unwrap_or(false) {
+            eprintln!("[{}] {}", job.id, job.pgid);
+        }
+
+        Ok(Value::Nil)
+    }
+}
@@ -1,12 +1,22 @@
 use std::rc::Rc;
 
 use crate::evaluate::eval_prelude::*;
+use log::trace;
 use lu_interpreter_structs::Function;
 use lu_syntax::ast::ClosureExprNode;
 
 impl Evaluable for ClosureExprNode {
-    fn do_evaluate(&self, _: &[EvalArg], _: &mut SyScope) -> EvalResult {
-        let cls = Function::closure_from_node(self.clone().into());
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let mut cls = Function::closure_from_node(self.clone());
+        // Closures capture the referenced variables of their environment on creation
+        cls.captured_vars = scope
+            .lock()
+            .vars_captured_by_closure(&self.referenced_var_names());
+        trace!(
+            "Created closure {} with captured vars {:?}",
+            cls.name,
+            cls.captured_vars
+        );
         Ok(Value::Command(Rc::new(cls)))
     }
 }
@@ -3,7 +3,11 @@ use crate::special_cmds::{self, SELECT_CMD_NAME};
 use crate::{eval_function, evaluate::eval_prelude::*};
 use crate::{Command, RunExternalCmd};
 use lu_interpreter_structs::special_cmds::{MATH_FN_NAME, SELECT_DEF_STRCT_DECL_ARG_NAME};
-use lu_syntax::ast::{CmdArgElement, CmdStmtNode, HasAstId, MathExprNode};
+use lu_syntax::ast::{
+    CmdArgElement, CmdStmtNode, HasAstId, MathExprNode, RedirStmtNode, RedirToValueElement,
+    SignatureNode, ValueExprElement,
+};
+use lu_syntax_elements::constants::SELF_ARG_NAME;
 use std::rc::Rc;
 
 impl Evaluable for CmdStmtNode {
@@ -12,12 +16,20 @@ impl Evaluable for CmdStmtNode {
         // Fill those into CommandArgs struct and pass to cmd. For now we do something simple here
         let cmd_name = self.get_cmd_name();
         let passed_flags = FlagVariant::convert(self.get_passed_flags());
-        let cmd: Rc<dyn Command> =
-            if let Some(cmd) = scope.lock().find_func(&cmd_name, &passed_flags) {
-                cmd.clone()
+        let cmd: Rc<dyn Command> = if let Some(cmd) =
+            scope
+                .lock()
+                .find_called_func(&cmd_name, &passed_flags, &self.to_item())
+        {
+            cmd.clone()
+        } else {
+            let ext_cmd = RunExternalCmd::new(self.to_item(), cmd_name);
+            if eval_args.contains(&EvalArg::OutputUnused) && self.redir_stmt().is_none() {
+                ext_cmd.inheriting_tty().rced()
             } else {
-                RunExternalCmd::new(self.to_item(), cmd_name).rced()
-            };
+                ext_cmd.rced()
+            }
+        };
 
         let grouped_args = if cmd
             .find_attr(CmdAttributeVariant::DontParseArguments)
@@ -30,8 +42,10 @@ impl Evaluable for CmdStmtNode {
 
         // FROM HERE ONLY EVALUATION OF CMD FOLLOWS
         // REASON: Otherwise the following dbg_stmt may return this func to early
+        // Redirecting writes to a file or variable. Therefore it is always impure
         if cmd.find_attr(CmdAttributeVariant::Impure).is_some()
             || cmd.find_attr(CmdAttributeVariant::PurityUnknown).is_some()
+            || self.redir_stmt().is_some()
         {
             let dbg_result = lu_dbg::warn_unpure_cmd_call(&cmd, self.get_ast_id(), scope)?;
             handle_dbg_intervention_before!(dbg_result, scope);
@@ -43,11 +57,13 @@ impl Evaluable for CmdStmtNode {
         let prev_scope_frame = select_scope_to_run_cmd_in(&cmd, scope);
 
         let cmd_call_frame =
-            ScopeFrameTag::CmdCallFrame(cmd.name().to_string(), cmd.signature().req_flags());
+            ScopeFrameTag::CmdCallFrame(cmd.name().to_string(), cmd.signature().decl.clone());
         // Add a new frame
         scope.lock().push_frame(cmd_call_frame.clone());
 
-        insert_cmd_args_into_scope(cmd.signature(), eval_args, grouped_args, scope);
+        let sign_node = cmd.as_function().and_then(|func| func.fn_node.signature());
+        let args_inserted =
+            insert_cmd_args_into_scope(cmd.signature(), sign_node, eval_args, grouped_args, scope);
 
         if self.get_cmd_name() == SELECT_CMD_NAME {
             let mut l_scope = scope.lock();
@@ -68,11 +84,13 @@ impl Evaluable for CmdStmtNode {
 
         // And now we can finally run the cmd
         // See Function::run
-        let cmd_result = if let Some(func) = cmd.as_function() {
-            eval_function(func, scope)
-        } else {
-            cmd.run_cmd(scope)
-        };
+        let cmd_result = args_inserted.and_then(|_| {
+            if let Some(func) = cmd.as_function() {
+                eval_function(func, scope)
+            } else {
+                cmd.run_cmd(scope)
+            }
+        });
 
         // Cleanup in reverse order
 
@@ -83,7 +101,27 @@ impl Evaluable for CmdStmtNode {
             scope.lock().set_cur_frame_id(prev_scope_frame);
         }
 
-        Evaluator::lu_result_to_eval_result(cmd_result)
+        let cmd_result = match cmd_result {
+            // Errors raised by the user, point to the cmd raising them
+            Err(LuErr::Eval(EvalErr::Raised {
+                kind,
+                message,
+                item: None,
+            })) => Err(LuErr::Eval(EvalErr::Raised {
+                kind,
+                message,
+                item: Some(self.to_item()),
+            })),
+            _ => cmd_result,
+        };
+        let cmd_result = Evaluator::lu_result_to_eval_result(cmd_result)?;
+        if let Some(redir_stmt) = self.redir_stmt() {
+            Evaluator::lu_result_to_eval_result(redirect(cmd_result, self, &redir_stmt, scope))?;
+            // The result has been redirected. Nothing is returned
+            Ok(Value::Nil)
+        } else {
+            Ok(cmd_result)
+        }
     }
 
     fn dbg_settings(&self) -> &'static [DbgSetting] {
@@ -91,12 +129,103 @@ impl Evaluable for CmdStmtNode {
     }
 }
 
+/// Calls method with self_val as its in value (`$ip.to_string`). Methods called this way don't
+/// take any arguments
+pub(crate) fn eval_method_call(
+    method: &Rc<dyn Command>,
+    self_val: Value,
+    call: SourceCodeItem,
+    scope: &mut SyScope,
+) -> LuResult<Value> {
+    let func = method.as_function().expect("Methods are always functions");
+    let prev_scope_frame = select_scope_to_run_cmd_in(method, scope);
+
+    let cmd_call_frame =
+        ScopeFrameTag::CmdCallFrame(method.name().to_string(), method.signature().decl.clone());
+    scope.lock().push_frame(cmd_call_frame.clone());
+
+    let in_arg = [EvalArg::CmdInVal {
+        val: self_val,
+        decl: call,
+    }];
+    let grouped_args = GroupedArgs {
+        arg_vals: vec![],
+        flag_vals: vec![],
+    };
+    let result = insert_cmd_args_into_scope(
+        method.signature(),
+        func.fn_node.signature(),
+        &in_arg,
+        grouped_args,
+        scope,
+    )
+    .and_then(|_| eval_function(func, scope));
+
+    scope.lock().pop_frame(&cmd_call_frame);
+    if let Some(prev_scope_frame) = prev_scope_frame {
+        scope.lock().set_cur_frame_id(prev_scope_frame);
+    }
+    result
+}
+
+/// Writes `val` to the target of `redir_stmt`. Like output written to a terminal, the
+/// redirected output always ends with a newline
+fn redirect(
+    val: Value,
+    cmd_stmt: &CmdStmtNode,
+    redir_stmt: &RedirStmtNode,
+    scope: &mut SyScope,
+) -> LuResult<()> {
+    let redir_to = redir_stmt
+        .get_redir_to()
+        .expect("Always present if ty_checking works");
+    let mut output = match val.coerce_to_interpolated_string() {
+        Some(output) => output,
+        None => {
+            return Err(EvalErr::NotConvertibleToString {
+                item: cmd_stmt.to_item(),
+                value_ty: val.get_ty().to_string(),
+            }
+            .into())
+        }
+    };
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+
+    match redir_to {
+        RedirToValueElement::FileName(file_name) => {
+            let path = file_name.text_trimmed();
+            if redir_stmt.is_append() {
+                lu_fs::append(path, output)
+            } else {
+                lu_fs::write(path, output)
+            }
+        }
+        RedirToValueElement::ValuePathExpr(var_path) => {
+            let (var_name, usage) = var_path.var_name_with_item();
+            let mut l_scope = scope.lock();
+            let var = l_scope.expect_var_mut(&var_name, usage)?;
+            var.val = match &var.val {
+                Value::String(prev) if redir_stmt.is_append() => {
+                    Value::String(prev.clone() + &output)
+                }
+                _ => Value::String(output),
+            };
+            Ok(())
+        }
+    }
+}
+
+/// Inserts the passed args and flags into the cur frame. Not passed args and flags with
+/// a default value are evaluated
 fn insert_cmd_args_into_scope(
     cmd_sign: &Signature,
+    sign_node: Option<SignatureNode>,
     args: &[EvalArg],
     grouped_args: GroupedArgs,
     scope: &mut SyScope,
-) -> () {
+) -> LuResult<()> {
     let GroupedArgs {
         arg_vals,
         flag_vals,
@@ -104,15 +233,31 @@ fn insert_cmd_args_into_scope(
     // Insert $in if given (should normaly be the case if cmd_stmt.eval is called from
     // piped_cmds_stmt)
     if let Some((val, val_decl)) = args.iter().find_map(|arg| arg.as_cmd_in_val()) {
-        scope
-            .lock()
-            .get_cur_frame_mut()
-            .insert_var(Variable::new_in(val.clone(), val_decl.clone().into()));
+        let mut l_scope = scope.lock();
+        let frame = l_scope.get_cur_frame_mut();
+        frame.insert_var(Variable::new_in(val.clone(), val_decl.clone()));
+        // Methods refer to their in value as $self
+        if cmd_sign.in_arg.name == SELF_ARG_NAME {
+            frame.insert_var(Variable::new(
+                SELF_ARG_NAME.to_string(),
+                val.clone(),
+                val_decl.clone(),
+            ));
+        }
     };
 
+    let arg_nodes = sign_node
+        .as_ref()
+        .map(|sign_node| sign_node.args())
+        .unwrap_or_default();
     let mut arg_iter = arg_vals.into_iter();
-    for arg in &cmd_sign.args {
-        let val = if arg.is_opt {
+    for (i, arg) in cmd_sign.args.iter().enumerate() {
+        let val = if arg.has_default {
+            match arg_iter.next() {
+                Some(val) => val,
+                None => eval_default_value(arg_nodes[i].default_value(), scope)?,
+            }
+        } else if arg.is_opt {
             Value::new_optional(arg.ty.clone(), arg_iter.next())
         } else {
             arg_iter
@@ -139,7 +284,7 @@ fn insert_cmd_args_into_scope(
     }
 
     // Insert non passed flags
-    for flag_sign in &cmd_sign.flags {
+    for (i, flag_sign) in cmd_sign.flags.iter().enumerate() {
         // Skip already inserted flags
         if flag_vals
             .iter()
@@ -148,7 +293,13 @@ fn insert_cmd_args_into_scope(
             continue;
         }
         // Non passed switches (flags with ty bool) are inserted as false
-        let val = if flag_sign.ty == ValueType::Bool {
+        let val = if flag_sign.has_default {
+            let flag_node = sign_node
+                .as_ref()
+                .and_then(|sign_node| sign_node.flags().nth(i))
+                .expect("Flags with default value are declared in a signature");
+            eval_default_value(flag_node.default_value(), scope)?
+        } else if flag_sign.ty == ValueType::Bool {
             false.into()
         } else {
             Value::new_optional(flag_sign.ty.clone(), None)
@@ -168,6 +319,12 @@ fn insert_cmd_args_into_scope(
             .get_cur_frame_mut()
             .insert_var(Variable::new(flag_name, val, usage_item));
     }
+    Ok(())
+}
+
+fn eval_default_value(default: Option<ValueExprElement>, scope: &mut SyScope) -> LuResult<Value> {
+    let default = default.expect("has_default is only set if a default value is present");
+    Evaluator::eval_result_to_lu_result(default.evaluate(scope))
 }
 
 /// Returns the current frame id, iff another frame had to be selected to run cmd
@@ -211,21 +368,42 @@ macro_rules! insert_if_bool_flag_or_set_as_last_seen {
     }};
 }
 
+/// Evaluates the arguments of an external cmd and converts them to the strings passed to the
+/// spawned process
+pub(crate) fn eval_external_cmd_args(
+    cmd_stmt: &CmdStmtNode,
+    scope: &mut SyScope,
+) -> Result<Vec<String>, RetValOrErr> {
+    let GroupedArgs { arg_vals, .. } = evaluate_args_no_grouping(cmd_stmt.args(), scope)?;
+    Evaluator::lu_result_to_eval_result(RunExternalCmd::args_to_strs(&arg_vals))
+}
+
 // Evaluates all arguments withouth grouping arguments to flags
 // Flags are inserted as string value-arguments
 fn evaluate_args_no_grouping(
-    args: impl Iterator<Item = CmdArgElement>,
+    mut args: impl Iterator<Item = CmdArgElement>,
     scope: &mut SyScope,
 ) -> Result<GroupedArgs, RetValOrErr> {
     let mut arg_vals = vec![];
-    for arg in args {
+    while let Some(arg) = args.next() {
         match arg {
             CmdArgElement::ShortFlag(short_flag) => {
                 arg_vals.push(Value::String(short_flag.to_string()));
             }
+            CmdArgElement::LongFlag(long_flag) if long_flag.has_assigned_value() => {
+                // --flag=value is passed as one argument
+                let val = match args.next() {
+                    Some(CmdArgElement::ValueExpr(expr)) => expr.evaluate(scope)?,
+                    _ => unreachable!("Parser guarantees a value after the ="),
+                };
+                arg_vals.push(Value::String(format!("{}={}", long_flag, val)));
+            }
             CmdArgElement::LongFlag(long_flag) => {
                 arg_vals.push(Value::String(long_flag.to_string()));
             }
+            CmdArgElement::DoubleMinus(double_minus) => {
+                arg_vals.push(Value::String(double_minus.to_string()));
+            }
             CmdArgElement::ValueExpr(expr) => {
                 arg_vals.push(expr.evaluate(scope)?);
             }
@@ -261,14 +439,19 @@ fn evaluate_and_group_args(
                 );
             }
             CmdArgElement::ShortFlag(short_flag) => {
-                insert_if_bool_flag_or_set_as_last_seen!(
-                    flag_vals,
-                    last_seen_flag,
-                    cmd_flags,
-                    short_flag,
-                    |flag_sign| flag_sign.short_name.as_ref() == Some(&short_flag.flag_name())
-                );
+                // Combined short flags (-la) are handled one after another
+                for flag_name in short_flag.flag_names() {
+                    insert_if_bool_flag_or_set_as_last_seen!(
+                        flag_vals,
+                        last_seen_flag,
+                        cmd_flags,
+                        short_flag,
+                        |flag_sign| flag_sign.short_name == Some(flag_name)
+                    );
+                }
             }
+            // Only separates flags from args. Nothing to evaluate
+            CmdArgElement::DoubleMinus(_) => {}
             CmdArgElement::ValueExpr(n) => {
                 let expected_val_ty = last_seen_flag
                     .as_ref()
@@ -288,7 +471,7 @@ fn evaluate_and_group_args(
                     _ => n.evaluate(scope)?,
                 };
                 if let Some((flag_sign, passed_flag_decl)) = last_seen_flag.take() {
-                    let val = if flag_sign.is_opt {
+                    let val = if flag_sign.is_opt && !flag_sign.has_default {
                         Value::Optional {
                             inner_ty: flag_sign.ty.clone(),
                             val: Some(Box::new(val)),
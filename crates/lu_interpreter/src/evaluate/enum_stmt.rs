@@ -0,0 +1,26 @@
+use crate::evaluate::eval_prelude::*;
+use lu_syntax::ast::EnumCtorExprNode;
+
+impl Evaluable for EnumCtorExprNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let mut values = vec![];
+        for field in self.fields() {
+            let name = field.field_name();
+            let val = field.value().unwrap().evaluate(scope)?;
+
+            values.push((name, val))
+        }
+
+        let enum_name = self.enum_name();
+        let decl = scope
+            .lock()
+            .find_enum(enum_name.text())
+            .cloned()
+            .expect("Enum in scope. Checked by ty checker");
+        Ok(Value::new_enum(
+            decl,
+            self.variant_name().unwrap().text().to_string(),
+            values,
+        ))
+    }
+}
@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
+
 use lu_syntax::ast::{
-    ArrayExprNode, BareWordToken, BooleanExprNode, FileNameElement, NumberExprNode,
-    OptionalExprNode, StringExprNode, ValueExprElement, ValuePathExprNode,
+    ArrayExprNode, BareWordToken, BooleanExprNode, FileNameElement, MapExprNode, NumberExprNode,
+    OptionalExprNode, ParentherizedExprNode, RangeExprNode, StringExprNode, StringExprPartElement,
+    ValueExprElement, ValuePathExprNode, ValuePathPart,
 };
 
 use crate::evaluate::eval_prelude::*;
 
+use super::cmd_stmt::eval_method_call;
+
 impl Evaluable for ValueExprElement {
     fn do_evaluate(&self, args: &[EvalArg], scope: &mut SyScope) -> EvalResult {
         match self {
@@ -12,11 +17,16 @@ impl Evaluable for ValueExprElement {
             ValueExprElement::BareWord(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::NumberExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::MathExpr(n) => n.evaluate_with_args(args, scope),
+            ValueExprElement::UnaryExpr(n) => n.evaluate_with_args(args, scope),
+            ValueExprElement::RangeExpr(n) => n.evaluate_with_args(args, scope),
+            ValueExprElement::ParentherizedExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::StringExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::ValuePathExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::ArrayExpr(n) => n.evaluate_with_args(args, scope),
+            ValueExprElement::MapExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::TableExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::StrctCtorExpr(n) => n.evaluate_with_args(args, scope),
+            ValueExprElement::EnumCtorExpr(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::FileName(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::CmdStmt(n) => n.evaluate_with_args(args, scope),
             ValueExprElement::OptionalExpr(n) => n.evaluate_with_args(args, scope),
@@ -57,41 +67,169 @@ impl Evaluable for NumberExprNode {
 }
 
 impl Evaluable for StringExprNode {
-    fn do_evaluate(&self, _: &[EvalArg], _scope: &mut SyScope) -> EvalResult {
-        Ok(Value::String(self.text().to_string()))
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        if self.is_raw() {
+            return Ok(Value::String(self.value()));
+        }
+        let mut result = String::new();
+        for part in self.parts() {
+            let is_cmd_output = matches!(part, StringExprPartElement::ParentherizedExpr(_));
+            let (val, item) = match part {
+                StringExprPartElement::StringContent(content) => {
+                    result.push_str(&content.unescaped());
+                    continue;
+                }
+                StringExprPartElement::ValuePathExpr(n) => (n.evaluate(scope)?, n.to_item()),
+                StringExprPartElement::ParentherizedExpr(n) => (n.evaluate(scope)?, n.to_item()),
+            };
+            let val_str = match val.coerce_to_interpolated_string() {
+                Some(val_str) => val_str,
+                None => {
+                    return Err(LuErr::Eval(EvalErr::NotConvertibleToString {
+                        item,
+                        value_ty: val.get_ty().to_string(),
+                    })
+                    .into())
+                }
+            };
+            if is_cmd_output {
+                // Output of commands usually ends with a newline. Like other shells we strip it
+                result.push_str(val_str.strip_suffix('\n').unwrap_or(&val_str));
+            } else {
+                result.push_str(&val_str);
+            }
+        }
+        Ok(Value::String(result))
+    }
+}
+
+impl Evaluable for ParentherizedExprNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        match self.inner() {
+            Some(inner) => inner.evaluate(scope),
+            None => Ok(Value::Nil),
+        }
     }
 }
 
 impl Evaluable for ValuePathExprNode {
     fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
-        let name_parts = self.var_name_parts();
-        assert!(!name_parts.is_empty());
-        let l_scope = scope.lock();
-        let mut prev_var = l_scope
-            .find_var(&name_parts[0])
+        let (var_name, _) = self.var_name_with_item();
+        let mut prev_var = scope
+            .lock()
+            .find_var(&var_name)
             .expect("var always found")
             .val
             .clone();
 
-        for index_name in &name_parts[1..] {
-            // Its field indexing into a struct
-            let (_, strct_fields) = prev_var.as_strct().expect("Prev var must be strct");
-            prev_var = strct_fields
-                .iter()
-                .find_map(|(field_name, val)| {
-                    if field_name == index_name {
-                        Some(val.clone())
-                    } else {
-                        None
+        for (part, item) in self.parts_with_item() {
+            prev_var = match part {
+                ValuePathPart::Field(key) if prev_var.as_map().is_some() => {
+                    let map = prev_var.as_map().unwrap();
+                    match map.get(&key) {
+                        Some(val) => val.clone(),
+                        None => return Err(LuErr::Eval(EvalErr::KeyNotFound { item, key }).into()),
                     }
-                })
-                .expect("Index always works");
+                }
+                ValuePathPart::Field(field_name) => {
+                    // Its field indexing into a struct
+                    let (strct_decl, strct_fields) =
+                        prev_var.as_strct().expect("Prev var must be strct");
+                    let field_val = strct_fields.iter().find_map(|(name, val)| {
+                        if *name == field_name {
+                            Some(val.clone())
+                        } else {
+                            None
+                        }
+                    });
+                    match field_val {
+                        Some(field_val) => field_val,
+                        None => {
+                            // Or calling a method of the struct
+                            let method = scope
+                                .lock()
+                                .find_method(&strct_decl.read(), &field_name)
+                                .cloned()
+                                .expect("Either field or method. Checked by ty checker");
+                            let result = eval_method_call(&method, prev_var.clone(), item, scope);
+                            Evaluator::lu_result_to_eval_result(result)?
+                        }
+                    }
+                }
+                ValuePathPart::Index(index) => {
+                    let arr = prev_var.as_array().expect("Prev var must be array");
+                    let idx = array_idx(index, arr.len(), false, item)?;
+                    arr[idx].clone()
+                }
+                ValuePathPart::Slice { start, end } => {
+                    let arr = prev_var.as_array().expect("Prev var must be array");
+                    let end_idx = match end {
+                        Some(end) => array_idx(end, arr.len(), true, item.clone())?,
+                        None => arr.len(),
+                    };
+                    let start_idx = array_idx(start, arr.len(), true, item.clone())?;
+                    if end_idx < start_idx {
+                        return Err(LuErr::Eval(EvalErr::SliceEndBeforeStart {
+                            end: end.expect("Omitted end never lies before start"),
+                            item,
+                            start,
+                        })
+                        .into());
+                    }
+                    Value::new_array(arr[start_idx..end_idx].to_vec())
+                }
+            };
         }
 
         Ok(prev_var)
     }
 }
 
+/// Converts a (possibly negative) index into an index into an array of length len.
+/// If `is_slice_bound`, len itself is a valid index
+pub(crate) fn array_idx(
+    index: i64,
+    len: usize,
+    is_slice_bound: bool,
+    item: SourceCodeItem,
+) -> LuResult<usize> {
+    let idx = if index < 0 { len as i64 + index } else { index };
+    let max = if is_slice_bound {
+        len as i64
+    } else {
+        len as i64 - 1
+    };
+    if idx < 0 || idx > max {
+        Err(EvalErr::IndexOutOfBounds { index, item, len }.into())
+    } else {
+        Ok(idx as usize)
+    }
+}
+
+impl Evaluable for RangeExprNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let start = eval_range_bound(&self.start(), scope)?;
+        // Missing end has been reported by the parser
+        let end = eval_range_bound(&self.end().unwrap(), scope)?;
+        let step = match self.step() {
+            Some(step_expr) => {
+                let step = eval_range_bound(&step_expr, scope)?;
+                if step == 0f64 {
+                    return Err(LuErr::Eval(EvalErr::RangeStepIsZero(step_expr.to_item())).into());
+                }
+                step
+            }
+            None => 1f64,
+        };
+        Ok(Value::new_range(start, end, step))
+    }
+}
+
+fn eval_range_bound(bound: &ValueExprElement, scope: &mut SyScope) -> Result<f64, RetValOrErr> {
+    let val = bound.evaluate(scope)?;
+    Ok(val.as_number().expect("Checked by ty checker").0)
+}
+
 impl Evaluable for ArrayExprNode {
     fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
         let mut values = Vec::new();
@@ -102,6 +240,21 @@ impl Evaluable for ArrayExprNode {
     }
 }
 
+impl Evaluable for MapExprNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let mut entries = BTreeMap::new();
+        for entry in self.entries() {
+            let val = match entry.value() {
+                Some(val) => val.evaluate(scope)?,
+                None => Value::Nil, // Parse error already reported
+            };
+            // Later entries overwrite earlier ones with the same key
+            entries.insert(entry.key(), val);
+        }
+        Ok(Value::new_map(entries))
+    }
+}
+
 impl Evaluable for BooleanExprNode {
     fn do_evaluate(&self, _: &[EvalArg], _: &mut SyScope) -> EvalResult {
         Ok(self.value().into())
@@ -1,7 +1,7 @@
 use crate::evaluate::eval_prelude::*;
 use log::trace;
 use lu_interpreter_structs::Function;
-use lu_syntax::ast::{BlockStmtNode, FnStmtNode};
+use lu_syntax::ast::{BlockStmtNode, FnStmtNode, ImplStmtNode};
 
 pub fn eval_function(fn_stmt: &Function, scope: &mut SyScope) -> LuResult<Value> {
     trace!("Evaluating Function: {}", fn_stmt.name);
@@ -13,8 +13,12 @@ pub fn eval_function(fn_stmt: &Function, scope: &mut SyScope) -> LuResult<Value>
         CmdEvaluableNode::ClsExpr(cls_expr) => {
             {
                 let mut l_scope = scope.lock();
+                let frame = l_scope.get_cur_frame_mut();
                 for v in &fn_stmt.captured_vars {
-                    l_scope.get_cur_frame_mut().insert_var(v.clone());
+                    // Arguments of the closure shadow captured vars
+                    if frame.get(&v.name).is_none() {
+                        frame.insert_var(v.clone());
+                    }
                 }
             }
             return eval_fn_cls_block(cls_expr.block_stmt(), scope);
@@ -41,3 +45,10 @@ impl Evaluable for FnStmtNode {
         Ok(Value::Nil)
     }
 }
+
+impl Evaluable for ImplStmtNode {
+    fn do_evaluate(&self, _: &[EvalArg], _: &mut SyScope) -> EvalResult {
+        // Methods are evaluated like fn stmts through the Command trait
+        Ok(Value::Nil)
+    }
+}
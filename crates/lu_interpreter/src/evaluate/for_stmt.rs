@@ -1,4 +1,5 @@
 use crate::{evaluate::eval_prelude::*, handle_dbg_intervention_before};
+use itertools::Itertools;
 use lu_syntax::ast::{ForStmtNode, HasAstId};
 
 impl Evaluable for ForStmtNode {
@@ -23,26 +24,40 @@ impl Evaluable for ForStmtNode {
         handle_dbg_intervention_before!(result, scope);
 
         let iterated_val = iterated_val.evaluate(scope)?;
-        let vals_to_iterate = if let Some(array) = iterated_val.as_array() {
-            assert_eq!(var_names.len(), 1);
-            // TODO ret error
-            debug!("Iterating over array");
-            (**array).clone()
-        } else if let Some(str_to_iter) = iterated_val.as_string() {
-            assert_eq!(var_names.len(), 1);
-            // TODO ret error
-            debug!("Iterating over string {}", str_to_iter);
-            str_to_iter
-                .chars()
-                .map(|c| Value::String(c.into()))
-                .collect()
-        } else {
-            // Error
-            todo!()
-        };
+        // Each iteration binds one value per var. Values are produced on demand
+        let vals_to_iterate: Box<dyn Iterator<Item = Vec<Value>> + '_> =
+            if let Some(array) = iterated_val.as_array() {
+                debug!("Iterating over array");
+                if var_names.len() == 1 {
+                    Box::new(array.iter().map(|val| vec![val.clone()]))
+                } else {
+                    // Array of structs. Each var binds one field (checked by ty checker)
+                    let rows: Vec<_> = array.iter().map(field_vals_in_decl_order).collect();
+                    Box::new(rows.into_iter())
+                }
+            } else if let Some(str_to_iter) = iterated_val.as_string() {
+                assert_eq!(var_names.len(), 1);
+                // TODO ret error
+                debug!("Iterating over string {}", str_to_iter);
+                Box::new(str_to_iter.chars().map(|c| vec![Value::String(c.into())]))
+            } else if let Some(map) = iterated_val.as_map() {
+                assert_eq!(var_names.len(), 2, "Checked by ty checker");
+                debug!("Iterating over map");
+                Box::new(
+                    map.iter()
+                        .map(|(key, val)| vec![Value::String(key.clone()), val.clone()]),
+                )
+            } else if let Value::Range { start, end, step } = &iterated_val {
+                assert_eq!(var_names.len(), 1);
+                debug!("Iterating over range {}", iterated_val);
+                Box::new(range_iter(start.0, end.0, step.0).map(|n| vec![Value::Number(n.into())]))
+            } else {
+                // Error
+                todo!()
+            };
 
         // We entered the for loop. We need to push a new scope and set the vars
-        for (i, val) in vals_to_iterate.into_iter().enumerate() {
+        for (i, vals) in vals_to_iterate.into_iter().enumerate() {
             // We have to do before eval, before evaluating the iterated_val once. Therefore
             // the first iteration does not need before_eval
             if i != 0 {
@@ -50,12 +65,11 @@ impl Evaluable for ForStmtNode {
                 handle_dbg_intervention_before!(result, scope);
             }
             {
-                let var = Variable::new(var_names[0].clone(), val, self.to_item());
-                scope
-                    .lock()
-                    .push_frame(ScopeFrameTag::ForStmtFrame)
-                    .1
-                    .insert_var(var);
+                let mut l_scope = scope.lock();
+                let frame = l_scope.push_frame(ScopeFrameTag::ForStmtFrame).1;
+                for (var_name, val) in var_names.iter().zip(vals) {
+                    frame.insert_var(Variable::new(var_name.clone(), val, self.to_item()));
+                }
             }
             // We pushed ForStmtFrame, block doesn't need to push additional frame
             // Make sure we deallocate ForStmtFrame before returning
@@ -64,7 +78,11 @@ impl Evaluable for ForStmtNode {
                 let mut scope = scope.lock();
                 scope.pop_frame(&ScopeFrameTag::ForStmtFrame);
             }
-            eval_result?;
+            match eval_result {
+                Err(RetValOrErr::Break) => break,
+                Ok(_) | Err(RetValOrErr::Continue) => {}
+                Err(e) => return Err(e),
+            }
 
             lu_dbg::after_eval(&self.get_ast_id(), scope);
         }
@@ -72,3 +90,32 @@ impl Evaluable for ForStmtNode {
         Ok(Value::Nil)
     }
 }
+
+/// Returns the field values of the strct val ordered by their decl
+fn field_vals_in_decl_order(val: &Value) -> Vec<Value> {
+    let (decl, fields) = match val {
+        Value::Strct(decl, fields) => (decl, fields),
+        _ => unreachable!("Ty checker ensures rows are structs"),
+    };
+    // Ctor expressions keep the order as written by the user
+    let l_decl = decl.read();
+    fields
+        .iter()
+        .sorted_by_key(|(field_name, _)| {
+            l_decl
+                .fields
+                .iter()
+                .find(|field| &field.name == field_name)
+                .map(|field| field.field_num)
+        })
+        .map(|(_, val)| val.clone())
+        .collect()
+}
+
+/// Iterates from start (inclusive) to end (exclusive) by step. A negative step counts down
+fn range_iter(start: f64, end: f64, step: f64) -> impl Iterator<Item = f64> {
+    // Multiplying instead of summing up the steps avoids accumulating float errors
+    (0u64..)
+        .map(move |i| start + i as f64 * step)
+        .take_while(move |n| if step > 0f64 { *n < end } else { *n > end })
+}
@@ -1,19 +1,35 @@
 use crate::evaluate::eval_prelude::*;
 use lu_syntax::ast::LetStmtNode;
 
+use super::match_stmt::{array_pattern_matches, pattern_matches};
+
 impl Evaluable for LetStmtNode {
     fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
-        let var_name = self.var_name().unwrap();
         let val = if let Some(rhs) = self.value() {
             rhs.evaluate(scope)?
         } else {
             Value::Nil
         };
 
-        scope
-            .lock()
-            .get_cur_frame_mut()
-            .insert_var(Variable::new(var_name, val, self.to_item()));
+        let vars = if let Some(var_name) = self.var_name() {
+            vec![Variable::new(var_name, val, self.to_item())]
+        } else {
+            // Destructuring let. The typechecker ensures that the pattern matches
+            let mut bound_vars = Vec::new();
+            if let Some(pattern) = self.pattern() {
+                pattern_matches(&pattern, &val, &mut bound_vars, scope)?;
+            } else if let Some(pattern) = self.array_pattern() {
+                let arr = val.as_array().expect("Typechecker ensures val is an array");
+                array_pattern_matches(&pattern, arr, &mut bound_vars, scope)?;
+            }
+            bound_vars
+        };
+
+        let mut l_scope = scope.lock();
+        let frame = l_scope.get_cur_frame_mut();
+        for var in vars {
+            frame.insert_var(var);
+        }
 
         Ok(Value::Nil)
     }
@@ -0,0 +1,168 @@
+use crate::evaluate::eval_prelude::*;
+use lu_syntax::{
+    ast::{ArrayPatternNode, MatchPatternElement, MatchStmtNode, StrctFieldPatternNode},
+    AstNodeChildren,
+};
+use parking_lot::RwLock;
+
+impl Evaluable for MatchStmtNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let matched_val = self.matched_value().unwrap().evaluate(scope)?;
+
+        for arm in self.arms() {
+            let mut bound_vars = Vec::new();
+            if !pattern_matches(
+                &arm.pattern().unwrap(),
+                &matched_val,
+                &mut bound_vars,
+                scope,
+            )? {
+                continue;
+            }
+            debug!(
+                "Match arm {} matches {}",
+                arm.pattern().unwrap(),
+                matched_val
+            );
+
+            {
+                let mut l_scope = scope.lock();
+                let (_, frame) = l_scope.push_frame(ScopeFrameTag::MatchArmFrame);
+                for var in bound_vars {
+                    frame.insert_var(var);
+                }
+            }
+            // We pushed MatchArmFrame, block doesn't need to push additional frame
+            let result = arm
+                .block()
+                .unwrap()
+                .evaluate_with_args(&[EvalArg::BlockNoPushFrame], scope);
+            scope.lock().pop_frame(&ScopeFrameTag::MatchArmFrame);
+            result?;
+
+            // Only the first matching arm is evaluated
+            break;
+        }
+
+        Ok(Value::Nil)
+    }
+}
+
+/// Returns whether val matches the pattern. Vars bound by the pattern are added to bound_vars
+pub(crate) fn pattern_matches(
+    pattern: &MatchPatternElement,
+    val: &Value,
+    bound_vars: &mut Vec<Variable>,
+    scope: &mut SyScope,
+) -> Result<bool, RetValOrErr> {
+    let matches = match pattern {
+        MatchPatternElement::LiteralPattern(n) => n.value().unwrap().evaluate(scope)? == *val,
+        MatchPatternElement::BindingPattern(n) => {
+            if let Some(var_name) = n.var_name().filter(|_| !n.is_wildcard()) {
+                bound_vars.push(Variable::new(
+                    var_name.text().to_string(),
+                    val.clone(),
+                    var_name.to_item(),
+                ));
+            }
+            true
+        }
+        MatchPatternElement::NonePattern(_) => matches!(val, Value::Optional { val: None, .. }),
+        MatchPatternElement::SomePattern(n) => match val {
+            Value::Optional {
+                val: Some(inner_val),
+                ..
+            } => pattern_matches(&n.inner_pattern().unwrap(), inner_val, bound_vars, scope)?,
+            _ => false,
+        },
+        MatchPatternElement::StrctPattern(n) => match val {
+            Value::Strct(decl, fields) if is_strct_named(decl, n.name().unwrap().text(), scope) => {
+                fields_match(n.fields(), fields, bound_vars, scope)?
+            }
+            _ => false,
+        },
+        MatchPatternElement::EnumPattern(n) => match val {
+            Value::Enum {
+                variant, fields, ..
+            } if variant == n.variant_name().unwrap().text() => {
+                fields_match(n.fields(), fields, bound_vars, scope)?
+            }
+            _ => false,
+        },
+    };
+    Ok(matches)
+}
+
+/// Whether the strct decl is the one, which name refers to in the current scope
+fn is_strct_named(decl: &Arc<RwLock<Strct>>, name: &str, scope: &SyScope) -> bool {
+    let l_scope = scope.lock();
+    match l_scope.find_strct(name) {
+        Some(named_decl) => {
+            Arc::ptr_eq(decl, named_decl) || decl.read().is_same_strct_as(&named_decl.read())
+        }
+        None => false,
+    }
+}
+
+/// Binds the elements of arr to `[first ...rest]`. Vars bound by the pattern are added to
+/// bound_vars. Errors if arr has less elements than the pattern
+pub(crate) fn array_pattern_matches(
+    pattern: &ArrayPatternNode,
+    arr: &[Value],
+    bound_vars: &mut Vec<Variable>,
+    scope: &mut SyScope,
+) -> Result<bool, RetValOrErr> {
+    for (idx, elem) in pattern.elems().enumerate() {
+        let elem_val = match arr.get(idx) {
+            Some(elem_val) => elem_val,
+            None => {
+                return Err(LuErr::Eval(EvalErr::IndexOutOfBounds {
+                    index: idx as i64,
+                    item: elem.to_item(),
+                    len: arr.len(),
+                })
+                .into())
+            }
+        };
+        if !pattern_matches(&elem, elem_val, bound_vars, scope)? {
+            return Ok(false);
+        }
+    }
+    if let Some(var_name) = pattern.rest().and_then(|rest| rest.var_name()) {
+        let rest = arr.get(pattern.elems().count()..).unwrap_or_default();
+        bound_vars.push(Variable::new(
+            var_name.text().to_string(),
+            Value::new_array(rest.to_vec()),
+            var_name.to_item(),
+        ));
+    }
+    Ok(true)
+}
+
+/// Returns whether all field patterns match the fields of a struct (or enum variant)
+fn fields_match(
+    field_patterns: AstNodeChildren<StrctFieldPatternNode>,
+    fields: &[(String, Value)],
+    bound_vars: &mut Vec<Variable>,
+    scope: &mut SyScope,
+) -> Result<bool, RetValOrErr> {
+    for field in field_patterns {
+        let field_name = field.field_name();
+        let (_, field_val) = fields
+            .iter()
+            .find(|(name, _)| name == field_name.text())
+            .expect("Typechecker ensures field is present");
+        if let Some(field_pattern) = field.pattern() {
+            if !pattern_matches(&field_pattern, field_val, bound_vars, scope)? {
+                return Ok(false);
+            }
+        } else {
+            bound_vars.push(Variable::new(
+                field_name.text().to_string(),
+                field_val.clone(),
+                field_name.to_item(),
+            ));
+        }
+    }
+    Ok(true)
+}
@@ -1,5 +1,11 @@
 use crate::evaluate::eval_prelude::*;
-use lu_syntax::ast::{MathExprNode, OperatorExprElement, ValueExprElement};
+use lu_syntax::ast::{
+    MathExprNode, OperatorExprElement, UnaryExprNode, UnaryOperatorElement, ValueExprElement,
+    ValuePathPart,
+};
+use std::rc::Rc;
+
+use super::expr::array_idx;
 
 impl Evaluable for MathExprNode {
     fn dbg_settings(&self) -> &'static [DbgSetting] {
@@ -32,76 +38,47 @@ impl Evaluable for MathExprNode {
         }
 
         let lhs = self.lhs();
-        let rhs = self.rhs();
+        let rhs = self.rhs_safe().expect("Missing rhs is a parse error");
+        if let OperatorExprElement::AssignSign(_) = operator {
+            // The lhs is not evaluated, as it may not yet exist (`$map.new_key = 1`)
+            let rhs_val = rhs.evaluate(scope)?;
+            return assign(&lhs, rhs_val, scope);
+        }
         let lhs_val = lhs.evaluate(scope)?;
         let rhs_val = rhs.evaluate(scope)?;
 
         match operator {
-            OperatorExprElement::AsKeyword(_) => {
+            OperatorExprElement::AsKeyword(_) | OperatorExprElement::AssignSign(_) => {
                 unreachable!("Handled above")
             }
-            OperatorExprElement::AssignSign(_)
-            | OperatorExprElement::AddAssignSign(_)
+            OperatorExprElement::AddAssignSign(_)
             | OperatorExprElement::MinAssignSign(_)
             | OperatorExprElement::MulAssignSign(_)
             | OperatorExprElement::DivAssignSign(_) => {
-                let lhs_var = if let ValueExprElement::ValuePathExpr(e) = lhs {
-                    e
-                } else {
-                    todo!("Error out");
-                };
-                assert!(lhs_var.var_name_parts_with_item().len() == 1);
-                let (var_name, usage) = lhs_var.var_name_parts_with_item()[0].clone();
-                let mut l_scope = scope.lock();
-                let var =
-                    Evaluator::lu_result_to_eval_result(l_scope.expect_var_mut(&var_name, usage))?;
-
-                if let OperatorExprElement::AssignSign(_) = operator {
-                    // Simple asign
-                    var.val = rhs_val;
-                } else if let Value::Number(val) = &mut var.val {
-                    let rhs_val = rhs_val.as_number().unwrap();
-                    match operator {
-                        OperatorExprElement::AddAssignSign(_) => {
-                            *val += rhs_val;
-                        }
-                        OperatorExprElement::MinAssignSign(_) => {
-                            *val -= rhs_val;
-                        }
-                        OperatorExprElement::MulAssignSign(_) => {
-                            *val *= rhs_val;
-                        }
-                        OperatorExprElement::DivAssignSign(_) => {
-                            *val /= rhs_val;
-                        }
-                        _ => unreachable!(),
+                let new_val = match operator {
+                    OperatorExprElement::AddAssignSign(_) => {
+                        num_op(lhs_val, rhs_val, self, |l, r| l + r)
                     }
-                } else {
-                    unreachable!();
-                }
-
-                // Assignment does not return value
-                Ok(Value::Nil)
-            }
-            OperatorExprElement::PlusSign(_) => {
-                let l_val = lhs_val.as_number().unwrap();
-                let r_val = rhs_val.as_number().unwrap();
-                Ok(Value::Number(l_val + r_val))
-            }
-            OperatorExprElement::MinusSign(_) => {
-                let l_val = lhs_val.as_number().unwrap();
-                let r_val = rhs_val.as_number().unwrap();
-                Ok(Value::Number(l_val - r_val))
-            }
-            OperatorExprElement::MultSign(_) => {
-                let l_val = lhs_val.as_number().unwrap();
-                let r_val = rhs_val.as_number().unwrap();
-                Ok(Value::Number(l_val * r_val))
+                    OperatorExprElement::MinAssignSign(_) => {
+                        num_op(lhs_val, rhs_val, self, |l, r| l - r)
+                    }
+                    OperatorExprElement::MulAssignSign(_) => {
+                        num_op(lhs_val, rhs_val, self, |l, r| l * r)
+                    }
+                    OperatorExprElement::DivAssignSign(_) => {
+                        num_op(lhs_val, rhs_val, self, |l, r| l / r)
+                    }
+                    _ => unreachable!(),
+                }?;
+                assign(&lhs, new_val, scope)
             }
-            OperatorExprElement::DivSign(_) => {
-                let l_val = lhs_val.as_number().unwrap();
-                let r_val = rhs_val.as_number().unwrap();
-                Ok(Value::Number(l_val / r_val))
+            OperatorExprElement::PlusSign(_) => Ok(add(lhs_val, rhs_val, self)?),
+            OperatorExprElement::MinusSign(_) => Ok(num_op(lhs_val, rhs_val, self, |l, r| l - r)?),
+            OperatorExprElement::MultSign(_) => Ok(num_op(lhs_val, rhs_val, self, |l, r| l * r)?),
+            OperatorExprElement::DivSign(_) => Ok(num_op(lhs_val, rhs_val, self, |l, r| l / r)?),
+            OperatorExprElement::ModSign(_) => Ok(num_op(lhs_val, rhs_val, self, |l, r| l % r)?),
+            OperatorExprElement::PowSign(_) => {
+                Ok(num_op(lhs_val, rhs_val, self, |l, r| l.powf(r))?)
             }
             OperatorExprElement::LessThanSign(_) => Ok((lhs_val < rhs_val).into()),
             OperatorExprElement::LessOrEqualSign(_) => Ok((lhs_val <= rhs_val).into()),
@@ -118,3 +95,125 @@ impl Evaluable for MathExprNode {
         }
     }
 }
+
+impl Evaluable for UnaryExprNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let operand = self.operand().unwrap();
+        let val = operand.evaluate(scope)?;
+        match self.operator() {
+            UnaryOperatorElement::MinusSign(_) => match val {
+                Value::Number(n) => Ok(Value::Number(-n)),
+                _ => Err(LuErr::from(not_applicable(self, &[&val])).into()),
+            },
+            UnaryOperatorElement::NotKeyword(_) => match val.coerce_to_bool() {
+                Some(b) => Ok((!b).into()),
+                None => Err(LuErr::Eval(EvalErr::NotConvertibleToBool(operand.to_item())).into()),
+            },
+        }
+    }
+}
+
+/// Assigns new_val to the var, field or array element lhs refers to (`$x.inner.0 = 1`).
+/// Assignment does not return a value
+fn assign(lhs: &ValueExprElement, new_val: Value, scope: &mut SyScope) -> EvalResult {
+    let lhs = if let ValueExprElement::ValuePathExpr(e) = lhs {
+        e
+    } else {
+        unreachable!("Checked by ty checker")
+    };
+    let (var_name, usage) = lhs.var_name_with_item();
+    let mut l_scope = scope.lock();
+    let var = Evaluator::lu_result_to_eval_result(l_scope.expect_var_mut(&var_name, usage))?;
+    Evaluator::lu_result_to_eval_result(assign_to_path(
+        &mut var.val,
+        &lhs.parts_with_item(),
+        new_val,
+    ))?;
+
+    Ok(Value::Nil)
+}
+
+/// Assigns new_val to the value reached by following path from val. Every value on the way is
+/// only copied, if it is shared with another value (copy on write)
+fn assign_to_path(
+    val: &mut Value,
+    path: &[(ValuePathPart, SourceCodeItem)],
+    new_val: Value,
+) -> LuResult<()> {
+    let ((part, item), rest) = match path.split_first() {
+        Some(first_and_rest) => first_and_rest,
+        None => {
+            *val = new_val;
+            return Ok(());
+        }
+    };
+    let inner = match (part, val) {
+        (ValuePathPart::Field(key), Value::Map(map)) => {
+            let map = Rc::make_mut(map);
+            if rest.is_empty() {
+                map.insert(key.clone(), new_val);
+                return Ok(());
+            }
+            match map.get_mut(key) {
+                Some(inner) => inner,
+                None => {
+                    return Err(EvalErr::KeyNotFound {
+                        item: item.clone(),
+                        key: key.clone(),
+                    }
+                    .into())
+                }
+            }
+        }
+        (ValuePathPart::Field(field_name), Value::Strct(_, fields)) => Rc::make_mut(fields)
+            .iter_mut()
+            .find(|(name, _)| name == field_name)
+            .map(|(_, field_val)| field_val)
+            .expect("Only fields are assignable. Checked by ty checker"),
+        (ValuePathPart::Index(index), Value::Array(arr)) => {
+            let arr = Rc::make_mut(arr);
+            let idx = array_idx(*index, arr.len(), false, item.clone())?;
+            &mut arr[idx]
+        }
+        _ => unreachable!("Checked by ty checker"),
+    };
+    assign_to_path(inner, rest, new_val)
+}
+
+/// `+` adds numbers and concatenates strings and arrays
+fn add(lhs: Value, rhs: Value, expr: &MathExprNode) -> LuResult<Value> {
+    match (lhs, rhs) {
+        (Value::Number(l), Value::Number(r)) => Ok(Value::Number(l + r)),
+        (Value::String(l) | Value::BareWord(l), Value::String(r) | Value::BareWord(r)) => {
+            Ok(Value::String(l + &r))
+        }
+        (Value::Array(mut l), Value::Array(r)) => {
+            Rc::make_mut(&mut l).extend(r.iter().cloned());
+            Ok(Value::Array(l))
+        }
+        (l, r) => Err(not_applicable(expr, &[&l, &r]).into()),
+    }
+}
+
+/// Applies op to lhs and rhs, which have to be numbers
+fn num_op(
+    lhs: Value,
+    rhs: Value,
+    expr: &MathExprNode,
+    op: impl Fn(f64, f64) -> f64,
+) -> LuResult<Value> {
+    match (&lhs, &rhs) {
+        (Value::Number(l), Value::Number(r)) => Ok(Value::Number(op(l.0, r.0).into())),
+        _ => Err(not_applicable(expr, &[&lhs, &rhs]).into()),
+    }
+}
+
+fn not_applicable(expr: &impl AstNode, operands: &[&Value]) -> EvalErr {
+    EvalErr::OperatorNotApplicable {
+        expr: expr.to_item(),
+        operand_tys: operands
+            .iter()
+            .map(|val| val.get_ty().to_string())
+            .collect(),
+    }
+}
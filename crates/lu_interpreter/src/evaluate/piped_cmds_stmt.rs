@@ -1,25 +1,190 @@
+use crate::evaluate::cmd_stmt::eval_external_cmd_args;
 use crate::evaluate::eval_prelude::*;
+use crate::{exit_code_of, external_cmd_failed_err, RunExternalCmd};
 use lu_error::lu_source_code_item;
-use lu_syntax::ast::{PipeOrValueExprElement, PipedCmdsStmtNode};
+use lu_syntax::ast::{CmdStmtNode, PipeOrValueExprElement, PipedCmdsStmtNode, ValueExprElement};
+use std::io::{Read, Write};
+use std::process::{Child, Stdio};
 
 impl Evaluable for PipedCmdsStmtNode {
-    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+    fn do_evaluate(&self, args: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let output_unused = args.contains(&EvalArg::OutputUnused);
         let (mut prev_val, mut prev_val_decl) = (Value::Nil, lu_source_code_item!()); // The first cmd does not have input
-        for cmd in self.piped_args() {
-            prev_val = cmd.evaluate_with_args(
-                &[EvalArg::CmdInVal {
+        let piped_args: Vec<_> = self.piped_args().collect();
+        let mut i = 0;
+        while i < piped_args.len() {
+            // Adjacent external cmds are connected by os pipes and run at the same time
+            let ext_cmds: Vec<_> = piped_args[i..]
+                .iter()
+                .map_while(|arg| as_streamable_external_cmd(arg, scope))
+                .collect();
+            let is_last = i + ext_cmds.len() == piped_args.len();
+            if ext_cmds.len() >= 2 {
+                prev_val =
+                    run_external_cmds_piped(&ext_cmds, prev_val, output_unused && is_last, scope)?;
+                prev_val_decl = ext_cmds.last().unwrap().to_item();
+                i += ext_cmds.len();
+            } else {
+                let cmd = &piped_args[i];
+                let mut cmd_args = vec![EvalArg::CmdInVal {
                     val: prev_val,
                     decl: prev_val_decl,
-                }],
-                scope,
-            )?;
-            prev_val_decl = cmd.to_item().into();
+                }];
+                if output_unused && i + 1 == piped_args.len() {
+                    cmd_args.push(EvalArg::OutputUnused);
+                }
+                prev_val = cmd.evaluate_with_args(&cmd_args, scope)?;
+                prev_val_decl = cmd.to_item();
+                i += 1;
+            }
         }
 
         Ok(prev_val)
     }
 }
 
+/// Returns the cmd stmt, iff it calls an external cmd, whose output can be streamed to the next
+/// external cmd. While debugging, all cmds are run one after another, so that each one can be
+/// inspected
+pub(crate) fn as_streamable_external_cmd(
+    arg: &ValueExprElement,
+    scope: &mut SyScope,
+) -> Option<CmdStmtNode> {
+    let cmd_stmt = arg.as_cmd_stmt()?;
+    let l_scope = scope.lock();
+    if cmd_stmt.redir_stmt().is_some() || get_dbg_session(&l_scope).is_some() {
+        return None;
+    }
+    let passed_flags = FlagVariant::convert(cmd_stmt.get_passed_flags());
+    if l_scope
+        .find_func(&cmd_stmt.get_cmd_name(), &passed_flags)
+        .is_some()
+    {
+        return None;
+    }
+    Some(cmd_stmt.clone())
+}
+
+/// Runs all `cmds` at the same time, with the stdout of each cmd connected to the stdin of the next
+/// one. Only the output of the last cmd is collected, or written to the terminal if
+/// `output_unused`. Like in other shells, only the exit status of the last cmd is taken into
+/// account
+fn run_external_cmds_piped(
+    cmds: &[CmdStmtNode],
+    input: Value,
+    output_unused: bool,
+    scope: &mut SyScope,
+) -> EvalResult {
+    let mut children: Vec<Child> = Vec::new();
+    let result = spawn_piped(cmds, input, output_unused, false, &mut children, scope);
+    let result = result.and_then(|_| {
+        let mut raw_output = Vec::new();
+        let mut last_stdout = match children.last_mut().unwrap().stdout.take() {
+            Some(stdout) => stdout,
+            None => return Ok(None), // Output is written to the terminal
+        };
+        last_stdout
+            .read_to_end(&mut raw_output)
+            .map_err(|e| {
+                let last_cmd = cmds.last().unwrap().to_item();
+                LuErr::Eval(EvalErr::ExternalCmdStdoutReadErr(
+                    last_cmd,
+                    format!("{:?}", e),
+                ))
+                .into()
+            })
+            .map(|_| Some(raw_output))
+    });
+    if result.is_err() {
+        for child in &mut children {
+            let _ = child.kill();
+        }
+    }
+
+    let mut last_status = None;
+    for child in &mut children {
+        last_status = Some(child.wait());
+    }
+
+    let raw_output = result?;
+    let last_cmd = cmds.last().unwrap().to_item();
+    let last_status = match last_status.expect("At least one cmd has been spawned") {
+        Ok(status) => status,
+        Err(e) => {
+            return Err(LuErr::Eval(EvalErr::ExternalCmdStdoutReadErr(
+                last_cmd,
+                format!("{:?}", e),
+            ))
+            .into())
+        }
+    };
+    set_status_var(exit_code_of(&last_status), &mut scope.lock());
+    if !last_status.success() {
+        return Err(external_cmd_failed_err(last_cmd, &last_status).into());
+    }
+
+    match raw_output {
+        Some(raw_output) => {
+            let raw_output = Evaluator::lu_result_to_eval_result(
+                String::from_utf8(raw_output).map_err(|e| e.into()),
+            )?;
+            Ok(Value::BareWord(raw_output))
+        }
+        None => Ok(Value::Nil),
+    }
+}
+
+/// Spawns all `cmds` and pushes them to `children`. The stdin of the first cmd gets `input`.
+/// Cmds spawned in the `background` share a new process group
+pub(crate) fn spawn_piped(
+    cmds: &[CmdStmtNode],
+    input: Value,
+    inherit_last_stdout: bool,
+    background: bool,
+    children: &mut Vec<Child>,
+    scope: &mut SyScope,
+) -> Result<(), RetValOrErr> {
+    for (idx, cmd_stmt) in cmds.iter().enumerate() {
+        let args = eval_external_cmd_args(cmd_stmt, scope)?;
+        let stdin = match children.last_mut() {
+            Some(prev_child) => Stdio::from(prev_child.stdout.take().expect("Stdout is piped")),
+            None if input.is_nil() => Stdio::null(),
+            None => Stdio::piped(),
+        };
+        let mut ext_cmd = RunExternalCmd::new(cmd_stmt.to_item(), cmd_stmt.get_cmd_name());
+        if background {
+            let pgid = children
+                .first()
+                .map(|leader| leader.id() as i32)
+                .unwrap_or(0);
+            ext_cmd = ext_cmd.in_process_group(pgid);
+        }
+        let stdout = if inherit_last_stdout && idx + 1 == cmds.len() {
+            Stdio::inherit()
+        } else {
+            Stdio::piped()
+        };
+        let mut child = Evaluator::lu_result_to_eval_result(ext_cmd.spawn(
+            &args,
+            stdin,
+            stdout,
+            Stdio::inherit(),
+        ))?;
+
+        if let Some(mut child_stdin) = child.stdin.take() {
+            // Writing in a separate thread, as the child may only read its input, while its
+            // output is read
+            let input = RunExternalCmd::stdin_text(&input);
+            std::thread::spawn(move || {
+                // The child may exit before reading all its input. That is not an error
+                let _ = child_stdin.write_all(input.as_bytes());
+            });
+        }
+        children.push(child);
+    }
+    Ok(())
+}
+
 impl Evaluable for PipeOrValueExprElement {
     fn do_evaluate(&self, args: &[EvalArg], scope: &mut SyScope) -> EvalResult {
         match self {
@@ -8,21 +8,34 @@ impl Evaluable for StatementElement {
             StatementElement::IfElifElseStmt(n) => n.evaluate(scope),
             StatementElement::LetStmt(n) => n.evaluate(scope),
             StatementElement::FnStmt(n) => n.evaluate(scope),
+            StatementElement::ImplStmt(n) => n.evaluate(scope),
             StatementElement::ForStmt(n) => n.evaluate(scope),
+            StatementElement::WhileStmt(n) => n.evaluate(scope),
+            StatementElement::BreakStmt(n) => n.evaluate(scope),
+            StatementElement::ContinueStmt(n) => n.evaluate(scope),
+            StatementElement::TryStmt(n) => n.evaluate(scope),
+            StatementElement::MatchStmt(n) => n.evaluate(scope),
+            StatementElement::BackgroundStmt(n) => n.evaluate(scope),
             StatementElement::RetStmt(n) => n.evaluate(scope),
 
             // Statements that do print their result
             _ => {
+                let silence = get_silence_stmt_returns(&scope.lock()).unwrap_or(false);
+                // The value would only be printed. External cmds can write to the terminal
+                // themselves (and interactive ones need to)
+                let args: &[EvalArg] = if silence {
+                    &[]
+                } else {
+                    &[EvalArg::OutputUnused]
+                };
                 let value = match self {
-                    StatementElement::CmdStmt(n) => n.evaluate(scope)?,
-                    StatementElement::PipedCmdsStmt(n) => n.evaluate(scope)?,
-                    StatementElement::ValueExpr(n) => n.evaluate(scope)?,
+                    StatementElement::CmdStmt(n) => n.evaluate_with_args(args, scope)?,
+                    StatementElement::PipedCmdsStmt(n) => n.evaluate_with_args(args, scope)?,
+                    StatementElement::ValueExpr(n) => n.evaluate_with_args(args, scope)?,
                     _ => unreachable!(),
                 };
-                if let Some(silence) = get_silence_stmt_returns(&scope.lock()) {
-                    if silence {
-                        return Ok(value); // Early return if no printing of statement returns is asked
-                    }
+                if silence {
+                    return Ok(value); // Early return if no printing of statement returns is asked
                 }
                 // Nil does not get printed
                 if value == Value::Nil {
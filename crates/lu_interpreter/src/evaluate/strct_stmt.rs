@@ -3,6 +3,11 @@ use lu_syntax::ast::StrctCtorExprNode;
 
 impl Evaluable for StrctCtorExprNode {
     fn do_evaluate(&self, _: &[EvalArg], state: &mut SyScope) -> EvalResult {
+        let decl = state
+            .lock()
+            .find_strct(&self.name())
+            .cloned()
+            .expect("Ty checker ensures strct is in scope");
         let mut values = vec![];
         for field in self.fields() {
             let name = field.field_name();
@@ -11,6 +16,6 @@ impl Evaluable for StrctCtorExprNode {
             values.push((name, val))
         }
 
-        Ok(Value::new_strct(self.name(), values))
+        Ok(Value::new_strct(decl, values))
     }
 }
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod test {
+    use lu_interpreter_structs::special_scope_vars::set_silence_stmt_returns;
     use lu_test_support::test_prelude::*;
 
     #[lu_conformance::tests(exact, serde=serde_json, file="test_data/evaluate/")]
     fn general_interpreter_tests(s: &str) -> LuResults<Value> {
-        let (global_frame, itprt_cfg) = make_test_interpreter();
+        let (mut global_frame, itprt_cfg) = make_test_interpreter();
+        // The returned values are checked. External cmds must therefore not write to the terminal
+        set_silence_stmt_returns(true, &mut global_frame);
         Interpreter::eval_for_tests(s.to_string().into(), global_frame, &itprt_cfg)
     }
 }
@@ -0,0 +1,49 @@
+use crate::evaluate::eval_prelude::*;
+use lu_interpreter_structs::err_strct_val;
+use lu_syntax::{ast::TryStmtNode, AstToken};
+
+impl Evaluable for TryStmtNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let try_block = self.try_block().unwrap();
+        let catch_stmt = self.catch_stmt().unwrap();
+
+        let (try_frame_id, _) = scope.lock().push_frame(ScopeFrameTag::TryStmtFrame);
+        // We pushed TryStmtFrame, block doesn't need to push additional frame
+        let try_result = try_block.evaluate_with_args(&[EvalArg::BlockNoPushFrame], scope);
+        {
+            let mut l_scope = scope.lock();
+            // An error may leave frames of cmds called within the try block behind. Those are
+            // above the TryStmtFrame
+            l_scope.set_cur_frame_id(try_frame_id);
+            l_scope.pop_frame(&ScopeFrameTag::TryStmtFrame);
+        }
+
+        let err = match try_result {
+            // Aborting through the debugger is not an error the user can handle
+            Err(RetValOrErr::Err(err)) if err != LuErr::Eval(EvalErr::DbgAbort) => err,
+            // Values and loop ctrl stmts are passed through
+            _ => return try_result,
+        };
+        debug!("Caught error {:?}", err);
+
+        {
+            let mut l_scope = scope.lock();
+            let err_val = err_strct_val(&err, &l_scope);
+            let (_, frame) = l_scope.push_frame(ScopeFrameTag::CatchStmtFrame);
+            if let Some(var_name) = catch_stmt.var_name() {
+                frame.insert_var(Variable::new(
+                    var_name.text().to_string(),
+                    err_val,
+                    var_name.to_item(),
+                ));
+            }
+        }
+        let catch_result = catch_stmt
+            .block()
+            .unwrap()
+            .evaluate_with_args(&[EvalArg::BlockNoPushFrame], scope);
+        scope.lock().pop_frame(&ScopeFrameTag::CatchStmtFrame);
+
+        catch_result
+    }
+}
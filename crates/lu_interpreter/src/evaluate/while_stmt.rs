@@ -0,0 +1,63 @@
+use crate::{evaluate::eval_prelude::*, handle_dbg_intervention_before};
+use lu_syntax::ast::{BreakStmtNode, ContinueStmtNode, HasAstId, WhileStmtNode};
+
+impl Evaluable for WhileStmtNode {
+    fn do_evaluate(&self, _: &[EvalArg], scope: &mut SyScope) -> EvalResult {
+        let condition = self.condition().unwrap();
+        let block = self.block().unwrap();
+        let text_till_block = self.text_till_block();
+
+        loop {
+            // The condition is reevaluated for every iteration. Therefore the debugger stops
+            // before each evaluation of it
+            let result = lu_dbg::before_eval(&text_till_block, self.get_ast_id(), scope)?;
+            handle_dbg_intervention_before!(result, scope);
+
+            let cond_val = match condition.evaluate(scope)?.coerce_to_bool() {
+                Some(v) => v,
+                None => {
+                    return Err(
+                        LuErr::Eval(EvalErr::NotConvertibleToBool(condition.to_item())).into(),
+                    )
+                }
+            };
+            if !cond_val {
+                break;
+            }
+
+            scope.lock().push_frame(ScopeFrameTag::WhileStmtFrame);
+            // We pushed WhileStmtFrame, block doesn't need to push additional frame
+            // Make sure we deallocate WhileStmtFrame before returning
+            let eval_result = block.evaluate_with_args(&[EvalArg::BlockNoPushFrame], scope);
+            scope.lock().pop_frame(&ScopeFrameTag::WhileStmtFrame);
+
+            match eval_result {
+                Err(RetValOrErr::Break) => break,
+                Ok(_) | Err(RetValOrErr::Continue) => {}
+                Err(e) => return Err(e),
+            }
+
+            lu_dbg::after_eval(&self.get_ast_id(), scope);
+        }
+
+        Ok(Value::Nil)
+    }
+}
+
+impl Evaluable for BreakStmtNode {
+    fn dbg_settings(&self) -> &'static [DbgSetting] {
+        &[DbgSetting::StopDbgBeforeEval]
+    }
+    fn do_evaluate(&self, _: &[EvalArg], _: &mut SyScope) -> EvalResult {
+        Err(RetValOrErr::Break)
+    }
+}
+
+impl Evaluable for ContinueStmtNode {
+    fn dbg_settings(&self) -> &'static [DbgSetting] {
+        &[DbgSetting::StopDbgBeforeEval]
+    }
+    fn do_evaluate(&self, _: &[EvalArg], _: &mut SyScope) -> EvalResult {
+        Err(RetValOrErr::Continue)
+    }
+}
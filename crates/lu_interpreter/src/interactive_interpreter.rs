@@ -1,4 +1,4 @@
-use lu_error::LuResults;
+use lu_error::{LuErr, LuResults};
 use lu_interpreter_structs::*;
 use lu_structure_parse::{modules_from_start_parse, LoadModulesConfig};
 use lu_syntax::Parse;
@@ -43,7 +43,13 @@ impl InteractiveInterpreter {
             .unwrap()
     }
 
-    pub fn eval_line(&mut self, code: &str) -> LuResults<Value> {
+    /// Evaluates the line. Warnings of the ty checker are passed to `report_warnings` before the
+    /// line is evaluated. If the line has errors, the warnings are returned with them.
+    pub fn eval_line(
+        &mut self,
+        code: &str,
+        report_warnings: impl FnOnce(&[LuErr], &Scope<Variable>),
+    ) -> LuResults<Value> {
         let cli_modi = self.get_cli_modi();
         let parse = Parse::cli_line(code.into(), (cli_modi.src.text.len() as u32).into());
         let parsed_node = parse.val.sf_node.clone();
@@ -76,8 +82,14 @@ impl InteractiveInterpreter {
         }
 
         self.ty_checker.typecheck(parsed_node.clone());
+        let warnings = mem::take(&mut self.ty_checker.warnings);
         if !self.ty_checker.errors.is_empty() {
-            return Err(mem::replace(&mut self.ty_checker.errors, vec![]));
+            let mut errs = mem::take(&mut self.ty_checker.errors);
+            errs.extend(warnings);
+            return Err(errs);
+        }
+        if !warnings.is_empty() {
+            report_warnings(&warnings, &self.ty_checker.scope);
         }
 
         let scope = &mut Arc::new(Mutex::new(self.ty_checker.scope.clone()));
@@ -70,6 +70,8 @@ impl Interpreter {
 
         let mut ty_errs = ty_check.val.get_errors().clone();
         ty_errs.extend(ty_check.errs);
+        // Warnings are returned with the errors. Callers have to check LuErr::is_warning
+        ty_errs.extend(ty_check.val.warnings);
 
         Outcome::new(ty_check.val.scope, ty_errs)
     }
@@ -79,7 +81,8 @@ impl Interpreter {
         global_frame: ScopeFrame<Variable>,
         cfg: &InterpreterCfg,
     ) -> LuResults<Value> {
-        let (scope, ty_errs) = Self::ty_check(code, global_frame, cfg).split();
+        let (scope, mut ty_errs) = Self::ty_check(code, global_frame, cfg).split();
+        ty_errs.retain(|e| !e.is_warning());
         if !ty_errs.is_empty() {
             return Err(ty_errs);
         }
@@ -100,6 +103,7 @@ impl Interpreter {
 
         let mut ty_errs = ty_check.val.get_errors().clone();
         ty_errs.extend(ty_check.errs);
+        ty_errs.extend(ty_check.val.warnings.clone());
 
         if ty_errs.is_empty() {
             Ok(ty_check.val.result.map(|mut ty| {
@@ -3,6 +3,7 @@ mod tests {
     use std::path::PathBuf;
 
     use fs_extra::dir::{copy, CopyOptions};
+    use lu_error::{LuErr, TyErr};
     use lu_test_support::test_prelude::*;
     use lu_test_support::{make_test_interpreter_in_playground, Playground};
     use lu_text_util::SourceCode;
@@ -90,12 +91,186 @@ mod tests {
             &itprt_cfg,
         );
         assert!(eval_result.is_ok(), "{:?}", eval_result);
+        let eval_result = eval_result.unwrap();
+        let (decl, fields) = eval_result.as_strct().expect("Result is a strct");
+        let l_decl = decl.read();
+        assert_eq!(l_decl.name, "MyStruct");
+        // The strct belongs to the module declaring it
+        assert!(l_decl
+            .mod_path
+            .as_file_path()
+            .is_some_and(|path| path.ends_with("other_file.lu")));
+        assert_eq!(
+            **fields,
+            vec![("value".to_string(), Value::Number(1.0.into()))]
+        );
+    }
+
+    #[test]
+    fn same_named_structs_of_different_modules_are_different_tys() {
+        let playground = Playground::new().permanent();
+        playground.make_file(
+            "file_a.lu",
+            br#"
+            struct Entry{value:num}
+            fn make_entry (ret: Entry)
+                ret Entry { value: 1 }
+            end
+            "#,
+        );
+        playground.make_file(
+            "file_b.lu",
+            br#"
+            struct Entry{value:num}
+            fn take_entry (entry: Entry ret: num)
+                ret $entry.value
+            end
+            "#,
+        );
+        let f_path = playground.make_file(
+            "first_file.lu",
+            br#"
+            use ./file_a.lu
+            use ./file_b.lu
+            take_entry (make_entry)
+            "#,
+        );
+
+        let (global_frame, itprt_cfg) = make_test_interpreter_in_playground(playground);
+        let eval_result = Interpreter::eval_for_tests(
+            SourceCode::new_file(f_path).unwrap(),
+            global_frame,
+            &itprt_cfg,
+        );
+        let errs = eval_result.expect_err("Entry of file_a is not Entry of file_b");
+        assert!(
+            errs.iter().any(|err| {
+                let err = format!("{:?}", err);
+                err.contains("file_a.lu:Entry") && err.contains("file_b.lu:Entry")
+            }),
+            "{:?}",
+            errs
+        );
+    }
+
+    #[test]
+    fn same_named_enums_of_different_modules_are_different_tys() {
+        let playground = Playground::new().permanent();
+        playground.make_file(
+            "file_a.lu",
+            br#"
+            enum Kind { File Dir }
+            fn make_kind (ret: Kind)
+                ret Kind.File
+            end
+            "#,
+        );
+        playground.make_file(
+            "file_b.lu",
+            br#"
+            enum Kind { File Dir }
+            fn take_kind (kind: Kind)
+            end
+            "#,
+        );
+        let f_path = playground.make_file(
+            "first_file.lu",
+            br#"
+            use ./file_a.lu
+            use ./file_b.lu
+            take_kind (make_kind)
+            "#,
+        );
+
+        let (global_frame, itprt_cfg) = make_test_interpreter_in_playground(playground);
+        let eval_result = Interpreter::eval_for_tests(
+            SourceCode::new_file(f_path).unwrap(),
+            global_frame,
+            &itprt_cfg,
+        );
+        let errs = eval_result.expect_err("Kind of file_a is not Kind of file_b");
+        assert!(
+            errs.iter().any(|err| {
+                let err = format!("{:?}", err);
+                err.contains("file_a.lu:Kind") && err.contains("file_b.lu:Kind")
+            }),
+            "{:?}",
+            errs
+        );
+    }
+
+    #[test]
+    fn methods_are_called_on_strcts_of_other_modules() {
+        let playground = Playground::new().permanent();
+        playground.make_file(
+            "other_file.lu",
+            br#"
+            struct Entry{value:num}
+            impl Entry
+                fn doubled (self ret: num)
+                    ret $self.value * 2
+                end
+            end
+            fn make_entry (ret: Entry)
+                ret Entry { value: 21 }
+            end
+            "#,
+        );
+        let f_path = playground.make_file(
+            "first_file.lu",
+            br#"
+            use ./other_file.lu
+            let entry = make_entry
+            let piped = $entry | Entry.doubled
+            [ $entry.doubled $piped ]
+            "#,
+        );
+
+        let (global_frame, itprt_cfg) = make_test_interpreter_in_playground(playground);
+        let eval_result = Interpreter::eval_for_tests(
+            SourceCode::new_file(f_path).unwrap(),
+            global_frame,
+            &itprt_cfg,
+        );
         assert_eq!(
             eval_result.unwrap(),
-            Value::new_strct(
-                "MyStruct".to_string(),
-                vec![("value".to_string(), Value::Number(1.0.into()))]
-            )
+            Value::new_array(vec![Value::Number(42.0.into()), Value::Number(42.0.into())])
+        );
+    }
+
+    #[test]
+    fn methods_can_only_be_implemented_in_the_module_of_the_strct() {
+        let playground = Playground::new().permanent();
+        playground.make_file(
+            "other_file.lu",
+            br#"
+            struct Entry{value:num}
+            "#,
+        );
+        let f_path = playground.make_file(
+            "first_file.lu",
+            br#"
+            use ./other_file.lu
+            impl Entry
+                fn doubled (self ret: num)
+                    ret $self.value * 2
+                end
+            end
+            "#,
+        );
+
+        let (global_frame, itprt_cfg) = make_test_interpreter_in_playground(playground);
+        let eval_result = Interpreter::eval_for_tests(
+            SourceCode::new_file(f_path).unwrap(),
+            global_frame,
+            &itprt_cfg,
+        );
+        let errs = eval_result.expect_err("Entry is declared in other_file.lu");
+        assert!(
+            errs.iter()
+                .any(|err| matches!(err, LuErr::Ty(TyErr::ImplOfStrctOfOtherModule { .. }))),
+            "{:?}",
+            errs
         );
     }
 }
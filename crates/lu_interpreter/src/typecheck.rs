@@ -5,31 +5,40 @@ use log::{debug, trace, warn};
 use lu_error::{AstErr, LuErr, LuResults};
 use lu_error::{SourceCodeItem, TyErr};
 use lu_interpreter_structs::prelude::VarAttributes;
+use lu_interpreter_structs::special_scope_vars::STATUS_VAR_NAME;
 use lu_interpreter_structs::{ArgSignature, Command, FlagVariant};
-use lu_pipeline_stage::PipelineStage;
+use lu_pipeline_stage::{ErrorContainer, PipelineStage};
 use parking_lot::RwLock;
 use rusttyc::{TcErr, TcKey, VarlessTypeChecker};
 use std::collections::hash_map::Entry;
 use std::fmt::Display;
 use std::rc::Rc;
 use std::sync::{Arc, Weak};
-use std::time::Duration;
 use std::{collections::HashMap, fmt::Debug};
 
 use crate::{visit_arg::VisitArg, FlagSignature, Scope, ValueType, Variable};
-use crate::{Signature, Strct, ValueTypeErr};
-
+use crate::{Enum, Signature, Strct, ValueTypeErr};
+use cmd_stmt::{resolve_overloaded_calls, OverloadedCall};
+use expr::check_interpolated_values;
+use fn_stmt::check_fn_generics;
+use for_stmt::check_for_var_counts;
+use math_expr::{check_additions, check_fn_casts};
+
+mod background_stmt;
 mod block_stmt;
 mod cls_expr;
 pub mod cmd_select;
 mod cmd_stmt;
 mod condition;
+mod enum_stmt;
 mod expr;
 mod fn_cls_ty_check;
 mod fn_stmt;
 mod for_stmt;
 mod if_stmt;
+mod impl_stmt;
 mod let_stmt;
+mod match_stmt;
 mod math_expr;
 mod piped_cmds_stmt;
 mod ret_stmt;
@@ -37,8 +46,11 @@ mod source_file;
 mod statement;
 mod table_expr;
 mod test;
+mod try_stmt;
 mod value_path_expr;
+mod while_stmt;
 
+#[derive(Clone)]
 pub struct TyCheckState {
     /// A TcKey (TermCheckKey) always refers to a node in the ast
     // We keep track of the node for error formatting reasons. Therefore a SourceCodeItem
@@ -57,17 +69,40 @@ pub struct TyCheckState {
     tc_func_table: HashMap<TcKey, TcFunc>,
     /// TcKey to TcStrct
     tc_strct_table: HashMap<TcKey, TcStrct>,
+    /// Strct decl (by address) to the self_key of its TcStrct
+    tc_strct_decl_table: HashMap<*const RwLock<Strct>, TcKey>,
     /// TcKey to Inner Tc of Array
     tc_array_table: HashMap<TcKey, TcKey>,
     /// TcKey to Inner Tc of Optional
     tc_optional_table: HashMap<TcKey, TcKey>,
+    /// TcKey to Tc of the values of a Map
+    tc_map_table: HashMap<TcKey, TcKey>,
+    /// TcKey to Tc of the elements of a Range
+    tc_range_table: HashMap<TcKey, TcKey>,
     /// TcKey to Generic name
     tc_generic_table: HashMap<TcKey, String>,
 
     /// Final result of typechecking
     pub ty_table: HashMap<TcKey, ValueType>,
 
+    /// Calls of overloaded cmds, whose overload is selected after all args are ty checked
+    overloaded_calls: Vec<OverloadedCall>,
+    /// Values interpolated into strings, whose tys are checked after the final solve
+    interpolated_values: Vec<(TcKey, SourceCodeItem)>,
+    /// Values cast to a fn ty and the fn ty, whose compatibility is checked after the final solve
+    fn_casts: Vec<(TcKey, ValueType, SourceCodeItem)>,
+    /// Operands of `+` and the addition, whose operand tys are checked after the final solve
+    additions: Vec<(TcKey, TcKey, SourceCodeItem)>,
+    /// Iterated values of for stmts with an unfitting number of vars and the expected number.
+    /// Whether the number fits the iterated ty is checked after the final solve
+    for_var_count_mismatches: Vec<(TcKey, usize, SourceCodeItem)>,
+    /// Generics of ty checked fn decls (key, name and decl). Generics stand for any ty, which is
+    /// checked after the final solve
+    fn_generics: Vec<(TcKey, String, SourceCodeItem)>,
+
     pub(crate) errors: Vec<LuErr>,
+    /// Warnings don't let the typecheck fail
+    pub(crate) warnings: Vec<LuErr>,
 
     /// The final result of this ty
     pub result: Option<ValueType>,
@@ -82,13 +117,23 @@ impl TyCheckState {
             scope,
             checker: VarlessTypeChecker::new(),
             errors: Vec::new(),
+            warnings: Vec::new(),
+            overloaded_calls: Vec::new(),
+            interpolated_values: Vec::new(),
+            fn_casts: Vec::new(),
+            additions: Vec::new(),
+            for_var_count_mismatches: Vec::new(),
+            fn_generics: Vec::new(),
             tc_var_table: BiHashMap::new(),
             tc_expr_table: HashMap::new(),
             tc_func_table: HashMap::new(),
             tc_strct_table: HashMap::new(),
+            tc_strct_decl_table: HashMap::new(),
             tc_generic_table: HashMap::new(),
             tc_array_table: HashMap::new(),
             tc_optional_table: HashMap::new(),
+            tc_map_table: HashMap::new(),
+            tc_range_table: HashMap::new(),
             ty_table: HashMap::new(),
             result: None,
             tc_var_cmd_table: Vec::new(),
@@ -113,13 +158,24 @@ impl TyCheckState {
 
     pub fn typecheck(&mut self, node: impl TypeCheck) {
         let ret_key = node.typecheck(self);
+        resolve_overloaded_calls(self);
 
         match self.checker.clone().type_check() {
             Ok(t) => {
                 self.ty_table = t;
-                self.result = ret_key.map(|k| self.ty_table.get(&k).unwrap().clone())
+                self.result = ret_key.map(|k| self.ty_table.get(&k).unwrap().clone());
+                check_interpolated_values(self);
+                check_fn_casts(self);
+                check_additions(self);
+                check_for_var_counts(self);
+                check_fn_generics(self);
             }
             Err(e) => {
+                self.interpolated_values.clear();
+                self.fn_casts.clear();
+                self.additions.clear();
+                self.for_var_count_mismatches.clear();
+                self.fn_generics.clear();
                 self.handle_tc_err(e);
             }
         }
@@ -145,6 +201,42 @@ impl TyCheckState {
         None
     }
 
+    /// Get the ty behind key after the final solve. The inner tys of arrays, optionals and maps
+    /// are tracked by their own keys and are filled in from them
+    pub(crate) fn solved_ty_of(&self, key: &TcKey) -> Option<ValueType> {
+        let solved_inner_ty = |inner_key: Option<&TcKey>, inner_ty: Box<ValueType>| {
+            inner_key
+                .and_then(|inner_key| self.solved_ty_of(inner_key))
+                .map(Box::new)
+                .unwrap_or(inner_ty)
+        };
+        let ty = match self.ty_table.get(key)?.clone() {
+            ValueType::Array {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Array {
+                inner_ty: solved_inner_ty(self.get_arr_inner_tc(key), inner_ty),
+                inner_ty_decl,
+            },
+            ValueType::Optional {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Optional {
+                inner_ty: solved_inner_ty(self.get_optional_inner_tc(key), inner_ty),
+                inner_ty_decl,
+            },
+            ValueType::Map {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Map {
+                inner_ty: solved_inner_ty(self.get_map_inner_tc(key), inner_ty),
+                inner_ty_decl,
+            },
+            ty => ty,
+        };
+        Some(ty)
+    }
+
     pub(crate) fn new_term_key(&mut self, term: SourceCodeItem) -> TcKey {
         let key = self.checker.new_term_key();
         self.tc_expr_table.insert(key, term);
@@ -204,7 +296,10 @@ impl TyCheckState {
             self.get_arr_inner_tc(&key1).cloned(),
             self.get_arr_inner_tc(&key2).cloned(),
         ) {
-            self.equate_keys(key1_arr_inner_tc, key2_arr_inner_tc);
+            // Arrays equated before share their inner key (`$arr = $arr + [1]`)
+            if key1_arr_inner_tc != key2_arr_inner_tc {
+                self.equate_keys(key1_arr_inner_tc, key2_arr_inner_tc);
+            }
             return; // No more work to do
         }
 
@@ -213,7 +308,20 @@ impl TyCheckState {
             self.get_optional_inner_tc(&key1).cloned(),
             self.get_optional_inner_tc(&key2).cloned(),
         ) {
-            self.equate_keys(key1_arr_inner_tc, key2_arr_inner_tc);
+            if key1_arr_inner_tc != key2_arr_inner_tc {
+                self.equate_keys(key1_arr_inner_tc, key2_arr_inner_tc);
+            }
+            return; // No more work to do
+        }
+
+        // Check whether both are maps
+        if let (Some(key1_map_inner_tc), Some(key2_map_inner_tc)) = (
+            self.get_map_inner_tc(&key1).cloned(),
+            self.get_map_inner_tc(&key2).cloned(),
+        ) {
+            if key1_map_inner_tc != key2_map_inner_tc {
+                self.equate_keys(key1_map_inner_tc, key2_map_inner_tc);
+            }
             return; // No more work to do
         }
 
@@ -277,6 +385,26 @@ impl TyCheckState {
                 self.tc_optional_table.insert(key, tc_opt.clone());
             }
         }
+        /* for maps */
+        else if let Some(tc_map) = self.tc_map_table.get(&key2).cloned() {
+            for key in equated_keys_with_key(key1, self) {
+                self.tc_map_table.insert(key, tc_map);
+            }
+        } else if let Some(tc_map) = self.tc_map_table.get(&key1).cloned() {
+            for key in equated_keys_with_key(key2, self) {
+                self.tc_map_table.insert(key, tc_map);
+            }
+        }
+        /* for ranges */
+        else if let Some(tc_range) = self.tc_range_table.get(&key2).cloned() {
+            for key in equated_keys_with_key(key1, self) {
+                self.tc_range_table.insert(key, tc_range);
+            }
+        } else if let Some(tc_range) = self.tc_range_table.get(&key1).cloned() {
+            for key in equated_keys_with_key(key2, self) {
+                self.tc_range_table.insert(key, tc_range);
+            }
+        }
     }
 
     fn concretizes_key(&mut self, key: TcKey, ty: ValueType) {
@@ -284,15 +412,24 @@ impl TyCheckState {
             key.concretizes_explicit(ty.subst_generic_ty(ValueType::Unspecified))
         };
 
-        let mut concretize_key_with_strct = |strct_name: &str, key: TcKey| {
-            if let Some(tc_strct) = self
-                .expect_strct_from_usage(strct_name, self.get_item_of(&key).clone())
-                .cloned()
-            {
-                self.tc_strct_table.insert(key, tc_strct);
-            } else {
-                warn!("Could not find strct with name {}", strct_name);
-            }
+        // StrctName may also refer to an enum (e.G. an enum ty in a sourced signature)
+        let enum_of_strct_name = ty
+            .as_strct_name()
+            .and_then(|name| self.scope.find_enum(name))
+            .cloned();
+        // Strcts are always identified by their decl. A StrctName refers to the strct visible
+        // under that name in the current scope
+        let strct_decl = if let Some(strct_decl) = ty.as_strct() {
+            Weak::upgrade(strct_decl)
+        } else if let Some(strct_name) = ty.as_strct_name().filter(|_| enum_of_strct_name.is_none())
+        {
+            let strct_decl = self
+                .scope
+                .expect_strct(strct_name, self.get_item_of(&key).clone())
+                .cloned();
+            self.ok_or_record(strct_decl)
+        } else {
+            None
         };
 
         if let Some(func_ty) = ty.as_func() {
@@ -321,21 +458,34 @@ impl TyCheckState {
 
             let res = self.checker.impose(concretizes_lib_key(key, ty.clone()));
             self.handle_tc_result(res);
-        } else if let Some(strct_decl) = ty.as_strct() {
-            // panic!("When does this ever happen?");
-            let strct_decl = Weak::upgrade(strct_decl).unwrap();
-            let l_strct_decl = strct_decl
-                .try_read_recursive_for(Duration::new(5, 0))
-                .expect("Could not acquire lock");
-            concretize_key_with_strct(&l_strct_decl.name, key.clone());
+        } else if let Some((inner_ty, inner_ty_decl)) = ty.as_map() {
+            let inner_ty_key =
+                self.new_term_key_concretiziesd(inner_ty_decl.clone(), *inner_ty.clone());
+            self.tc_map_table.insert(key, inner_ty_key);
+
+            let res = self.checker.impose(concretizes_lib_key(key, ty.clone()));
+            self.handle_tc_result(res);
+        } else if ty == ValueType::Range {
+            // Ranges yield numbers
+            let elem_key =
+                self.new_term_key_concretiziesd(self.get_item_of(&key).clone(), ValueType::Number);
+            self.tc_range_table.insert(key, elem_key);
+
+            let res = self.checker.impose(concretizes_lib_key(key, ty.clone()));
+            self.handle_tc_result(res);
+        } else if let Some(strct_decl) = strct_decl {
+            let tc_strct = self.tc_strct_of_decl(&strct_decl);
+            self.tc_strct_table.insert(key, tc_strct);
             let res = self.checker.impose(concretizes_lib_key(
                 key,
-                ValueType::StrctName(l_strct_decl.name.clone()),
+                ValueType::Strct(Arc::downgrade(&strct_decl)),
             ));
             self.handle_tc_result(res);
-        } else if let Some(strct_name) = ty.as_strct_name() {
-            concretize_key_with_strct(strct_name, key.clone());
-            let res = self.checker.impose(concretizes_lib_key(key, ty.clone()));
+        } else if let Some(enm) = enum_of_strct_name {
+            let res = self.checker.impose(concretizes_lib_key(
+                key,
+                ValueType::Enum(Arc::downgrade(&enm)),
+            ));
             self.handle_tc_result(res);
         } else {
             let res = self.checker.impose(concretizes_lib_key(key, ty.clone()));
@@ -343,6 +493,11 @@ impl TyCheckState {
         }
     }
 
+    pub(crate) fn push_warning(&mut self, warning: LuErr) {
+        debug!("Recording warning: {:?}", warning);
+        self.warnings.push(warning);
+    }
+
     /// TODO pass Constraint when Constraint is pub and do impose here instead on caller side
     pub(crate) fn handle_tc_result(&mut self, res: Result<(), TcErr<ValueType>>) -> bool {
         if let Err(e) = res {
@@ -405,36 +560,49 @@ impl TyCheckState {
     /// Gets or inserts a key + tc_func for cmd with name `cmd_name`, that could be called with
     /// the `passed_flags`
     fn get_key_of_cmd(&mut self, func_name: &str, passed_flags: &[FlagVariant]) -> Option<TcKey> {
-        if let Some(func) = self.scope.find_func(func_name, &passed_flags).cloned() {
-            let already_inserted_key = self.tc_var_cmd_table.iter().find_map(|(cmd, key)| {
-                if Rc::ptr_eq(&func, cmd) {
-                    Some(key)
-                } else {
-                    None
-                }
-            });
-            if let Some(key) = already_inserted_key {
-                Some(key.clone())
+        let func = self.scope.find_func(func_name, passed_flags).cloned()?;
+        Some(self.get_key_of_rc_cmd(&func))
+    }
+
+    /// Returns the key of func. Overloads of a func have different keys
+    fn get_key_of_rc_cmd(&mut self, func: &Rc<dyn Command>) -> TcKey {
+        let already_inserted_key = self.tc_var_cmd_table.iter().find_map(|(cmd, key)| {
+            if Rc::ptr_eq(func, cmd) {
+                Some(key)
             } else {
-                // Func is in scope, but doesn't have a tc_key yet ( first time usage of the func )
-                debug!(
-                    "Found cmd {}, which has no tc_key yet. Inserting new tc_func",
-                    func_name
-                );
-                let tc_func = TcFunc::from_signature(func.signature(), self);
-                let tc_func_self_key = tc_func.self_key.clone();
-                self.tc_var_cmd_table
-                    .push((func.clone(), tc_func.self_key.clone()));
-                self.tc_func_table.insert(tc_func.self_key.clone(), tc_func);
-                Some(tc_func_self_key)
+                None
             }
+        });
+        if let Some(key) = already_inserted_key {
+            *key
         } else {
-            None
+            // Func is in scope, but doesn't have a tc_key yet ( first time usage of the func )
+            debug!(
+                "Found cmd {}, which has no tc_key yet. Inserting new tc_func",
+                func.name()
+            );
+            let tc_func = TcFunc::from_signature(func.signature(), self);
+            let tc_func_self_key = tc_func.self_key;
+            self.tc_var_cmd_table.push((func.clone(), tc_func_self_key));
+            self.tc_func_table.insert(tc_func_self_key, tc_func);
+            tc_func_self_key
         }
     }
     /// Returns the key of the var (if present, (or still has to be inserted))
     fn get_key_of_var(&mut self, var_name: &str) -> Option<TcKey> {
         if let Some(var) = self.scope.find_var(var_name).cloned() {
+            if let Some(generic_func) = var
+                .val
+                .as_command()
+                .filter(|func| func.signature().is_generic())
+            {
+                // Each usage of a generic func as a value gets its own instantiation of the
+                // generics. Therefore the func is not cached in the tc_var_table
+                debug!("Instantiating generic func {} at usage", var_name);
+                return self
+                    .instantiate_tc_cmd(generic_func)
+                    .map(|tc_func| tc_func.self_key);
+            }
             if let Some(var_key) = self.tc_var_table.get_by_left(&var) {
                 Some(*var_key)
             } else {
@@ -450,23 +618,27 @@ impl TyCheckState {
                         .insert(var.clone(), tc_func.self_key.clone());
                     Some(tc_func.self_key)
                 } else if let Some(strct) = var.val.as_strct_decl().cloned() {
-                    let strct_name = { strct.read_recursive().name.clone() };
                     debug!(
                         "First time usage of a strct {}. Inserting new tc_strct.",
-                        strct_name
+                        var_name
                     );
-                    let tc_strct = TcStrct::from_strct(&strct, self);
+                    let tc_strct = self.tc_strct_of_decl(&strct);
                     self.tc_var_table
                         .insert(var.clone(), tc_strct.self_key.clone());
 
-                    // TODO TcStrct cant concretize itself because of recursion...
-                    debug!("Concretizing tc strct to be of strctname ty");
-                    self.concretizes_key(
-                        tc_strct.self_key.clone(),
-                        ValueType::StrctName(strct_name),
-                    );
-
                     Some(tc_strct.self_key)
+                } else if var.val.as_enum_decl().is_some() {
+                    // Enums are only used as tys (see expect_enum_from_usage)
+                    None
+                } else if var.val.as_job_table().is_some() {
+                    // The job table is only accessed by the job control builtins
+                    None
+                } else if var_name == STATUS_VAR_NAME {
+                    // $status lives in the global frame. It is not copied into the current frame,
+                    // so that updates to it stay visible
+                    let key = self.new_term_key_concretiziesd(var.decl.clone(), ValueType::Number);
+                    self.tc_var_table.insert(var, key);
+                    Some(key)
                 } else {
                     warn!("Var is present, but not func: {}. inserting it", var_name);
                     Some(self.insert_var(var))
@@ -530,6 +702,40 @@ impl TyCheckState {
         }
     }
 
+    /// Returns the TcStrct of the strct decl. The TcStrct is generated on first usage
+    fn tc_strct_of_decl(&mut self, strct: &Arc<RwLock<Strct>>) -> TcStrct {
+        if let Some(self_key) = self.tc_strct_decl_table.get(&Arc::as_ptr(strct)) {
+            return self.tc_strct_table[self_key].clone();
+        }
+        let tc_strct = TcStrct::from_strct(strct, self);
+        self.tc_strct_decl_table
+            .insert(Arc::as_ptr(strct), tc_strct.self_key);
+        // TODO TcStrct cant concretize itself because of recursion...
+        debug!("Concretizing tc strct to be of its strct ty");
+        self.concretizes_key(tc_strct.self_key, ValueType::Strct(Arc::downgrade(strct)));
+        tc_strct
+    }
+
+    /// Returns the TcFunc of the method method_name of tc_strct. None if the strct does not have
+    /// such a method
+    fn tc_method_of(&mut self, tc_strct: &TcStrct, method_name: &str) -> Option<TcFunc> {
+        let method = self
+            .scope
+            .find_method(&tc_strct.strct.read_recursive(), method_name)
+            .cloned()?;
+        self.instantiate_tc_cmd(&method)
+    }
+
+    /// Some if such an enum is found. None otherwise (and an error will be generated)
+    pub(crate) fn expect_enum_from_usage(
+        &mut self,
+        name: &str,
+        usage: SourceCodeItem,
+    ) -> Option<Arc<RwLock<Enum>>> {
+        let enm = self.scope.expect_enum(name, usage).cloned();
+        self.ok_or_record(enm)
+    }
+
     fn get_tc_cmd_from_key(&mut self, key: TcKey) -> Option<TcFunc> {
         self.get_tc_func(&key.clone())
             .cloned()
@@ -547,34 +753,19 @@ impl TyCheckState {
     }
 
     pub fn get_tc_cmd_from_rc_cmd(&mut self, cmd: &Rc<dyn Command>) -> Option<TcFunc> {
-        self.get_key_of_cmd(cmd.name(), &cmd.signature().req_flags())
-            .map(|key| self.get_tc_cmd_from_key(key))
-            .flatten()
-    }
-
-    /// Returns the strct behind key if key is a Strct. Records an error otherwise
-    /// Therefore the user does not have to handle the None case
-    fn expect_tc_cmd_from_key(&mut self, key: TcKey) -> Option<TcFunc> {
-        if let Some(cmd) = self.get_tc_cmd_from_key(key) {
-            Some(cmd)
-        } else {
-            let key_item = self.get_item_of(&key).clone();
-            self.push_err(TyErr::ItemExpectedToBeFunc(key_item).into());
-            None
-        }
+        let key = self.get_key_of_rc_cmd(cmd);
+        self.get_tc_cmd_from_key(key)
     }
 
-    /// Some if such a callable is found. None otherwise (and an error will be generated)
-    fn expect_tc_cmd_from_cmd_usage(
-        &mut self,
-        cllbl_name: &str,
-        required_flags: &[FlagVariant],
-        cllbl_usage: SourceCodeItem,
-    ) -> Option<TcFunc> {
-        if let Some(func_key) = self.expect_key_from_cmd(&cllbl_name, required_flags, cllbl_usage) {
-            self.expect_tc_cmd_from_key(func_key)
+    /// Returns the TcFunc to check a usage of cmd against. Generic cmds are instantiated anew
+    /// for each usage, so that the generics of different call sites don't influence each other.
+    /// The decl of a generic cmd keeps its own TcFunc (see get_tc_cmd_from_rc_cmd)
+    pub fn instantiate_tc_cmd(&mut self, cmd: &Rc<dyn Command>) -> Option<TcFunc> {
+        if cmd.signature().is_generic() {
+            let tc_func = TcFunc::from_signature(cmd.signature(), self);
+            Some(tc_func.substitute_generics(self))
         } else {
-            None
+            self.get_tc_cmd_from_rc_cmd(cmd)
         }
     }
 
@@ -602,6 +793,18 @@ impl TyCheckState {
         inner_ty_key
     }
 
+    /// Returns the key of the values behind key if key is a map. Records an error otherwise
+    /// Therefore the user does not have to handle the None case
+    fn expect_map_inner_ty_from_key(&mut self, map_key: TcKey) -> Option<TcKey> {
+        let inner_ty_key = self.get_map_inner_tc(&map_key.clone()).cloned();
+
+        if inner_ty_key.is_none() {
+            let key_item = self.get_item_of(&map_key).clone();
+            self.push_err(TyErr::ItemExpectedToBeMap(key_item).into());
+        }
+        inner_ty_key
+    }
+
     /// Get the SourceCodeItem behind the key
     pub(crate) fn get_item_of(&self, key: &TcKey) -> &SourceCodeItem {
         self.tc_expr_table.get(key).unwrap()
@@ -630,6 +833,14 @@ impl TyCheckState {
     fn get_optional_inner_tc(&self, key: &TcKey) -> Option<&TcKey> {
         self.tc_optional_table.get(key)
     }
+
+    fn get_map_inner_tc(&self, key: &TcKey) -> Option<&TcKey> {
+        self.tc_map_table.get(key)
+    }
+
+    fn get_range_elem_tc(&self, key: &TcKey) -> Option<&TcKey> {
+        self.tc_range_table.get(key)
+    }
 }
 
 impl PipelineStage for TyCheckState {
@@ -659,6 +870,8 @@ pub struct TcStrct {
     self_key: TcKey,
     /// Always sorted by field name
     field_keys: Vec<TcStrctField>,
+    /// The strct decl. Its methods are looked up on usage
+    strct: Arc<RwLock<Strct>>,
 }
 
 impl TcStrct {
@@ -685,6 +898,7 @@ impl TcStrct {
             Self {
                 self_key,
                 field_keys,
+                strct: strct.clone(),
             }
         };
 
@@ -712,10 +926,20 @@ pub struct TcFunc {
     args_keys: Vec<(ArgSignature, TcKey)>,
     var_arg_key: Option<TcKey>,
     flags_keys: Vec<(FlagSignature, TcKey)>,
+    /// The signature this TcFunc has been generated from
+    sign: Signature,
 }
 
 impl TcFunc {
     pub(crate) fn substitute_generics(self, ty_state: &mut TyCheckState) -> TcFunc {
+        self.substitute_generics_with_keys(ty_state).0
+    }
+
+    /// Substitutes the generics and returns the keys the generics have been substituted with
+    pub(crate) fn substitute_generics_with_keys(
+        self,
+        ty_state: &mut TyCheckState,
+    ) -> (TcFunc, HashMap<String, TcKey>) {
         debug!(
             "Substituting generics in: {}",
             ty_state.get_item_of(&self.self_key)
@@ -734,7 +958,7 @@ impl TcFunc {
             .tc_func_table
             .insert(result.self_key.clone(), result.clone());
         trace!("{:?}", result);
-        result
+        (result, generics_key)
     }
 
     fn substitute_generics_rec(
@@ -792,6 +1016,11 @@ impl TcFunc {
                 let new_inner_opt_key = subst_generic_key(inner_opt_key, seen_generics, ty_state);
                 ty_state.tc_optional_table.insert(key, new_inner_opt_key); // TODO bit of direct access here...
                 key
+            } else if let Some(inner_map_key) = ty_state.get_map_inner_tc(&key).cloned() {
+                trace!("Substitute Generics: Found inner map_ty. Recursing into that");
+                let new_inner_map_key = subst_generic_key(inner_map_key, seen_generics, ty_state);
+                ty_state.tc_map_table.insert(key, new_inner_map_key); // TODO bit of direct access here...
+                key
             } else {
                 trace!(
                     "Found non generic normal key {}. Not substituting",
@@ -819,7 +1048,7 @@ impl TcFunc {
     pub fn from_signature(sign: &Signature, ty_state: &mut TyCheckState) -> Self {
         debug!("Generating TcFunc for Signature: {:?}", sign);
         // TODO concretizising the self_key with ValueType::Func will result in endless recursion
-        let self_key = ty_state.new_term_key(sign.decl.clone()); 
+        let self_key = ty_state.new_term_key(sign.decl.clone());
         // let self_key = ty_state
         //     .new_term_key_concretiziesd(sign.decl.clone(), ValueType::Func(Box::new(sign.clone())));
 
@@ -864,6 +1093,7 @@ impl TcFunc {
             args_keys,
             var_arg_key,
             flags_keys,
+            sign: sign.clone(),
         };
 
         ty_state
@@ -877,28 +1107,54 @@ impl TcFunc {
         ty_func
     }
 
+    /// The least and the most (None if unbounded) args a call of this func can pass
+    fn arg_count_range(&self) -> (usize, Option<usize>) {
+        let req_args = self
+            .args_keys
+            .iter()
+            .filter(|(arg, _)| !arg.may_be_omitted())
+            .count();
+        let max_args = self
+            .var_arg_key
+            .map_or(Some(self.args_keys.len()), |_| None);
+        (req_args, max_args)
+    }
+
+    /// Whether there is a call, which would be accepted by self and other
     fn same_arity_as(&self, other: &TcFunc) -> bool {
-        match (self.var_arg_key, other.var_arg_key) {
-            (None, None) => self.args_keys.len() == other.args_keys.len(),
-            // case self.args_ty.len == other.args_ty.len:
-            //      works, as both expect same arg count
-            // case self.args_ty.len > other.args_ty.len:
-            //      works, as other args can be filled up in var_arg
-            // case self.args_ty.len < other.args_ty.len:
-            //      doesn't work, as (other.args_ty.len - self.args_ty.len) to many args for self
-            (None, Some(_)) => self.args_keys.len() >= other.args_keys.len(),
-            // See above
-            (Some(_), None) => self.args_keys.len() <= other.args_keys.len(),
-            (Some(_), Some(_)) => true,
-        }
+        let (self_min, self_max) = self.arg_count_range();
+        let (other_min, other_max) = other.arg_count_range();
+        self_max.is_none_or(|self_max| other_min <= self_max)
+            && other_max.is_none_or(|other_max| self_min <= other_max)
+    }
+
+    /// Whether self and other require the same flags to be passed
+    fn same_req_flags_as(&self, other: &TcFunc) -> bool {
+        let req_flags_declared_by = |a: &TcFunc, b: &TcFunc| {
+            a.flags_keys
+                .iter()
+                .filter(|(flag, _)| flag.is_required())
+                .all(|(flag, _)| {
+                    b.flags_keys.iter().any(|(other_flag, _)| {
+                        other_flag.is_required() && flag.names_same_flag_as(other_flag)
+                    })
+                })
+        };
+        req_flags_declared_by(self, other) && req_flags_declared_by(other, self)
     }
 
     // TODO return Vec<Constraint> when constraint is pub
     fn equate_with(&self, other: &TcFunc, ty_state: &mut TyCheckState) {
-        assert!(
-            self.same_arity_as(other),
-            "Should only equate fns with same arity???"
-        );
+        if !self.same_arity_as(other) || !self.same_req_flags_as(other) {
+            let err = TyErr::TypesNotEqual {
+                lhs_decl: Some(ty_state.get_item_of(&self.self_key).clone()),
+                lhs_ty: ValueType::new_func(self.sign.clone()).to_string(),
+                rhs_decl: Some(ty_state.get_item_of(&other.self_key).clone()),
+                rhs_ty: ValueType::new_func(other.sign.clone()).to_string(),
+            };
+            ty_state.push_err(err.into());
+            return;
+        }
         let in_ret_constr = [
             self.in_key.equate_with(other.in_key),
             self.ret_key.equate_with(other.ret_key),
@@ -913,9 +1169,17 @@ impl TcFunc {
             .iter()
             .map(|(_, key)| key)
             .chain(other.var_arg_key.as_ref());
+        let flags_constr = self.flags_keys.iter().filter_map(|(flag, self_flag_key)| {
+            other
+                .flags_keys
+                .iter()
+                .find(|(other_flag, _)| flag.names_same_flag_as(other_flag))
+                .map(|(_, other_flag_key)| self_flag_key.equate_with(*other_flag_key))
+        });
         let args_constr = itertools::zip(self_args_key_iter, other_args_key_iter)
             .map(|(self_arg_key, other_arg_key)| self_arg_key.equate_with(*other_arg_key))
-            .chain(in_ret_constr);
+            .chain(in_ret_constr)
+            .chain(flags_constr);
 
         for constr in args_constr {
             let res = ty_state.checker.impose(constr);
@@ -927,7 +1191,15 @@ impl TcFunc {
 #[derive(Clone, Debug, EnumAsInner)]
 pub enum TypeCheckArg {
     Arg(VisitArg),
-    CmdStmt { in_piped_arg_key: TcKey },
+    CmdStmt {
+        in_piped_arg_key: TcKey,
+    },
+    /// Patterns are checked against the key of the matched value
+    MatchPattern {
+        matched_key: TcKey,
+    },
+    /// The value path is the lhs of an assignment
+    AssignTarget,
 }
 
 pub trait TypeCheck: Display {
@@ -0,0 +1,14 @@
+use lu_syntax::ast::BackgroundStmtNode;
+use rusttyc::TcKey;
+
+use crate::{TyCheckState, TypeCheck, TypeCheckArg};
+
+impl TypeCheck for BackgroundStmtNode {
+    fn do_typecheck(&self, _args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        if let Some(stmt) = self.stmt() {
+            stmt.typecheck(ty_state);
+        }
+
+        None // The output of a job is not returned
+    }
+}
@@ -18,15 +18,16 @@ impl TypeCheck for ClosureExprNode {
         let name = Function::closure_name_from_node(&self.clone().into());
 
         let sign = Signature::from_sign_and_stmt(self.signature(), self.decl_item());
-        let required_flags = sign.req_flags();
 
         let tc_func = TcFunc::from_signature(&sign, ty_state);
 
-        let cls_frame = ScopeFrameTag::TyCFnFrame(name.clone(), required_flags.clone());
-        // Closures capture variables in their environment.
-        let all_vars_inside_fn = ty_state.scope.all_vars_captured_by_closure();
+        let cls_frame = ScopeFrameTag::TyCFnFrame(name.clone(), sign.decl.clone());
+        // Closures capture the referenced variables of their environment.
+        let captured_vars = ty_state
+            .scope
+            .vars_captured_by_closure(&self.referenced_var_names());
         let (_, frame) = ty_state.scope.push_frame(cls_frame.clone());
-        for v in all_vars_inside_fn {
+        for v in captured_vars {
             frame.insert_var(v);
         }
 
@@ -35,7 +36,7 @@ impl TypeCheck for ClosureExprNode {
         frame.insert_var(Variable::new_func(Rc::new(func)));
 
         let self_key = tc_func.self_key.clone();
-        insert_arguments_into_scope(tc_func, &sign, ty_state);
+        insert_arguments_into_scope(tc_func, &sign, self.signature(), ty_state);
 
         if let Some(fn_block) = self.block_stmt() {
             fn_block.typecheck(ty_state);
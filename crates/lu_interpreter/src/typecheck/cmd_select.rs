@@ -41,12 +41,18 @@ pub(crate) fn do_extra_ty_check_select_cmd(
             let decl = cmd_stmt.to_item();
             let name = special_cmds::select_def_strct_name(&decl);
 
-            let strct = Arc::new(RwLock::new(Strct::new(name.clone(), fields, decl)));
             // Insert strct
             let cur_mod_frame = ty_state
                 .scope
                 .get_cur_mod_frame()
                 .expect("Select only found below module frames");
+            let mod_path = cur_mod_frame.get_mod_tag().id.clone();
+            let strct = Arc::new(RwLock::new(Strct::new(
+                name.clone(),
+                fields,
+                decl,
+                mod_path,
+            )));
             cur_mod_frame.insert_var(Variable::new_strct_decl_arc(strct.clone()));
 
             // Return correct ret_ty for select
@@ -9,13 +9,17 @@ use lu_interpreter_structs::{
 };
 use lu_pipeline_stage::{ErrorContainer, PipelineStage};
 use lu_syntax::{
-    ast::{CmdArgElement, CmdStmtNode, LetStmtNode, MathExprNode, ValueExprElement},
+    ast::{
+        CmdArgElement, CmdStmtNode, LetStmtNode, MathExprNode, RedirStmtNode, RedirToValueElement,
+        ValueExprElement,
+    },
     AstElement, AstNode, AstToken,
 };
-use rusttyc::TcKey;
-use std::{collections::HashMap, iter};
+use rusttyc::{Partial, TcKey, Variant};
+use std::{collections::HashMap, iter, mem, rc::Rc};
 
 use crate::typecheck::cmd_select::do_extra_ty_check_select_cmd;
+use crate::typecheck::expr::check_is_interpolatable;
 use crate::{TcFunc, TyCheckState, TypeCheck, TypeCheckArg, ValueType, Variable};
 
 impl TypeCheck for CmdStmtNode {
@@ -28,15 +32,19 @@ impl TypeCheck for CmdStmtNode {
 
         // Finding result type here
         let passed_flags = FlagVariant::convert(self.get_passed_flags());
-        let called_cmd = ty_state
+        let overloads: Vec<_> = ty_state
             .scope
-            .find_func(&self.get_cmd_name(), &passed_flags)
-            .cloned();
-        let (cmd_keys, called_cmd) = if let Some(cmd) = called_cmd {
+            .find_overloads(&self.get_cmd_name(), &passed_flags)
+            .into_iter()
+            .cloned()
+            .collect();
+        if overloads.len() > 1 {
+            return ty_check_overloaded_cmd(self, overloads, args, ty_state);
+        }
+        let (cmd_keys, called_cmd) = if let Some(cmd) = overloads.into_iter().next() {
             (
                 ty_state
-                    // TODO use get_tc_cmd_from_cmd_usage
-                    .get_tc_cmd_from_rc_cmd(&cmd)
+                    .instantiate_tc_cmd(&cmd)
                     .expect("If cmd is found in scope it must be found in ty_state"),
                 cmd,
             )
@@ -61,7 +69,13 @@ impl TypeCheck for CmdStmtNode {
             .find_attr(CmdAttributeVariant::DontParseArguments)
             .is_none()
         {
-            ty_check_cmd_args_and_flags_based_on_signature(self, self.args(), &cmd_keys, ty_state);
+            ty_check_cmd_args_and_flags_based_on_signature(
+                self,
+                self.args(),
+                &cmd_keys,
+                None,
+                ty_state,
+            );
         } else {
             ty_check_cmd_args(self.args(), ty_state);
         }
@@ -71,17 +85,323 @@ impl TypeCheck for CmdStmtNode {
                 return Some(key);
             }
         }
+        if let Some(redir_stmt) = self.redir_stmt() {
+            ty_check_redir(self, &redir_stmt, cmd_keys.ret_key, ty_state);
+            // The result is redirected. Nothing is returned
+            return Some(ty_state.new_term_key_concretiziesd(self.to_item(), ValueType::Nil));
+        }
         Some(ty_state.new_term_key_equated(self.to_item(), cmd_keys.ret_key))
     }
 }
 
+/// The overload of a call can only be selected, after the tys of the passed args are inferred.
+/// The args are ty checked now. The overload is selected by resolve_overloaded_calls
+fn ty_check_overloaded_cmd(
+    cmd_node: &CmdStmtNode,
+    overloads: Vec<Rc<dyn Command>>,
+    args: &[TypeCheckArg],
+    ty_state: &mut TyCheckState,
+) -> Option<TcKey> {
+    let in_key = args.iter().find_map(|arg| arg.as_cmd_stmt()).cloned();
+    // Values of flags are ty checked as well. Which args are values of flags, depends on the
+    // overload
+    let arg_keys = cmd_node
+        .args()
+        .filter_map(|arg| arg.into_value_expr().ok())
+        .map(|arg| {
+            let key = arg.typecheck(ty_state);
+            (arg, key)
+        })
+        .collect();
+    let ret_key = ty_state.new_term_key(cmd_node.to_item());
+    ty_state.overloaded_calls.push(OverloadedCall {
+        cmd_node: cmd_node.clone(),
+        overloads,
+        in_key,
+        arg_keys,
+        ret_key,
+    });
+
+    if let Some(redir_stmt) = cmd_node.redir_stmt() {
+        ty_check_redir(cmd_node, &redir_stmt, ret_key, ty_state);
+        // The result is redirected. Nothing is returned
+        return Some(ty_state.new_term_key_concretiziesd(cmd_node.to_item(), ValueType::Nil));
+    }
+    Some(ret_key)
+}
+
+/// A call of an overloaded cmd, whose overload has not been selected yet
+#[derive(Clone)]
+pub(crate) struct OverloadedCall {
+    cmd_node: CmdStmtNode,
+    overloads: Vec<Rc<dyn Command>>,
+    in_key: Option<TcKey>,
+    /// The passed value args (including the values of flags) with their keys
+    arg_keys: Vec<(ValueExprElement, Option<TcKey>)>,
+    ret_key: TcKey,
+}
+
+/// Selects the overload of all overloaded calls, based on the tys inferred for the passed args.
+/// The selection is recorded in the scope, so that the same overload is called during
+/// evaluation. Calls, whose args depend on the result of other overloaded calls, are resolved in
+/// a later round
+pub(crate) fn resolve_overloaded_calls(ty_state: &mut TyCheckState) {
+    while !ty_state.overloaded_calls.is_empty() {
+        // If the solve fails, the error is reported by the final solve
+        let tys = ty_state.checker.clone().type_check().unwrap_or_default();
+        let (ready, pending): (Vec<_>, Vec<_>) = mem::take(&mut ty_state.overloaded_calls)
+            .into_iter()
+            .partition(|call| call.args_are_inferred(&tys));
+        // Without progress, the remaining calls are resolved with the tys inferred up to now
+        let (ready, pending) = if ready.is_empty() {
+            (pending, vec![])
+        } else {
+            (ready, pending)
+        };
+        for call in ready {
+            call.resolve(&tys, ty_state);
+        }
+        ty_state.overloaded_calls.extend(pending);
+    }
+}
+
+impl OverloadedCall {
+    fn args_are_inferred(&self, tys: &HashMap<TcKey, ValueType>) -> bool {
+        self.arg_keys.iter().filter_map(|(_, key)| *key).all(|key| {
+            tys.get(&key)
+                .is_some_and(|ty| *ty != ValueType::Unspecified)
+        })
+    }
+
+    fn resolve(self, tys: &HashMap<TcKey, ValueType>, ty_state: &mut TyCheckState) {
+        let selected = self.select_overload(tys, ty_state);
+        ty_state
+            .scope
+            .resolved_overloads
+            .insert(self.cmd_node.to_item(), selected.clone());
+
+        let cmd_keys = ty_state
+            .instantiate_tc_cmd(&selected)
+            .expect("If cmd is found in scope it must be found in ty_state");
+        if let Some(in_key) = self.in_key {
+            ty_state.equate_keys(cmd_keys.in_key, in_key);
+        } else {
+            warn!("Cmd stmt arg should always be passed");
+            ty_state.concretizes_key(cmd_keys.in_key, ValueType::Nil);
+        }
+        ty_check_cmd_args_and_flags_based_on_signature(
+            &self.cmd_node,
+            self.cmd_node.args(),
+            &cmd_keys,
+            Some(&self.arg_keys),
+            ty_state,
+        );
+        ty_state.equate_keys(self.ret_key, cmd_keys.ret_key);
+    }
+
+    fn select_overload(
+        &self,
+        tys: &HashMap<TcKey, ValueType>,
+        ty_state: &mut TyCheckState,
+    ) -> Rc<dyn Command> {
+        let matching_overloads: Vec<_> = self
+            .overloads
+            .iter()
+            .filter(|overload| self.accepts_passed_args(overload, tys))
+            .collect();
+        // Of multiple matching overloads, the ones with the most args of exactly the passed ty
+        // are preferred. Passing a path selects fn f(p: path) over fn f(s: str)
+        let max_score = matching_overloads
+            .iter()
+            .map(|overload| self.count_exactly_matching_args(overload, tys))
+            .max()
+            .unwrap_or_default();
+        let matching_overloads: Vec<_> = matching_overloads
+            .into_iter()
+            .filter(|overload| self.count_exactly_matching_args(overload, tys) == max_score)
+            .collect();
+
+        match matching_overloads.as_slice() {
+            [overload] => (*overload).clone(),
+            [] => {
+                ty_state.push_err(
+                    TyErr::NoMatchingOverload {
+                        cmd_stmt: self.cmd_node.to_item(),
+                        overloads: self
+                            .overloads
+                            .iter()
+                            .map(|overload| overload.signature().decl.clone())
+                            .collect(),
+                    }
+                    .into(),
+                );
+                // Checking against the first overload reports the mismatching args
+                self.overloads[0].clone()
+            }
+            [first, ..] => {
+                ty_state.push_err(
+                    TyErr::AmbiguousOverloadCall {
+                        cmd_stmt: self.cmd_node.to_item(),
+                        matching_overloads: matching_overloads
+                            .iter()
+                            .map(|overload| overload.signature().decl.clone())
+                            .collect(),
+                    }
+                    .into(),
+                );
+                (*first).clone()
+            }
+        }
+    }
+
+    /// The tys of the passed args (without values of flags) as taken by overload. Args, whose ty
+    /// could not be inferred are Unspecified
+    fn passed_arg_tys(
+        &self,
+        overload: &Rc<dyn Command>,
+        tys: &HashMap<TcKey, ValueType>,
+    ) -> Vec<ValueType> {
+        let sign = overload.signature();
+        let flag_takes_arg = |flag_sign_matches_usage: &dyn Fn(&FlagSignature) -> bool| {
+            sign.flags
+                .iter()
+                .any(|flag| flag_sign_matches_usage(flag) && flag.ty != ValueType::Bool)
+        };
+        let mut passed_arg_tys = vec![];
+        let mut args = self.cmd_node.args();
+        while let Some(arg) = args.next() {
+            let skip_flag_arg = match arg {
+                CmdArgElement::LongFlag(n) => {
+                    n.has_assigned_value()
+                        || flag_takes_arg(&|flag| flag.long_name == Some(n.flag_name()))
+                }
+                CmdArgElement::ShortFlag(n) => n
+                    .flag_names()
+                    .last()
+                    .is_some_and(|name| flag_takes_arg(&|flag| flag.short_name == Some(*name))),
+                CmdArgElement::DoubleMinus(_) => false,
+                CmdArgElement::ValueExpr(arg) => {
+                    let ty = self
+                        .arg_keys
+                        .iter()
+                        .find(|(passed, _)| *passed == arg)
+                        .and_then(|(_, key)| key.and_then(|key| tys.get(&key)))
+                        .cloned()
+                        .unwrap_or(ValueType::Unspecified);
+                    passed_arg_tys.push(ty);
+                    false
+                }
+            };
+            if skip_flag_arg {
+                args.next();
+            }
+        }
+        passed_arg_tys
+    }
+
+    fn accepts_passed_args(
+        &self,
+        overload: &Rc<dyn Command>,
+        tys: &HashMap<TcKey, ValueType>,
+    ) -> bool {
+        let sign = overload.signature();
+        let passed_arg_tys = self.passed_arg_tys(overload, tys);
+        if passed_arg_tys.len() > sign.args.len() && sign.var_arg.is_none() {
+            return false;
+        }
+        let omitted_args_are_optional = sign
+            .args
+            .iter()
+            .skip(passed_arg_tys.len())
+            .all(|arg| arg.may_be_omitted());
+        omitted_args_are_optional
+            && passed_arg_tys
+                .iter()
+                .zip(declared_arg_tys(overload))
+                .all(|(passed, declared)| is_passable(passed, declared))
+    }
+
+    /// The number of passed args, whose ty is exactly the ty of the arg declared by overload.
+    /// Args only matching by coercion (a path passed as str) are not counted
+    fn count_exactly_matching_args(
+        &self,
+        overload: &Rc<dyn Command>,
+        tys: &HashMap<TcKey, ValueType>,
+    ) -> usize {
+        self.passed_arg_tys(overload, tys)
+            .iter()
+            .zip(declared_arg_tys(overload))
+            .filter(|(passed, declared)| passed == declared)
+            .count()
+    }
+}
+
+fn declared_arg_tys(cmd: &Rc<dyn Command>) -> impl Iterator<Item = &ValueType> {
+    let sign = cmd.signature();
+    sign.args.iter().map(|arg| &arg.ty).chain(
+        sign.var_arg
+            .iter()
+            .flat_map(|var_arg| iter::repeat(&var_arg.ty)),
+    )
+}
+
+/// Whether an arg of ty passed may be passed for an arg declared with ty declared. The same
+/// coercions as when equating the keys of both apply
+fn is_passable(passed: &ValueType, declared: &ValueType) -> bool {
+    if passed.as_func().is_some() && declared.as_func().is_some() {
+        // Fn tys are compared, when the keys of the selected overload are equated
+        return true;
+    }
+    let as_partial = |ty: ValueType| Partial {
+        variant: ty,
+        least_arity: 0,
+    };
+    ValueType::meet(
+        as_partial(passed.clone()),
+        as_partial(declared.clone().subst_generic_ty(ValueType::Unspecified)),
+    )
+    .is_ok()
+}
+
+fn ty_check_redir(
+    cmd_stmt: &CmdStmtNode,
+    redir_stmt: &RedirStmtNode,
+    cmd_ret_key: TcKey,
+    ty_state: &mut TyCheckState,
+) {
+    check_is_interpolatable(Some(cmd_ret_key), cmd_stmt.to_item(), ty_state);
+    match redir_stmt.get_redir_to() {
+        Some(RedirToValueElement::FileName(file_name)) => {
+            file_name.typecheck(ty_state);
+        }
+        Some(RedirToValueElement::ValuePathExpr(var_path)) => {
+            if !var_path.parts_with_item().is_empty() {
+                ty_state.push_err(
+                    TyErr::Message(
+                        "Redirecting into a struct field or array element is not supported".into(),
+                    )
+                    .into(),
+                );
+            }
+            if let Some(var_key) = var_path.typecheck(ty_state) {
+                // The output is redirected as text into the variable
+                ty_state.concretizes_key(var_key, ValueType::String);
+            }
+        }
+        // Parse error has already been reported
+        None => {}
+    }
+}
+
 fn ty_check_cmd_args<ArgIter: Iterator<Item = CmdArgElement>>(
     args: ArgIter,
     ty_state: &mut TyCheckState,
 ) {
     for arg in args {
         match arg {
-            CmdArgElement::ShortFlag(_) | CmdArgElement::LongFlag(_) => {}
+            CmdArgElement::ShortFlag(_)
+            | CmdArgElement::LongFlag(_)
+            | CmdArgElement::DoubleMinus(_) => {}
             CmdArgElement::ValueExpr(expr) => {
                 expr.typecheck(ty_state);
             }
@@ -93,43 +413,78 @@ fn ty_check_cmd_args_and_flags_based_on_signature<ArgIter: Iterator<Item = CmdAr
     cmd_node: &CmdStmtNode,
     mut args: ArgIter,
     called_func: &TcFunc,
+    checked_args: Option<&CheckedArgs>,
     ty_state: &mut TyCheckState,
 ) {
     let mut called_func_arg_tc_iter = called_func.args_keys.iter();
-    // Flags that are required but not passed
-    let mut missing_called_func_req_flags: Vec<_> = called_func
-        .flags_keys
-        .iter()
-        .filter(|(flag, _)| flag.is_required())
-        .map(|(flag, _)| flag.clone())
-        .collect();
+    let mut call = CmdCall {
+        called_func,
+        checked_args,
+        missing_req_flags: called_func
+            .flags_keys
+            .iter()
+            .filter(|(flag, _)| flag.is_required())
+            .map(|(flag, _)| flag.clone())
+            .collect(),
+    };
 
     while let Some(next_arg) = args.next() {
         match next_arg {
-            CmdArgElement::ShortFlag(n) => ty_check_flag(
-                &mut args,
-                |flag_sign| flag_sign.short_name == Some(n.flag_name()),
-                called_func,
-                n.to_item(),
-                &mut missing_called_func_req_flags,
-                ty_state,
-            ),
+            CmdArgElement::ShortFlag(n) => {
+                let flag_names = n.flag_names();
+                for (i, flag_name) in flag_names.iter().enumerate() {
+                    // Of combined short flags (-la) only the last one may take an argument
+                    let arg_passing = if i + 1 == flag_names.len() {
+                        FlagArgPassing::NextArg
+                    } else {
+                        FlagArgPassing::NotPossible
+                    };
+                    ty_check_flag(
+                        &mut args,
+                        |flag_sign| flag_sign.short_name == Some(*flag_name),
+                        arg_passing,
+                        n.to_item(),
+                        &mut call,
+                        ty_state,
+                    )
+                }
+            }
             CmdArgElement::LongFlag(n) => ty_check_flag(
                 &mut args,
                 |flag_sign| flag_sign.long_name == Some(n.flag_name()),
-                called_func,
+                if n.has_assigned_value() {
+                    FlagArgPassing::Assigned
+                } else {
+                    FlagArgPassing::NextArg
+                },
                 n.to_item(),
-                &mut missing_called_func_req_flags,
+                &mut call,
                 ty_state,
             ),
+            // Only separates flags from args
+            CmdArgElement::DoubleMinus(_) => {}
             CmdArgElement::ValueExpr(arg) => {
                 match called_func_arg_tc_iter.next() {
                     Some((_, called_func_arg_tc)) => {
-                        ty_check_cmd_arg(arg, called_func_arg_tc, called_func, cmd_node, ty_state);
+                        ty_check_cmd_arg(
+                            arg,
+                            called_func_arg_tc,
+                            called_func,
+                            cmd_node,
+                            checked_args,
+                            ty_state,
+                        );
                     }
                     None => {
                         if let Some(var_arg_ty) = called_func.var_arg_key {
-                            ty_check_cmd_arg(arg, &var_arg_ty, called_func, cmd_node, ty_state);
+                            ty_check_cmd_arg(
+                                arg,
+                                &var_arg_ty,
+                                called_func,
+                                cmd_node,
+                                checked_args,
+                                ty_state,
+                            );
                         } else {
                             // Found unexpected argument
                             let called_func_decl =
@@ -149,8 +504,8 @@ fn ty_check_cmd_args_and_flags_based_on_signature<ArgIter: Iterator<Item = CmdAr
     }
 
     for (arg, non_passed_arg) in called_func_arg_tc_iter {
-        if arg.is_opt {
-            // Okay. optional arg not passed.
+        if arg.may_be_omitted() {
+            // Okay. optional arg (or arg with default value) not passed.
             continue;
         }
         let arg_decl = ty_state.get_item_of(non_passed_arg).clone();
@@ -163,7 +518,7 @@ fn ty_check_cmd_args_and_flags_based_on_signature<ArgIter: Iterator<Item = CmdAr
         )
     }
 
-    for non_passed_flag in missing_called_func_req_flags {
+    for non_passed_flag in call.missing_req_flags {
         ty_state.push_err(
             TyErr::NotPassedRequiredFlag {
                 flag_decl: non_passed_flag.decl.clone(),
@@ -174,17 +529,36 @@ fn ty_check_cmd_args_and_flags_based_on_signature<ArgIter: Iterator<Item = CmdAr
     }
 }
 
+/// How the argument of a passed flag is given
+enum FlagArgPassing {
+    /// `--flag value`
+    NextArg,
+    /// `--flag=value`
+    Assigned,
+    /// `-a` in `-ab`. Only the last of combined short flags may take an argument
+    NotPossible,
+}
+
+/// The call of a cmd, whose flags are ty checked
+struct CmdCall<'a> {
+    called_func: &'a TcFunc,
+    checked_args: Option<&'a CheckedArgs>,
+    /// Flags that are required but not passed (so far)
+    missing_req_flags: Vec<FlagSignature>,
+}
+
 fn ty_check_flag<ArgIter: Iterator<Item = CmdArgElement>, P>(
     args: &mut ArgIter,
     mut flag_sign_matches_usage: P,
-    called_func: &TcFunc,
+    arg_passing: FlagArgPassing,
     flag_usage: SourceCodeItem,
-    missing_called_func_req_flags: &mut Vec<FlagSignature>,
+    call: &mut CmdCall,
     ty_state: &mut TyCheckState,
 ) where
     P: FnMut(&FlagSignature) -> bool,
 {
-    if let Some((flag, key)) = called_func.flags_keys.iter().find_map(|(flag, key)| {
+    let checked_args = call.checked_args;
+    if let Some((flag, key)) = call.called_func.flags_keys.iter().find_map(|(flag, key)| {
         if flag_sign_matches_usage(flag) {
             Some((flag, key))
         } else {
@@ -193,29 +567,73 @@ fn ty_check_flag<ArgIter: Iterator<Item = CmdArgElement>, P>(
     }) {
         // Found passed flag.
         if flag.is_required() {
-            if let Some(flag_pos) = missing_called_func_req_flags
+            if let Some(flag_pos) = call
+                .missing_req_flags
                 .iter()
                 .position(|missing_flag| missing_flag == flag)
             {
-                missing_called_func_req_flags.remove(flag_pos);
+                call.missing_req_flags.remove(flag_pos);
             }
         }
 
-        if flag.ty != ValueType::Bool {
-            // next arg must be argument to flag
-            match args.next() {
-                Some(CmdArgElement::ValueExpr(arg_val)) => {
-                    warn!("Not promoting math expr to function");
-                    let arg_val_key = arg_val.typecheck(ty_state).unwrap();
-                    ty_state.concretizes_key(arg_val_key, flag.ty.clone());
-                }
-                _ => {
-                    ty_state.push_err(TyErr::FlagWithoutArgument(flag_usage).into());
+        match (flag.ty == ValueType::Bool, arg_passing) {
+            (true, FlagArgPassing::Assigned) => {
+                ty_state.push_err(TyErr::ValueAssignedToSwitch(flag_usage).into());
+                ty_check_assigned_flag_value(args, checked_args, ty_state);
+            }
+            (true, _) => {}
+            (false, FlagArgPassing::NotPossible) => {
+                ty_state.push_err(TyErr::FlagWithArgumentNotLastInGroup(flag_usage).into());
+            }
+            (false, _) => {
+                // next arg must be argument to flag
+                match args.next() {
+                    Some(CmdArgElement::ValueExpr(arg_val)) => {
+                        warn!("Not promoting math expr to function");
+                        if let Some(arg_val_key) = arg_key(&arg_val, checked_args, ty_state) {
+                            ty_state.concretizes_key(arg_val_key, flag.ty.clone());
+                        }
+                    }
+                    _ => {
+                        ty_state.push_err(TyErr::FlagWithoutArgument(flag_usage).into());
+                    }
                 }
             }
         }
     } else {
         ty_state.push_err(TyErr::PassingOfNotDeclaredFlag(flag_usage).into());
+        if let FlagArgPassing::Assigned = arg_passing {
+            ty_check_assigned_flag_value(args, checked_args, ty_state);
+        }
+    }
+}
+
+/// The value of `--flag=value` belongs to the flag, even if the flag does not take one.
+/// It is not reported as an unexpected argument
+fn ty_check_assigned_flag_value<ArgIter: Iterator<Item = CmdArgElement>>(
+    args: &mut ArgIter,
+    checked_args: Option<&CheckedArgs>,
+    ty_state: &mut TyCheckState,
+) {
+    if let Some(CmdArgElement::ValueExpr(arg_val)) = args.next() {
+        arg_key(&arg_val, checked_args, ty_state);
+    }
+}
+
+/// Value args, which have been ty checked before the called overload has been selected
+type CheckedArgs = [(ValueExprElement, Option<TcKey>)];
+
+/// Returns the key of arg. Args, which have not been checked yet, are ty checked
+fn arg_key(
+    arg: &ValueExprElement,
+    checked_args: Option<&CheckedArgs>,
+    ty_state: &mut TyCheckState,
+) -> Option<TcKey> {
+    match checked_args
+        .and_then(|checked_args| checked_args.iter().find(|(checked, _)| checked == arg))
+    {
+        Some((_, key)) => *key,
+        None => arg.typecheck(ty_state),
     }
 }
 
@@ -224,6 +642,7 @@ fn ty_check_cmd_arg(
     called_func_arg_tc: &TcKey,
     called_func: &TcFunc,
     cmd_node: &CmdStmtNode,
+    checked_args: Option<&CheckedArgs>,
     ty_state: &mut TyCheckState,
 ) {
     debug!(
@@ -233,18 +652,17 @@ fn ty_check_cmd_arg(
         called_func_arg_tc
     );
 
-    // Check whether we have to fixup the MathExpr to become a function
-    let passed_arg_key = if let (Some(passed_math_expr), Some(expected_fn_ty)) = (
+    // Check whether we have to fixup the MathExpr to become a function. Already checked args
+    // (passed to an overloaded cmd) are never promoted
+    if let (Some(passed_math_expr), Some(expected_fn_ty), None) = (
         passed_arg.as_math_expr(),
         ty_state.get_tc_func(called_func_arg_tc).cloned(),
+        checked_args,
     ) {
         ty_check_math_expr_as_fn(passed_math_expr, expected_fn_ty, ty_state);
-    } else {
-        let passed_arg_key = passed_arg
-            .typecheck(ty_state)
-            .expect("Arg always returns a key");
+    } else if let Some(passed_arg_key) = arg_key(&passed_arg, checked_args, ty_state) {
         ty_state.equate_keys(passed_arg_key, *called_func_arg_tc);
-    };
+    }
 }
 
 fn ty_check_math_expr_as_fn(
@@ -253,7 +671,7 @@ fn ty_check_math_expr_as_fn(
     ty_state: &mut TyCheckState,
 ) {
     // TODO assert expected_fn_ty is simple
-    let fn_frame = ScopeFrameTag::TyCFnFrame(MATH_FN_NAME.into(), vec![]);
+    let fn_frame = ScopeFrameTag::TyCFnFrame(MATH_FN_NAME.into(), passed_math_expr.to_item());
     let (_, frame) = ty_state.scope.push_frame(fn_frame.clone());
     // Insert vars
     for (arg, key) in &expected_fn_ty.args_keys {
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use lu_error::TyErr;
+use lu_interpreter_structs::{EnumVariant, ValueType};
+use lu_pipeline_stage::PipelineStage;
+use lu_syntax::{
+    ast::{EnumCtorExprNode, EnumVariantNameToken, StrctNameToken},
+    AstNode, AstToken,
+};
+use parking_lot::RwLock;
+use rusttyc::TcKey;
+
+use crate::{Enum, TyCheckState, TypeCheck, TypeCheckArg};
+
+impl TypeCheck for EnumCtorExprNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        let (enm, variant) =
+            match expect_enum_variant(&self.enum_name(), self.variant_name(), ty_state) {
+                Some(enum_variant) => enum_variant,
+                None => {
+                    return Some(
+                        ty_state.new_term_key_concretiziesd(self.to_item(), ValueType::Error),
+                    )
+                }
+            };
+        let ctor_key = ty_state
+            .new_term_key_concretiziesd(self.to_item(), ValueType::Enum(Arc::downgrade(&enm)));
+
+        for field_ctor in self.fields() {
+            let field_name = field_ctor.field_name();
+            let val_key = field_ctor.value().and_then(|val| val.typecheck(ty_state));
+            match variant.fields.iter().find(|field| field.name == field_name) {
+                Some(field) => {
+                    let field_key =
+                        ty_state.new_term_key_concretiziesd(field.decl.clone(), field.ty.clone());
+                    if let Some(val_key) = val_key {
+                        ty_state.equate_keys(field_key, val_key);
+                    }
+                }
+                None => ty_state.push_err(
+                    TyErr::StructDoesNotHaveField {
+                        field_name,
+                        strct_decl: variant.decl.clone(),
+                        usage: field_ctor.to_item(),
+                    }
+                    .into(),
+                ),
+            }
+        }
+
+        for field in &variant.fields {
+            if !self
+                .fields()
+                .any(|field_ctor| field_ctor.field_name() == field.name)
+            {
+                ty_state.push_err(
+                    TyErr::StructMissesField {
+                        field_name: field.name.clone(),
+                        strct_decl: variant.decl.clone(),
+                        usage: self.to_item(),
+                    }
+                    .into(),
+                );
+            }
+        }
+
+        Some(ctor_key)
+    }
+}
+
+/// Returns the enum and its variant referred to by `<EnumName>.<VariantName>`. Records an
+/// error if either of them is not found
+pub(crate) fn expect_enum_variant(
+    enum_name: &StrctNameToken,
+    variant_name: Option<EnumVariantNameToken>,
+    ty_state: &mut TyCheckState,
+) -> Option<(Arc<RwLock<Enum>>, EnumVariant)> {
+    let enm = ty_state.expect_enum_from_usage(enum_name.text(), enum_name.to_item())?;
+    let variant_name = variant_name?; // Parser already reported an error
+    let variant = enm.read().find_variant(variant_name.text()).cloned();
+    match variant {
+        Some(variant) => Some((enm, variant)),
+        None => {
+            let enum_decl = enm.read().decl.clone();
+            ty_state.push_err(
+                TyErr::EnumDoesNotHaveVariant {
+                    enum_decl,
+                    usage: variant_name.to_item(),
+                    variant_name: variant_name.text().to_string(),
+                }
+                .into(),
+            );
+            None
+        }
+    }
+}
@@ -1,14 +1,17 @@
 use itertools::{EitherOrBoth, Itertools};
 use log::{debug, warn};
+use lu_error::{SourceCodeItem, TyErr};
 use lu_pipeline_stage::PipelineStage;
 use lu_syntax::{
     ast::{
-        ArrayExprNode, BareWordToken, BooleanExprNode, FileNameElement, NumberExprNode,
-        OptionalExprNode, StrctCtorExprNode, StringExprNode, ValueExprElement,
+        ArrayExprNode, BareWordToken, BooleanExprNode, FileNameElement, MapExprNode,
+        NumberExprNode, OptionalExprNode, ParentherizedExprNode, RangeExprNode, StrctCtorExprNode,
+        StringExprNode, StringExprPartElement, ValueExprElement,
     },
     AstElement, AstNode, AstToken,
 };
 use rusttyc::TcKey;
+use std::mem;
 
 use crate::{TyCheckState, TypeCheck, TypeCheckArg, ValueType};
 
@@ -19,11 +22,16 @@ impl TypeCheck for ValueExprElement {
             ValueExprElement::BareWord(n) => n.typecheck_with_args(args, state),
             ValueExprElement::NumberExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::MathExpr(n) => n.typecheck_with_args(args, state),
+            ValueExprElement::UnaryExpr(n) => n.typecheck_with_args(args, state),
+            ValueExprElement::RangeExpr(n) => n.typecheck_with_args(args, state),
+            ValueExprElement::ParentherizedExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::StringExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::ValuePathExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::ArrayExpr(n) => n.typecheck_with_args(args, state),
+            ValueExprElement::MapExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::TableExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::StrctCtorExpr(n) => n.typecheck_with_args(args, state),
+            ValueExprElement::EnumCtorExpr(n) => n.typecheck_with_args(args, state),
             ValueExprElement::CmdStmt(n) => n.typecheck_with_args(args, state),
             ValueExprElement::FileName(n) => n.typecheck_with_args(args, state),
             ValueExprElement::OptionalExpr(n) => n.typecheck_with_args(args, state),
@@ -81,10 +89,72 @@ impl TypeCheck for NumberExprNode {
 
 impl TypeCheck for StringExprNode {
     fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        if !self.is_raw() {
+            for part in self.parts() {
+                let (part_key, part_item) = match part {
+                    StringExprPartElement::StringContent(_) => continue,
+                    StringExprPartElement::ValuePathExpr(n) => (n.typecheck(ty_state), n.to_item()),
+                    StringExprPartElement::ParentherizedExpr(n) => {
+                        (n.typecheck(ty_state), n.to_item())
+                    }
+                };
+                check_is_interpolatable(part_key, part_item, ty_state);
+            }
+        }
         Some(ty_state.new_term_key_concretiziesd(self.to_item(), ValueType::String))
     }
 }
 
+/// Records that the value behind `key` is interpolated into a string. Whether its ty can be
+/// converted to a string is checked by check_interpolated_values after the final solve
+pub(crate) fn check_is_interpolatable(
+    key: Option<TcKey>,
+    item: SourceCodeItem,
+    ty_state: &mut TyCheckState,
+) {
+    if let Some(key) = key {
+        ty_state.interpolated_values.push((key, item));
+    }
+}
+
+pub(crate) fn check_interpolated_values(ty_state: &mut TyCheckState) {
+    for (key, item) in mem::take(&mut ty_state.interpolated_values) {
+        let ty = match ty_state.ty_table.get(&key) {
+            Some(ty) => ty.clone(),
+            None => continue,
+        };
+        if !ty.is_interpolatable_into_string() {
+            ty_state.push_err(
+                TyErr::NotConvertibleToString {
+                    found_ty: ty.to_string(),
+                    item,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+impl TypeCheck for ParentherizedExprNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        self.inner().and_then(|inner| inner.typecheck(ty_state))
+    }
+}
+
+impl TypeCheck for RangeExprNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
+        // start, end and step are all numbers
+        let bounds = std::iter::once(self.start())
+            .chain(self.end())
+            .chain(self.step());
+        for bound in bounds {
+            let bound_key = bound.typecheck(state).expect("ValueExpr always gives key");
+            state.concretizes_key(bound_key, ValueType::Number);
+        }
+        Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Range))
+    }
+}
+
 impl TypeCheck for ArrayExprNode {
     fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
         // Equate all inner ty's (they must be of the same ty)
@@ -115,6 +185,27 @@ impl TypeCheck for ArrayExprNode {
     }
 }
 
+impl TypeCheck for MapExprNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
+        let map_key = state.new_term_key_concretiziesd(
+            self.to_item(),
+            ValueType::new_map(ValueType::Unspecified, self.to_item()),
+        );
+        let inner_ty_key = state
+            .expect_map_inner_ty_from_key(map_key)
+            .expect("Prev inserted, always present");
+
+        // All values must be of the same ty
+        for entry_val in self.entries().filter_map(|entry| entry.value()) {
+            if let Some(val_key) = entry_val.typecheck(state) {
+                state.equate_keys(inner_ty_key, val_key);
+            }
+        }
+
+        Some(map_key)
+    }
+}
+
 impl TypeCheck for StrctCtorExprNode {
     fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
         // panic!("Ty chekcing ");
@@ -1,17 +1,36 @@
 use lu_interpreter_structs::{Signature, ValueType};
+use lu_syntax::ast::{SignatureNode, ValueExprElement};
+use rusttyc::TcKey;
 
-use crate::{TcFunc, TyCheckState};
+use crate::{TcFunc, TyCheckState, TypeCheck};
 
 pub(crate) fn insert_arguments_into_scope(
     tc_func: TcFunc,
     sign: &Signature,
+    sign_node: Option<SignatureNode>,
     ty_state: &mut TyCheckState,
 ) {
+    if let Some(sign_node) = sign_node {
+        // Defaults are checked before the args are in scope
+        let arg_defaults = sign_node.args().into_iter().map(|arg| arg.default_value());
+        let flag_defaults = sign_node.flags().map(|flag| flag.default_value());
+        let arg_keys = tc_func.args_keys.iter().map(|(_, key)| *key);
+        let flag_keys = tc_func.flags_keys.iter().map(|(_, key)| *key);
+        for (key, default) in arg_keys
+            .chain(flag_keys)
+            .zip(arg_defaults.chain(flag_defaults))
+        {
+            if let Some(default) = default {
+                ty_check_default_value(&default, key, ty_state);
+            }
+        }
+    }
+
     let var_key_to_insert = {
         let mut var_ty_to_insert = Vec::new();
 
         for (arg, key) in tc_func.args_keys {
-            if arg.is_opt {
+            if arg.is_opt && !arg.has_default {
                 // optional arg is inserted as optional<ty>
                 let key = ty_state.new_term_key_concretiziesd(
                     arg.decl.clone(),
@@ -31,7 +50,7 @@ pub(crate) fn insert_arguments_into_scope(
             var_ty_to_insert.push((var_arg.to_var(), tc_func.var_arg_key.unwrap()));
         }
         for (flag, key) in tc_func.flags_keys {
-            if flag.ty.is_bool() || flag.is_required() {
+            if flag.ty.is_bool() || flag.is_required() || flag.has_default {
                 var_ty_to_insert.push((flag.to_var(), key))
             } else {
                 // optional flag and ty is not bool, inserted flag is optional then
@@ -53,3 +72,10 @@ pub(crate) fn insert_arguments_into_scope(
         ty_state.equate_keys(var_key, key);
     }
 }
+
+/// The default value of an arg (or flag) has to be of the declared ty
+fn ty_check_default_value(default: &ValueExprElement, arg_key: TcKey, ty_state: &mut TyCheckState) {
+    if let Some(default_key) = default.typecheck(ty_state) {
+        ty_state.equate_keys(arg_key, default_key);
+    }
+}
@@ -1,8 +1,14 @@
+use itertools::Itertools;
+use lu_error::TyErr;
 use lu_interpreter_structs::Signature;
-use lu_syntax::ast::FnStmtNode;
+use lu_pipeline_stage::PipelineStage;
+use lu_syntax::{ast::FnStmtNode, AstToken};
 use rusttyc::TcKey;
 
-use crate::{ScopeFrameTag, TyCheckState};
+use std::collections::{hash_map::Entry, HashMap};
+use std::mem;
+
+use crate::{ScopeFrameTag, TyCheckState, ValueType};
 
 use super::{fn_cls_ty_check::insert_arguments_into_scope, TypeCheck};
 
@@ -12,31 +18,125 @@ impl TypeCheck for FnStmtNode {
         _: &[super::TypeCheckArg],
         ty_state: &mut TyCheckState,
     ) -> Option<TcKey> {
-        let fn_name = if let Some(name) = self.name() {
-            name
-        } else {
-            return None;
-        };
+        if let Some(name) = self.name() {
+            ty_check_fn_stmt_named(self, name, ty_state);
+        }
 
-        let sign = Signature::from_sign_and_stmt(self.signature(), self.decl_item());
-        let req_flags = sign.req_flags();
+        // A fn stmt doesn't return a value
+        None
+    }
+}
 
-        let tc_func = ty_state
-            .expect_tc_cmd_from_cmd_usage(&fn_name, &req_flags, self.decl_item())
-            .expect("Always works");
+/// Ty checks the block of fn_stmt, which has been sourced as func with name fn_name
+pub(crate) fn ty_check_fn_stmt_named(
+    fn_stmt: &FnStmtNode,
+    fn_name: String,
+    ty_state: &mut TyCheckState,
+) {
+    let sign = Signature::from_fn_stmt(fn_stmt);
+    check_generics_dont_shadow_tys(fn_stmt, ty_state);
 
-        let fn_frame = ScopeFrameTag::TyCFnFrame(fn_name.clone(), req_flags);
-        ty_state.scope.push_frame(fn_frame.clone());
+    let func = ty_state
+        .scope
+        .find_func_by_decl(&fn_name, &sign.decl)
+        .cloned()
+        .expect("Always works");
+    let func_key = ty_state.get_key_of_rc_cmd(&func);
+    let (tc_func, generic_keys) = ty_state
+        .get_tc_func(&func_key)
+        .cloned()
+        .expect("Always works")
+        .substitute_generics_with_keys(ty_state);
+    let generics = generic_keys.into_iter().map(|(generic_name, key)| {
+        // Explicit generics are declared in the param list. Others at their first usage
+        let generic_decl = fn_stmt
+            .generic_params()
+            .iter()
+            .find(|generic| generic.text() == generic_name)
+            .map(|generic| generic.to_item())
+            .unwrap_or_else(|| ty_state.get_item_of(&key).clone());
+        (key, generic_name, generic_decl)
+    });
+    // Ordered by decl, so that errors are reported deterministically
+    let generics: Vec<_> = generics
+        .sorted_by_key(|(_, _, generic_decl)| generic_decl.range.start())
+        .collect();
+    ty_state.fn_generics.extend(generics);
 
-        insert_arguments_into_scope(tc_func, &sign, ty_state);
+    let fn_frame = ScopeFrameTag::TyCFnFrame(fn_name, sign.decl.clone());
+    ty_state.scope.push_frame(fn_frame.clone());
 
-        if let Some(fn_block) = self.block_stmt() {
-            fn_block.typecheck(ty_state);
-        }
+    insert_arguments_into_scope(tc_func, &sign, fn_stmt.signature(), ty_state);
 
-        ty_state.scope.pop_frame(&fn_frame);
+    if let Some(fn_block) = fn_stmt.block_stmt() {
+        fn_block.typecheck(ty_state);
+    }
 
-        // A fn stmt doesn't return a value
-        None
+    ty_state.scope.pop_frame(&fn_frame);
+}
+
+/// A generic param with the name of a strct or enum would silently hide that ty in the signature
+fn check_generics_dont_shadow_tys(fn_stmt: &FnStmtNode, ty_state: &mut TyCheckState) {
+    for generic in fn_stmt.generic_params() {
+        let name = generic.text();
+        let ty_decl = if let Some(strct) = ty_state.scope.find_strct(name) {
+            strct.read().decl.clone()
+        } else if let Some(enum_) = ty_state.scope.find_enum(name) {
+            enum_.read().decl.clone()
+        } else {
+            continue;
+        };
+        ty_state.push_err(
+            TyErr::GenericShadowsTy {
+                generic: generic.to_item(),
+                ty_decl,
+            }
+            .into(),
+        );
+    }
+}
+
+/// Maps the keys, which are (transitively) equated with a generic of a fn decl, to the index of
+/// that generic in fn_generics
+pub(crate) fn keys_of_fn_generics(ty_state: &TyCheckState) -> HashMap<TcKey, usize> {
+    let mut generic_of_key = HashMap::new();
+    for (generic_idx, (generic_key, _, _)) in ty_state.fn_generics.iter().enumerate() {
+        let mut to_visit = vec![*generic_key];
+        while let Some(key) = to_visit.pop() {
+            if let Entry::Vacant(entry) = generic_of_key.entry(key) {
+                entry.insert(generic_idx);
+                to_visit.extend(ty_state.tc_equated_keys.get(&key).into_iter().flatten());
+            }
+        }
+    }
+    generic_of_key
+}
+
+/// A generic stands for any ty. Records an error for every generic of a fn decl, which the fn
+/// uses as a specific ty (e.g. `$a - 1` restricts the generic of a to num) or as another generic
+pub(crate) fn check_fn_generics(ty_state: &mut TyCheckState) {
+    let generic_of_key = keys_of_fn_generics(ty_state);
+    let fn_generics = mem::take(&mut ty_state.fn_generics);
+    for (generic_idx, (key, generic_name, generic_decl)) in fn_generics.iter().enumerate() {
+        let used_as_ty = match ty_state.ty_table.get(key) {
+            Some(ValueType::Unspecified | ValueType::Any | ValueType::Error) | None => {
+                // Generics equated with each other are all found from the first one
+                match generic_of_key.get(key) {
+                    Some(other_idx) if *other_idx != generic_idx => {
+                        fn_generics[*other_idx].1.clone()
+                    }
+                    _ => continue,
+                }
+            }
+            Some(ty) => ty.to_string(),
+        };
+        ty_state.push_err(
+            TyErr::GenericUsedAsTy {
+                generic_decl: generic_decl.clone(),
+                generic_name: generic_name.clone(),
+                used_as_ty,
+            }
+            .into(),
+        );
     }
 }
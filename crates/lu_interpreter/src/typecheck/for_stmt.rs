@@ -1,8 +1,12 @@
+use itertools::Itertools;
+use lu_error::TyErr;
 use lu_interpreter_structs::{ScopeFrameTag, Value, Variable};
+use lu_pipeline_stage::PipelineStage;
 use lu_syntax::{ast::ForStmtNode, AstNode, AstToken};
 use rusttyc::TcKey;
+use std::mem;
 
-use crate::{TypeCheck, TypeCheckArg};
+use crate::{TyCheckState, TypeCheck, TypeCheckArg, ValueType};
 
 impl TypeCheck for ForStmtNode {
     fn do_typecheck(
@@ -10,8 +14,6 @@ impl TypeCheck for ForStmtNode {
         _args: &[TypeCheckArg],
         ty_state: &mut crate::TyCheckState,
     ) -> Option<TcKey> {
-        ty_state.scope.push_frame(ScopeFrameTag::ForStmtFrame);
-
         let var_names: Vec<String> = self
             .var_names()
             .into_iter()
@@ -19,14 +21,66 @@ impl TypeCheck for ForStmtNode {
             .collect();
         assert!(var_names.len() > 0, "TODO");
 
-        ty_state.insert_var(Variable::new(
-            var_names[0].clone(),
-            Value::Nil,
-            self.to_item(),
-        ));
+        // TODO check that iterated_value is either array, map, range or string
+        let iterated_key = self
+            .iterated_value()
+            .and_then(|iterated| iterated.typecheck(ty_state));
+        let map_inner_key = iterated_key.and_then(|key| ty_state.get_map_inner_tc(&key).cloned());
 
-        // TODO check that iterated_value is either array or string. Currently thats not possible
-        let _ = self.iterated_value();
+        ty_state.scope.push_frame(ScopeFrameTag::ForStmtFrame);
+
+        if let Some(map_inner_key) = map_inner_key {
+            // Maps are iterated by key and value
+            check_var_count(self, &var_names, 2, iterated_key.unwrap(), ty_state);
+            let mut var_names = var_names.into_iter();
+            if let Some(key_var) = var_names.next() {
+                let key_var_key =
+                    ty_state.insert_var(Variable::new(key_var, Value::Nil, self.to_item()));
+                ty_state.concretizes_key(key_var_key, ValueType::String);
+            }
+            if let Some(val_var) = var_names.next() {
+                let val_var_key =
+                    ty_state.insert_var(Variable::new(val_var, Value::Nil, self.to_item()));
+                ty_state.equate_keys(val_var_key, map_inner_key);
+            }
+        } else if let Some(strct_fields) = iterated_key
+            .filter(|_| var_names.len() > 1)
+            .and_then(|key| strct_fields_of_rows(key, ty_state))
+        {
+            // Arrays of structs may be iterated by one var per field (in decl order)
+            check_var_count(
+                self,
+                &var_names,
+                strct_fields.len(),
+                iterated_key.unwrap(),
+                ty_state,
+            );
+            for (var_name, field_key) in var_names.into_iter().zip(strct_fields) {
+                let var_key =
+                    ty_state.insert_var(Variable::new(var_name, Value::Nil, self.to_item()));
+                ty_state.equate_keys(var_key, field_key);
+            }
+        } else {
+            if let Some(iterated_key) = iterated_key {
+                check_var_count(self, &var_names, 1, iterated_key, ty_state);
+            }
+            let var_key = ty_state.insert_var(Variable::new(
+                var_names[0].clone(),
+                Value::Nil,
+                self.to_item(),
+            ));
+            // Arrays and ranges yield their elements
+            if let Some(elem_key) = iterated_key
+                .and_then(|key| {
+                    ty_state
+                        .get_arr_inner_tc(&key)
+                        .or_else(|| ty_state.get_range_elem_tc(&key))
+                })
+                .cloned()
+            {
+                ty_state.equate_keys(var_key, elem_key);
+            }
+        }
 
         if let Some(block) = self.block() {
             block.typecheck(ty_state);
@@ -37,3 +91,52 @@ impl TypeCheck for ForStmtNode {
         None // For does not return
     }
 }
+
+/// Returns the keys of the fields (ordered by decl) of the structs within the array behind
+/// iterated_key. None if iterated_key is not an array of structs
+fn strct_fields_of_rows(iterated_key: TcKey, ty_state: &TyCheckState) -> Option<Vec<TcKey>> {
+    let row_key = ty_state.get_arr_inner_tc(&iterated_key)?;
+    let strct = ty_state.tc_strct_table.get(row_key)?;
+    Some(
+        strct
+            .field_keys
+            .iter()
+            .sorted_by_key(|field| field.field_num)
+            .map(|field| field.ty)
+            .collect(),
+    )
+}
+
+/// Records the for stmt for check_for_var_counts, if the number of vars does not fit the
+/// iterated value
+fn check_var_count(
+    for_stmt: &ForStmtNode,
+    var_names: &[String],
+    expected: usize,
+    iterated_key: TcKey,
+    ty_state: &mut TyCheckState,
+) {
+    if var_names.len() != expected {
+        ty_state
+            .for_var_count_mismatches
+            .push((iterated_key, expected, for_stmt.to_item()));
+    }
+}
+
+/// Records an error for every for stmt, whose number of vars does not fit the iterated value.
+/// Values of not known ty are not checked
+pub(crate) fn check_for_var_counts(ty_state: &mut TyCheckState) {
+    for (iterated_key, expected, for_stmt) in mem::take(&mut ty_state.for_var_count_mismatches) {
+        match ty_state.solved_ty_of(&iterated_key) {
+            None | Some(ValueType::Unspecified) => {}
+            Some(iterated_ty) => ty_state.push_err(
+                TyErr::ForVarCountMismatch {
+                    expected,
+                    for_stmt,
+                    iterated_ty: iterated_ty.to_string(),
+                }
+                .into(),
+            ),
+        }
+    }
+}
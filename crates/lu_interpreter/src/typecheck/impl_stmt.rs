@@ -0,0 +1,47 @@
+use lu_error::TyErr;
+use lu_pipeline_stage::{ErrorContainer, PipelineStage};
+use lu_syntax::{
+    ast::{method_cmd_name, ImplStmtNode},
+    AstToken,
+};
+use rusttyc::TcKey;
+
+use crate::{TyCheckState, TypeCheck, TypeCheckArg};
+
+use super::fn_stmt::ty_check_fn_stmt_named;
+
+impl TypeCheck for ImplStmtNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        let strct_name = self.strct_name()?;
+        let strct = ty_state
+            .scope
+            .expect_strct(strct_name.text(), strct_name.to_item())
+            .cloned();
+        if let Some(strct) = ty_state.ok_or_record(strct) {
+            let cur_mod_path = ty_state
+                .scope
+                .get_cur_mod_frame()
+                .map(|mod_frame| mod_frame.get_mod_tag().id.clone());
+            let l_strct = strct.read_recursive();
+            if cur_mod_path.as_ref() != Some(&l_strct.mod_path) {
+                ty_state.push_err(
+                    TyErr::ImplOfStrctOfOtherModule {
+                        impl_stmt: strct_name.to_item(),
+                        strct_decl: l_strct.decl.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+
+        for fn_stmt in self.fn_stmts() {
+            if let Some(name) = fn_stmt.name() {
+                let name = method_cmd_name(strct_name.text(), &name);
+                ty_check_fn_stmt_named(&fn_stmt, name, ty_state);
+            }
+        }
+
+        // An impl stmt doesn't return a value
+        None
+    }
+}
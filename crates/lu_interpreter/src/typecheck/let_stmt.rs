@@ -1,9 +1,16 @@
+use lu_error::TyErr;
 use lu_interpreter_structs::Value;
-use lu_pipeline_stage::ErrorContainer;
-use lu_syntax::ast::LetStmtNode;
+use lu_pipeline_stage::{ErrorContainer, PipelineStage};
+use lu_syntax::{
+    ast::{ArrayPatternNode, LetStmtNode, PipeOrValueExprElement, ValueExprElement},
+    AstElement, AstNode,
+};
 use rusttyc::TcKey;
 
-use crate::{TypeCheck, TypeCheckArg, ValueType, Variable};
+use crate::{
+    typecheck::match_stmt::typecheck_array_pattern, TyCheckState, TypeCheck, TypeCheckArg,
+    ValueType, Variable,
+};
 
 impl TypeCheck for LetStmtNode {
     fn do_typecheck(
@@ -31,6 +38,8 @@ impl TypeCheck for LetStmtNode {
                     .expect("Rhs val always returns ty");
                 ty_state.equate_keys(let_stmt_key, rhs_key);
             }
+        } else if self.pattern().is_some() || self.array_pattern().is_some() {
+            typecheck_destructuring_let(self, ty_state);
         } else {
             // Incomplete let stmt in parsing. This is okay
         }
@@ -39,3 +48,64 @@ impl TypeCheck for LetStmtNode {
         None
     }
 }
+
+/// Typechecks `let Ip{a b} = ...` and `let [first ...rest] = ...`
+fn typecheck_destructuring_let(let_stmt: &LetStmtNode, ty_state: &mut TyCheckState) {
+    let matched_key = ty_state.new_term_key(let_stmt.item_till_assign());
+    if let Some(decl_ty) = let_stmt.decl_ty() {
+        let ty_out = ValueType::from_node_or_err_resolve_strct_name(&decl_ty, &ty_state.scope);
+        let ty = ty_state.ok_and_record(ty_out);
+        ty_state.concretizes_key(matched_key, ty);
+    }
+    if let Some(rhs_val) = let_stmt.value() {
+        let rhs_key = rhs_val
+            .typecheck(ty_state)
+            .expect("Rhs val always returns ty");
+        ty_state.equate_keys(matched_key, rhs_key);
+    }
+
+    let mut refutable_patterns = Vec::new();
+    if let Some(pattern) = let_stmt.pattern() {
+        pattern.typecheck_with_args(&[TypeCheckArg::MatchPattern { matched_key }], ty_state);
+        if !pattern.is_irrefutable() {
+            refutable_patterns.push(pattern.to_item());
+        }
+    } else if let Some(pattern) = let_stmt.array_pattern() {
+        typecheck_array_pattern(&pattern, matched_key, ty_state);
+        check_arr_literal_len(let_stmt, &pattern, ty_state);
+        refutable_patterns.extend(
+            pattern
+                .elems()
+                .filter(|elem| !elem.is_irrefutable())
+                .map(|elem| elem.to_item()),
+        );
+    }
+
+    for pattern in refutable_patterns {
+        ty_state.push_err(TyErr::RefutablePatternInLet { pattern }.into());
+    }
+}
+
+/// The length of an array literal is known statically. If it has less elements than the
+/// pattern, the let would fail at runtime
+fn check_arr_literal_len(
+    let_stmt: &LetStmtNode,
+    pattern: &ArrayPatternNode,
+    ty_state: &mut TyCheckState,
+) {
+    if let Some(PipeOrValueExprElement::ValueExpr(ValueExprElement::ArrayExpr(arr))) =
+        let_stmt.value()
+    {
+        let arr_len = arr.values().count();
+        if arr_len < pattern.elems().count() {
+            ty_state.push_err(
+                TyErr::ArrayPatternLongerThanArray {
+                    arr: arr.to_item(),
+                    arr_len,
+                    pattern: pattern.to_item(),
+                }
+                .into(),
+            );
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use lu_error::{SourceCodeItem, TyErr};
+use lu_interpreter_structs::{ScopeFrameTag, ValueType, Variable};
+use lu_pipeline_stage::PipelineStage;
+use lu_syntax::{
+    ast::{
+        ArrayPatternNode, EnumPatternNode, MatchPatternElement, MatchStmtNode,
+        StrctFieldPatternNode, StrctPatternNode,
+    },
+    AstNode, AstNodeChildren, AstToken,
+};
+use rusttyc::TcKey;
+use std::sync::Arc;
+
+use crate::{typecheck::enum_stmt::expect_enum_variant, TyCheckState, TypeCheck, TypeCheckArg};
+
+impl TypeCheck for MatchStmtNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        let matched_key = self
+            .matched_value()
+            .and_then(|matched_value| matched_value.typecheck(ty_state));
+
+        for arm in self.arms() {
+            ty_state.scope.push_frame(ScopeFrameTag::MatchArmFrame);
+            if let (Some(pattern), Some(matched_key)) = (arm.pattern(), matched_key) {
+                pattern
+                    .typecheck_with_args(&[TypeCheckArg::MatchPattern { matched_key }], ty_state);
+            }
+            if let Some(block) = arm.block() {
+                block.typecheck(ty_state);
+            }
+            ty_state.scope.pop_frame(&ScopeFrameTag::MatchArmFrame);
+        }
+
+        warn_on_missing_opt_arm(self, ty_state);
+
+        None // Match does not return
+    }
+}
+
+impl TypeCheck for MatchPatternElement {
+    fn do_typecheck(&self, args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        let matched_key = match args.first() {
+            Some(TypeCheckArg::MatchPattern { matched_key }) => *matched_key,
+            _ => unreachable!("Patterns are always checked against a matched value"),
+        };
+
+        match self {
+            MatchPatternElement::LiteralPattern(n) => {
+                if let Some(lit_key) = n.value().and_then(|lit| lit.typecheck(ty_state)) {
+                    ty_state.equate_keys(matched_key, lit_key);
+                }
+            }
+            MatchPatternElement::BindingPattern(n) => {
+                if let Some(var_name) = n.var_name().filter(|_| !n.is_wildcard()) {
+                    let var_key = ty_state.insert_var(Variable::new_nil(
+                        var_name.text_trimmed(),
+                        var_name.to_item(),
+                    ));
+                    ty_state.equate_keys(var_key, matched_key);
+                }
+            }
+            MatchPatternElement::NonePattern(n) => {
+                opt_inner_key(matched_key, n.to_item(), ty_state);
+            }
+            MatchPatternElement::SomePattern(n) => {
+                if let (Some(inner_key), Some(inner_pattern)) = (
+                    opt_inner_key(matched_key, n.to_item(), ty_state),
+                    n.inner_pattern(),
+                ) {
+                    inner_pattern.typecheck_with_args(
+                        &[TypeCheckArg::MatchPattern {
+                            matched_key: inner_key,
+                        }],
+                        ty_state,
+                    );
+                }
+            }
+            MatchPatternElement::StrctPattern(n) => {
+                typecheck_strct_pattern(n, matched_key, ty_state)
+            }
+            MatchPatternElement::EnumPattern(n) => typecheck_enum_pattern(n, matched_key, ty_state),
+        }
+
+        None
+    }
+}
+
+/// Returns the key of the inner value of the optional behind matched_key
+fn opt_inner_key(
+    matched_key: TcKey,
+    pattern_item: SourceCodeItem,
+    ty_state: &mut TyCheckState,
+) -> Option<TcKey> {
+    if ty_state.get_optional_inner_tc(&matched_key).is_none() {
+        let pattern_key = ty_state.new_term_key_concretiziesd(
+            pattern_item.clone(),
+            ValueType::new_optional(ValueType::Unspecified, pattern_item),
+        );
+        ty_state.equate_keys(matched_key, pattern_key);
+    }
+    // If the matched value is not optional, equating above already reported the error
+    ty_state.get_optional_inner_tc(&matched_key).cloned()
+}
+
+/// Checks `[first ...rest]` against the array behind matched_key
+pub(crate) fn typecheck_array_pattern(
+    pattern: &ArrayPatternNode,
+    matched_key: TcKey,
+    ty_state: &mut TyCheckState,
+) {
+    if ty_state.get_arr_inner_tc(&matched_key).is_none() {
+        let pattern_key = ty_state.new_term_key_concretiziesd(
+            pattern.to_item(),
+            ValueType::new_array(ValueType::Unspecified, pattern.to_item()),
+        );
+        ty_state.equate_keys(matched_key, pattern_key);
+    }
+    // If the matched value is not an array, equating above already reported the error
+    let inner_key = match ty_state.get_arr_inner_tc(&matched_key).cloned() {
+        Some(inner_key) => inner_key,
+        None => return,
+    };
+
+    for elem in pattern.elems() {
+        elem.typecheck_with_args(
+            &[TypeCheckArg::MatchPattern {
+                matched_key: inner_key,
+            }],
+            ty_state,
+        );
+    }
+    if let Some(var_name) = pattern.rest().and_then(|rest| rest.var_name()) {
+        // The rest is an array itself
+        let var_key = ty_state.insert_var(Variable::new_nil(
+            var_name.text_trimmed(),
+            var_name.to_item(),
+        ));
+        ty_state.equate_keys(var_key, matched_key);
+    }
+}
+
+fn typecheck_strct_pattern(
+    pattern: &StrctPatternNode,
+    matched_key: TcKey,
+    ty_state: &mut TyCheckState,
+) {
+    let strct_name = match pattern.name() {
+        Some(name) => name,
+        None => return, // Parser already reported an error
+    };
+    let strct = match ty_state
+        .expect_strct_from_usage(strct_name.text(), strct_name.to_item())
+        .cloned()
+    {
+        Some(strct) => strct,
+        None => return,
+    };
+    let pattern_key = ty_state.new_term_key_equated(pattern.to_item(), strct.self_key);
+    ty_state.equate_keys(matched_key, pattern_key);
+
+    let field_keys: Vec<(String, TcKey)> = strct
+        .field_keys
+        .iter()
+        .map(|field| (field.name.clone(), field.ty))
+        .collect();
+    let strct_decl = ty_state.get_item_of(&strct.self_key).clone();
+    typecheck_field_patterns(pattern.fields(), &field_keys, strct_decl, ty_state);
+}
+
+fn typecheck_enum_pattern(
+    pattern: &EnumPatternNode,
+    matched_key: TcKey,
+    ty_state: &mut TyCheckState,
+) {
+    let (enm, variant) =
+        match expect_enum_variant(&pattern.enum_name(), pattern.variant_name(), ty_state) {
+            Some(enum_variant) => enum_variant,
+            None => return,
+        };
+    let pattern_key = ty_state
+        .new_term_key_concretiziesd(pattern.to_item(), ValueType::Enum(Arc::downgrade(&enm)));
+    ty_state.equate_keys(matched_key, pattern_key);
+
+    let field_keys: Vec<(String, TcKey)> = variant
+        .fields
+        .iter()
+        .map(|field| {
+            let key = ty_state.new_term_key_concretiziesd(field.decl.clone(), field.ty.clone());
+            (field.name.clone(), key)
+        })
+        .collect();
+    typecheck_field_patterns(pattern.fields(), &field_keys, variant.decl, ty_state);
+}
+
+/// Checks the field patterns against the fields (name and key) of a struct (or enum variant)
+fn typecheck_field_patterns(
+    field_patterns: AstNodeChildren<StrctFieldPatternNode>,
+    field_keys: &[(String, TcKey)],
+    decl: SourceCodeItem,
+    ty_state: &mut TyCheckState,
+) {
+    for field in field_patterns {
+        let field_name = field.field_name();
+        let field_key = match field_keys
+            .iter()
+            .find(|(name, _)| name == field_name.text())
+        {
+            Some((_, key)) => *key,
+            None => {
+                ty_state.push_err(
+                    TyErr::StructDoesNotHaveField {
+                        field_name: field_name.text().to_string(),
+                        strct_decl: decl.clone(),
+                        usage: field_name.to_item(),
+                    }
+                    .into(),
+                );
+                continue;
+            }
+        };
+
+        if let Some(field_pattern) = field.pattern() {
+            field_pattern.typecheck_with_args(
+                &[TypeCheckArg::MatchPattern {
+                    matched_key: field_key,
+                }],
+                ty_state,
+            );
+        } else {
+            // Ip{a} binds the field a to the var a
+            let var_key = ty_state.insert_var(Variable::new_nil(
+                field_name.text().to_string(),
+                field_name.to_item(),
+            ));
+            ty_state.equate_keys(var_key, field_key);
+        }
+    }
+}
+
+/// A match over an optional value should either cover None and Some, or have an arm matching
+/// everything
+fn warn_on_missing_opt_arm(match_stmt: &MatchStmtNode, ty_state: &mut TyCheckState) {
+    let patterns: Vec<MatchPatternElement> =
+        match_stmt.arms().filter_map(|arm| arm.pattern()).collect();
+    let is_opt_match = patterns.iter().any(|pattern| {
+        matches!(
+            pattern,
+            MatchPatternElement::NonePattern(_) | MatchPatternElement::SomePattern(_)
+        )
+    });
+    if !is_opt_match || patterns.iter().any(MatchPatternElement::is_irrefutable) {
+        return;
+    }
+
+    let has_none_arm = patterns
+        .iter()
+        .any(|pattern| matches!(pattern, MatchPatternElement::NonePattern(_)));
+    let has_some_arm = patterns.iter().any(|pattern| match pattern {
+        MatchPatternElement::SomePattern(n) => n
+            .inner_pattern()
+            .map(|inner| inner.is_irrefutable())
+            .unwrap_or(false),
+        _ => false,
+    });
+
+    let missing_arm = if !has_none_arm {
+        "None"
+    } else if !has_some_arm {
+        "Some{_}"
+    } else {
+        return;
+    };
+    ty_state.push_warning(
+        TyErr::NonExhaustiveOptMatch {
+            match_stmt: match_stmt.decl_item(),
+            missing_arm: missing_arm.to_string(),
+        }
+        .into(),
+    );
+}
@@ -1,67 +1,101 @@
+use lu_error::TyErr;
 use lu_interpreter_structs::ValueType;
 use lu_pipeline_stage::PipelineStage;
 use lu_syntax::{
-    ast::{MathExprNode, OperatorExprElement, ValueExprElement},
-    AstNode,
+    ast::{
+        MathExprNode, OperatorExprElement, UnaryExprNode, UnaryOperatorElement, ValueExprElement,
+    },
+    AstElement, AstNode,
 };
 use rusttyc::TcKey;
+use std::mem;
 
+use super::fn_stmt::keys_of_fn_generics;
 use crate::{TyCheckState, TypeCheck, TypeCheckArg};
 
 impl TypeCheck for MathExprNode {
     fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
-        match self.operator() {
-            OperatorExprElement::AsKeyword(_) => {
-                // let lhs_key = self.lhs().typecheck(state).expect("ValueExpr always gives key");
-                // TODO this is currently a noop, as anything can be Any. It should somehow be
-                // expressable, that only any is allowed here
-                // state.concretizes_key(lhs_key, ValueType::Any);
+        // As cast is handled special
+        let operator = self.operator();
+        if let OperatorExprElement::AsKeyword(_) = operator {
+            // let lhs_key = self.lhs().typecheck(state).expect("ValueExpr always gives key");
+            // TODO this is currently a noop, as anything can be Any. It should somehow be
+            // expressable, that only any is allowed here
+            // state.concretizes_key(lhs_key, ValueType::Any);
 
-                if let Some(ty) = self.rhs_as_lu_type() {
-                    match ValueType::from_node_or_err_resolve_strct_name(&ty, &state.scope)
-                        .as_results()
-                    {
-                        Ok(ty) => {
-                            return Some(state.new_term_key_concretiziesd(self.to_item(), ty))
+            if let Some(ty) = self.rhs_as_lu_type() {
+                match ValueType::from_node_or_err_resolve_strct_name(&ty, &state.scope).as_results()
+                {
+                    Ok(ty) => {
+                        if let ValueType::Func(_) = ty {
+                            // Whether the func is compatible is checked after the final solve
+                            if let Some(lhs_key) = self.lhs().typecheck(state) {
+                                state.fn_casts.push((lhs_key, ty.clone(), self.to_item()));
+                            }
                         }
-                        Err(e) => state.push_errs(e),
+                        return Some(state.new_term_key_concretiziesd(self.to_item(), ty));
                     }
-                } else {
-                    // Either incomplete input, or grammar already gave warning here :)
+                    Err(e) => state.push_errs(e),
                 }
-                None
+            } else {
+                // Either incomplete input, or grammar already gave warning here :)
             }
-            OperatorExprElement::PlusSign(_)
-            | OperatorExprElement::MinusSign(_)
-            | OperatorExprElement::MultSign(_)
-            | OperatorExprElement::DivSign(_) => {
-                let (lhs, _) = equate(&self.lhs(), &self.rhs(), state);
+            return None;
+        }
+
+        let rhs = match self.rhs_safe() {
+            Some(rhs) => rhs,
+            None => {
+                // The missing rhs has been reported by the parser (`echo 50%`)
+                return Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Error));
+            }
+        };
+        match operator {
+            OperatorExprElement::AsKeyword(_) => unreachable!("Handled above"),
+            OperatorExprElement::PlusSign(_) => {
+                let lhs = self.lhs().typecheck(state).unwrap();
+                let rhs = rhs.typecheck(state).unwrap();
+                // Whether the operands are addable is checked after the final solve
+                state.additions.push((lhs, rhs, self.to_item()));
+                state.equate_keys(lhs, rhs);
                 Some(state.new_term_key_equated(self.to_item(), lhs))
             }
+            OperatorExprElement::MinusSign(_)
+            | OperatorExprElement::MultSign(_)
+            | OperatorExprElement::DivSign(_)
+            | OperatorExprElement::ModSign(_)
+            | OperatorExprElement::PowSign(_) => {
+                concretize(&self.lhs(), ValueType::Number, state);
+                concretize(&rhs, ValueType::Number, state);
+                Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Number))
+            }
             OperatorExprElement::LessThanSign(_)
             | OperatorExprElement::LessOrEqualSign(_)
             | OperatorExprElement::BiggerThanSign(_)
             | OperatorExprElement::BiggerOrEqualSign(_)
             | OperatorExprElement::InequalitySign(_)
             | OperatorExprElement::EqualitySign(_) => {
-                equate(&self.lhs(), &self.rhs(), state);
+                equate(&self.lhs(), &rhs, state);
                 Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Bool))
             }
             OperatorExprElement::OrKeyword(_) | OperatorExprElement::AndKeyword(_) => {
                 concretize(&self.lhs(), ValueType::Bool, state);
-                concretize(&self.rhs(), ValueType::Bool, state);
+                concretize(&rhs, ValueType::Bool, state);
                 Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Bool))
             }
             OperatorExprElement::DivAssignSign(_)
             | OperatorExprElement::MulAssignSign(_)
             | OperatorExprElement::AddAssignSign(_)
             | OperatorExprElement::MinAssignSign(_) => {
-                concretize(&self.lhs(), ValueType::Number, state);
-                concretize(&self.rhs(), ValueType::Number, state);
+                let lhs_key = ty_check_assign_target(&self.lhs(), state);
+                state.concretizes_key(lhs_key, ValueType::Number);
+                concretize(&rhs, ValueType::Number, state);
                 None
             }
             OperatorExprElement::AssignSign(_) => {
-                equate(&self.lhs(), &self.rhs(), state);
+                let lhs_key = ty_check_assign_target(&self.lhs(), state);
+                let rhs_key = rhs.typecheck(state).unwrap();
+                state.equate_keys(lhs_key, rhs_key);
                 // Assignment does not return type
                 None
             }
@@ -69,6 +103,82 @@ impl TypeCheck for MathExprNode {
     }
 }
 
+/// Checks that the values cast to a fn ty (`$f as fn(...)`) are compatible with it. Values of
+/// other (e.g. `any`) tys are checked at runtime
+pub(crate) fn check_fn_casts(state: &mut TyCheckState) {
+    for (lhs_key, cast_ty, cast_expr) in mem::take(&mut state.fn_casts) {
+        // Funcs passed by name are only known by their TcFunc
+        let found_ty = match (state.ty_table.get(&lhs_key), state.get_tc_func(&lhs_key)) {
+            (Some(found_ty @ ValueType::Func(_)), _) => found_ty.clone(),
+            (_, Some(tc_func)) => ValueType::new_func(tc_func.sign.clone()),
+            _ => continue,
+        };
+        if !found_ty.is_assignable_to(&cast_ty) {
+            state.push_err(
+                TyErr::FnNotCastable {
+                    cast_expr,
+                    expected_ty: cast_ty.to_string(),
+                    found_ty: found_ty.to_string(),
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+impl TypeCheck for UnaryExprNode {
+    fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
+        let ty = match self.operator() {
+            UnaryOperatorElement::MinusSign(_) => ValueType::Number,
+            UnaryOperatorElement::NotKeyword(_) => ValueType::Bool,
+        };
+        if let Some(operand) = self.operand() {
+            concretize(&operand, ty.clone(), state);
+        }
+        Some(state.new_term_key_concretiziesd(self.to_item(), ty))
+    }
+}
+
+/// `+` is only defined for numbers, strings and arrays. Records an error for every addition,
+/// whose operands are inferred to be none of them or are generic (and might be none of them)
+pub(crate) fn check_additions(state: &mut TyCheckState) {
+    let generic_of_key = keys_of_fn_generics(state);
+    for (lhs, rhs, expr) in mem::take(&mut state.additions) {
+        if let Some(generic_idx) = generic_of_key.get(&lhs) {
+            let generic_name = state.fn_generics[*generic_idx].1.clone();
+            let operand_tys = vec![generic_name.clone(), generic_name];
+            state.push_err(TyErr::OperatorNotApplicable { expr, operand_tys }.into());
+            continue;
+        }
+        match state.ty_table.get(&lhs) {
+            Some(
+                ValueType::Number
+                | ValueType::String
+                | ValueType::BareWord
+                | ValueType::Array { .. }
+                | ValueType::Any
+                | ValueType::Unspecified
+                | ValueType::Error,
+            )
+            | None => {}
+            Some(lhs_ty) => {
+                let rhs_ty = state.ty_table.get(&rhs).unwrap_or(lhs_ty);
+                let operand_tys = vec![lhs_ty.to_string(), rhs_ty.to_string()];
+                state.push_err(TyErr::OperatorNotApplicable { expr, operand_tys }.into())
+            }
+        }
+    }
+}
+
+/// Ty checks the lhs of an assignment. Only (paths into) vars can be assigned to
+fn ty_check_assign_target(lhs: &ValueExprElement, state: &mut TyCheckState) -> TcKey {
+    if !matches!(lhs, ValueExprElement::ValuePathExpr(_)) {
+        state.push_err(TyErr::NotAssignable(lhs.to_item()).into());
+    }
+    lhs.typecheck_with_args(&[TypeCheckArg::AssignTarget], state)
+        .expect("ValueExpr always gives key")
+}
+
 /// Equates lhs with rhs and returns (LhsKey, RhsKey)
 fn equate(
     lhs: &ValueExprElement,
@@ -14,19 +14,10 @@ impl TypeCheck for RetStmtNode {
     ) -> Option<TcKey> {
         let self_item = self.ret_kw().to_item();
 
-        let cmd_id = ty_state.scope.find_cur_command().map(|callable| {
-            (
-                callable.name().to_string(),
-                callable.signature().req_flags(),
-            )
-        });
+        let cur_cmd = ty_state.scope.find_cur_command().cloned();
 
-        if let Some((cur_cmd_name, cur_cmd_req_flags)) = cmd_id {
-            if let Some(tc_func) = ty_state.expect_tc_cmd_from_cmd_usage(
-                &cur_cmd_name,
-                &cur_cmd_req_flags,
-                self_item.clone(),
-            ) {
+        if let Some(cur_cmd) = cur_cmd {
+            if let Some(tc_func) = ty_state.get_tc_cmd_from_rc_cmd(&cur_cmd) {
                 let ret_stmt_key =
                     ty_state.new_term_key_equated(self_item, tc_func.ret_key.clone());
                 if let Some(ret_value) = self.returned_val() {
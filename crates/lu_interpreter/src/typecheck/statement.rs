@@ -11,10 +11,17 @@ impl TypeCheck for StatementElement {
     ) -> Option<TcKey> {
         match self {
             StatementElement::ForStmt(n) => n.typecheck(ty_state),
+            StatementElement::WhileStmt(n) => n.typecheck(ty_state),
+            StatementElement::BreakStmt(n) => n.typecheck(ty_state),
+            StatementElement::ContinueStmt(n) => n.typecheck(ty_state),
+            StatementElement::TryStmt(n) => n.typecheck(ty_state),
+            StatementElement::MatchStmt(n) => n.typecheck(ty_state),
             StatementElement::IfElifElseStmt(n) => n.typecheck(ty_state),
             StatementElement::PipedCmdsStmt(n) => n.typecheck(ty_state),
+            StatementElement::BackgroundStmt(n) => n.typecheck(ty_state),
             StatementElement::LetStmt(n) => n.typecheck(ty_state),
             StatementElement::FnStmt(n) => n.typecheck(ty_state),
+            StatementElement::ImplStmt(n) => n.typecheck(ty_state),
             StatementElement::CmdStmt(n) => n.typecheck(ty_state),
             StatementElement::RetStmt(n) => n.typecheck(ty_state),
             StatementElement::ValueExpr(n) => n.typecheck(ty_state),
@@ -0,0 +1,36 @@
+use lu_interpreter_structs::{err_strct_ty, Value};
+use lu_syntax::{ast::TryStmtNode, AstToken};
+use rusttyc::TcKey;
+
+use crate::{visit_arg::VisitArg, ScopeFrameTag, TyCheckState, TypeCheck, TypeCheckArg, Variable};
+
+impl TypeCheck for TryStmtNode {
+    fn do_typecheck(&self, _args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        if let Some(try_block) = self.try_block() {
+            try_block.typecheck_with_args(
+                &[TypeCheckArg::Arg(VisitArg::BlockTypeArg(
+                    ScopeFrameTag::TryStmtFrame,
+                ))],
+                ty_state,
+            );
+        }
+
+        if let Some(catch_stmt) = self.catch_stmt() {
+            ty_state.scope.push_frame(ScopeFrameTag::CatchStmtFrame);
+            if let Some(var_name) = catch_stmt.var_name() {
+                let var_key = ty_state.insert_var(Variable::new(
+                    var_name.text_trimmed(),
+                    Value::Nil,
+                    var_name.to_item(),
+                ));
+                ty_state.concretizes_key(var_key, err_strct_ty());
+            }
+            if let Some(block) = catch_stmt.block() {
+                block.typecheck(ty_state);
+            }
+            ty_state.scope.pop_frame(&ScopeFrameTag::CatchStmtFrame);
+        }
+
+        None // Try does not return
+    }
+}
@@ -1,58 +1,120 @@
-use lu_error::TyErr;
+use lu_error::{SourceCodeItem, TyErr};
 use lu_pipeline_stage::PipelineStage;
-use lu_syntax::{ast::ValuePathExprNode, AstNode};
+use lu_syntax::{
+    ast::{ValuePathExprNode, ValuePathPart},
+    AstNode,
+};
 use rusttyc::TcKey;
 
-use crate::{TyCheckState, TypeCheck, TypeCheckArg, ValueType};
+use crate::{TcFunc, TyCheckState, TypeCheck, TypeCheckArg, ValueType};
 
 impl TypeCheck for ValuePathExprNode {
-    fn do_typecheck(&self, _: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
-        let var_parts = self.var_name_parts_with_item();
-        let mut prev_key: Option<TcKey> = None;
-        for (part, item) in var_parts {
-            if let Some(last_key) = prev_key {
-                // we have a part which is dependend on the previous value_path part
-                // e.G. $a.b (with part == "b" and last_key = key_of(a))
-                if let Some(strct) = state.expect_strct_from_key(&last_key).cloned() {
-                    if let Some(key) = strct
-                        .field_keys
-                        .iter()
-                        .find(|field| &field.name == &part)
-                        .map(|field| field.ty.clone())
-                    {
-                        prev_key = Some(key)
-                    } else {
-                        state.push_err(
-                            TyErr::StructDoesNotHaveField {
-                                field_name: part.to_string(),
-                                strct_decl: state.get_item_of(&strct.self_key).clone(),
-                                usage: item,
+    fn do_typecheck(&self, args: &[TypeCheckArg], state: &mut TyCheckState) -> Option<TcKey> {
+        let is_assign_target = matches!(args.first(), Some(TypeCheckArg::AssignTarget));
+        let (var_name, var_item) = self.var_name_with_item();
+        let mut prev_key = if let Some(var_key) = state.expect_key_from_var(&var_name, var_item) {
+            var_key
+        } else {
+            // Var not present, error key
+            return Some(state.new_term_key_concretiziesd(self.to_item(), ValueType::Error));
+        };
+
+        for (part, item) in self.parts_with_item() {
+            // we have a part which is dependend on the previous value_path part
+            // e.G. $a.b (with part == "b" and prev_key = key_of(a))
+            match part {
+                ValuePathPart::Field(_) if state.get_map_inner_tc(&prev_key).is_some() => {
+                    // Accessing a key of a map. Whether the key is present is only known at
+                    // runtime
+                    prev_key = *state.get_map_inner_tc(&prev_key).unwrap();
+                }
+                ValuePathPart::Field(field_name) => {
+                    if let Some(strct) = state.expect_strct_from_key(&prev_key).cloned() {
+                        if let Some(key) = strct
+                            .field_keys
+                            .iter()
+                            .find(|field| field.name == field_name)
+                            .map(|field| field.ty)
+                        {
+                            prev_key = key
+                        } else if let Some(method) = state.tc_method_of(&strct, &field_name) {
+                            if is_assign_target {
+                                state.push_err(TyErr::NotAssignable(item.clone()).into());
                             }
-                            .into(),
-                        );
-                        // Immediate return as this can't recover here
+                            prev_key = ty_check_method_call(method, prev_key, item, state);
+                        } else {
+                            state.push_err(
+                                TyErr::StructDoesNotHaveField {
+                                    field_name,
+                                    strct_decl: state.get_item_of(&strct.self_key).clone(),
+                                    usage: item,
+                                }
+                                .into(),
+                            );
+                            // Immediate return as this can't recover here
+                            return Some(
+                                state.new_term_key_concretiziesd(self.to_item(), ValueType::Error),
+                            );
+                        }
+                    }
+                }
+                ValuePathPart::Index(_) => {
+                    if let Some(inner_key) = state.expect_arr_inner_ty_from_key(prev_key) {
+                        prev_key = inner_key
+                    } else {
                         return Some(
                             state.new_term_key_concretiziesd(self.to_item(), ValueType::Error),
                         );
                     }
                 }
-            } else {
-                // No previous part, this is no field indexing
-                if let Some(var_key) = state.expect_key_from_var(&part, item) {
-                    prev_key = Some(var_key)
-                } else {
-                    // Var not present, error key
-                    return Some(
-                        state.new_term_key_concretiziesd(self.to_item(), ValueType::Error),
-                    );
+                ValuePathPart::Slice { .. } => {
+                    if is_assign_target {
+                        state.push_err(TyErr::NotAssignable(item).into());
+                    }
+                    // A slice of an array is an array of the same type
+                    if state.expect_arr_inner_ty_from_key(prev_key).is_none() {
+                        return Some(
+                            state.new_term_key_concretiziesd(self.to_item(), ValueType::Error),
+                        );
+                    }
                 }
             }
         }
 
-        if let Some(prev_key) = prev_key {
-            Some(state.new_term_key_equated(self.to_item(), prev_key))
-        } else {
-            None
+        Some(state.new_term_key_equated(self.to_item(), prev_key))
+    }
+}
+
+/// Ty checks the call of method with the value of self_key as its in value (`$ip.to_string`).
+/// Arguments can't be passed this way. Returns the key of the returned value
+fn ty_check_method_call(
+    method: TcFunc,
+    self_key: TcKey,
+    call: SourceCodeItem,
+    state: &mut TyCheckState,
+) -> TcKey {
+    state.equate_keys(method.in_key, self_key);
+    for (arg, _) in &method.args_keys {
+        if !arg.may_be_omitted() {
+            state.push_err(
+                TyErr::UnsatisfiedArg {
+                    arg_decl: arg.decl.clone(),
+                    cmd_stmt: call.clone(),
+                }
+                .into(),
+            );
+        }
+    }
+    for (flag, _) in &method.flags_keys {
+        if flag.is_required() {
+            state.push_err(
+                TyErr::NotPassedRequiredFlag {
+                    flag_decl: flag.decl.clone(),
+                    cmd_stmt: call.clone(),
+                }
+                .into(),
+            );
         }
     }
+    method.ret_key
 }
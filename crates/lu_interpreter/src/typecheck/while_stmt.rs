@@ -0,0 +1,48 @@
+use lu_error::AstErr;
+use lu_pipeline_stage::PipelineStage;
+use lu_syntax::{
+    ast::{BreakStmtNode, ContinueStmtNode, WhileStmtNode},
+    AstNode,
+};
+use rusttyc::TcKey;
+
+use crate::{visit_arg::VisitArg, ScopeFrameTag, TyCheckState, TypeCheck, TypeCheckArg};
+
+impl TypeCheck for WhileStmtNode {
+    fn do_typecheck(&self, _args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        if let Some(condition) = self.condition() {
+            condition.typecheck(ty_state);
+        }
+
+        if let Some(block) = self.block() {
+            block.typecheck_with_args(
+                &[TypeCheckArg::Arg(VisitArg::BlockTypeArg(
+                    ScopeFrameTag::WhileStmtFrame,
+                ))],
+                ty_state,
+            );
+        }
+
+        None // While does not return
+    }
+}
+
+impl TypeCheck for BreakStmtNode {
+    fn do_typecheck(&self, _args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        check_is_within_loop(self.to_item(), ty_state);
+        None
+    }
+}
+
+impl TypeCheck for ContinueStmtNode {
+    fn do_typecheck(&self, _args: &[TypeCheckArg], ty_state: &mut TyCheckState) -> Option<TcKey> {
+        check_is_within_loop(self.to_item(), ty_state);
+        None
+    }
+}
+
+fn check_is_within_loop(stmt: lu_error::SourceCodeItem, ty_state: &mut TyCheckState) {
+    if !ty_state.scope.ctx_is_within_loop() {
+        ty_state.push_err(AstErr::LoopCtrlOutsideOfLoop(stmt).into());
+    }
+}
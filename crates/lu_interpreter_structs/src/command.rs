@@ -162,26 +162,23 @@ pub trait Command: CommandClone + Debug {
         Rc::new(self)
     }
 
-    /// A command is called if the cmd.name() and at least all required flags are passed
+    /// A command is called if the cmd.name() and at least all required flags are passed. All
+    /// passed flags have to be declared by the command
     fn is_called_by(&self, called_cmd_name: &str, passed_flags: &[FlagVariant]) -> bool {
-        // TODO is this logic correct?
+        let flags = &self.signature().flags;
         self.name() == called_cmd_name
-            && passed_flags.iter().all(|passed_flag| {
-                self.signature().flags.iter().any(|flag_decl| {
-                    flag_decl.is_required()
-                        && match passed_flag {
-                            FlagVariant::LongFlag(name) => flag_decl
-                                .long_name
-                                .as_ref()
-                                .map(|flag_decl_name| flag_decl_name == name)
-                                .unwrap_or(false),
-                            FlagVariant::ShortFlag(name) => flag_decl
-                                .short_name
-                                .as_ref()
-                                .map(|flag_decl_name| flag_decl_name == name)
-                                .unwrap_or(false),
-                        }
+            && flags
+                .iter()
+                .filter(|flag_decl| flag_decl.is_required())
+                .all(|flag_decl| {
+                    passed_flags
+                        .iter()
+                        .any(|passed_flag| flag_decl.is_named_by_flag(passed_flag))
                 })
+            && passed_flags.iter().all(|passed_flag| {
+                flags
+                    .iter()
+                    .any(|flag_decl| flag_decl.is_named_by_flag(passed_flag))
             })
     }
 }
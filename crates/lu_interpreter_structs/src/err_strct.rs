@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use lu_error::{lu_source_code_item, LuErr, SourceCodeItem};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+use crate::{ModPath, Scope, ScopeFrame, Strct, StrctField, Value, ValueType, Variable};
+
+// The strct of a caught error (the var of a catch block)
+pub const ERR_STRCT_NAME: &str = "Error";
+const ERR_STRCT_FIELD_0: &str = "kind";
+const ERR_STRCT_FIELD_1: &str = "message";
+/// The source code, where the error occurred
+const ERR_STRCT_FIELD_2: &str = "code";
+/// The file (module) containing the code and the position of the code in it. Line and column
+/// start at 1
+const ERR_STRCT_FIELD_3: &str = "file";
+const ERR_STRCT_FIELD_4: &str = "line";
+const ERR_STRCT_FIELD_5: &str = "column";
+
+pub static ERR_STRCT: Lazy<Arc<RwLock<Strct>>> = Lazy::new(|| {
+    let decl = lu_source_code_item!();
+    let fields = [
+        (ERR_STRCT_FIELD_0, ValueType::String),
+        (ERR_STRCT_FIELD_1, ValueType::String),
+        (ERR_STRCT_FIELD_2, ValueType::String),
+        (ERR_STRCT_FIELD_3, ValueType::String),
+        (ERR_STRCT_FIELD_4, ValueType::Number),
+        (ERR_STRCT_FIELD_5, ValueType::Number),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, (name, ty))| StrctField::new(name.to_string(), ty.clone(), i as u32, decl.clone()))
+    .collect();
+    Arc::new(RwLock::new(Strct::new(
+        ERR_STRCT_NAME.into(),
+        fields,
+        decl,
+        ModPath::builtin(),
+    )))
+});
+
+/// Makes the strct of caught errors known (so that its fields can be type checked)
+pub fn init_err_strct(frame: &mut ScopeFrame<Variable>) {
+    frame.insert_var(Variable::new(
+        ERR_STRCT_NAME.to_string(),
+        Value::StrctDecl(ERR_STRCT.clone()),
+        lu_source_code_item!(),
+    ));
+}
+
+pub fn err_strct_ty() -> ValueType {
+    ValueType::Strct(Arc::downgrade(&*ERR_STRCT))
+}
+
+/// Converts the caught `err` into a value of the ERR_STRCT
+pub fn err_strct_val(err: &LuErr, scope: &Scope<Variable>) -> Value {
+    let (kind, message, item) = match err {
+        LuErr::Eval(err) => (err.kind(), err.message(), err.item()),
+        LuErr::Internal(msg) => ("Internal".to_string(), msg.clone(), None),
+        _ => ("Error".to_string(), format!("{:?}", err), None),
+    };
+    let (code, (file, line, column)) = match item {
+        Some(item) => (item.content.clone(), location_of(item, scope)),
+        None => (String::new(), (String::new(), 0, 0)),
+    };
+    Value::new_strct(
+        ERR_STRCT.clone(),
+        vec![
+            (ERR_STRCT_FIELD_0.into(), Value::String(kind)),
+            (ERR_STRCT_FIELD_1.into(), Value::String(message)),
+            (ERR_STRCT_FIELD_2.into(), Value::String(code)),
+            (ERR_STRCT_FIELD_3.into(), Value::String(file)),
+            (
+                ERR_STRCT_FIELD_4.into(),
+                Value::Number((line as f64).into()),
+            ),
+            (
+                ERR_STRCT_FIELD_5.into(),
+                Value::Number((column as f64).into()),
+            ),
+        ],
+    )
+}
+
+/// Returns the file, line and column of item. If the module of item is not found (e.g. for
+/// items of rust code), the file is empty and line and column are 0
+fn location_of(item: &SourceCodeItem, scope: &Scope<Variable>) -> (String, usize, usize) {
+    let modi = scope
+        .get_all_frames()
+        .filter_map(|frame| frame.tag.as_module_frame())
+        .find(|modi| modi.mod_int_address() == Some(item.sf_node_addr));
+    let modi = match modi {
+        Some(modi) => modi,
+        None => return (String::new(), 0, 0),
+    };
+    let start: usize = item.range.start().into();
+    let before_item = modi.src.text.get(..start).unwrap_or_default();
+    let line = before_item.matches('\n').count() + 1;
+    let line_start = before_item.rfind('\n').map_or(0, |nl_pos| nl_pos + 1);
+    let column = before_item[line_start..].chars().count() + 1;
+    (modi.id.to_string(), line, column)
+}
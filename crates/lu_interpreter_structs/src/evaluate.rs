@@ -7,6 +7,12 @@ use crate::Value;
 pub enum RetValOrErr {
     RetVal(Value),
     Err(LuErr),
+    /// Leave the innermost loop
+    #[from(ignore)]
+    Break,
+    /// Continue with the next iteration of the innermost loop
+    #[from(ignore)]
+    Continue,
 }
 
 pub type EvalResult = Result<Value, RetValOrErr>;
@@ -1,9 +1,13 @@
-use crate::{CmdAttribute, SyScope, Value, external_cmds_attr::{EXT_CMDS_ATTRIBUTES, EXT_CMDS_DEF_ATTRIBUTES}};
+use crate::{set_status_var, CmdAttribute, SyScope, Value, external_cmds_attr::{EXT_CMDS_ATTRIBUTES, EXT_CMDS_DEF_ATTRIBUTES}};
 use lu_syntax_elements::constants::{IN_ARG_NAME, RET_ARG_NAME, VAR_ARGS_DEF_NAME};
-use std::{io::Write, process::Stdio};
+use std::{
+    io::Write,
+    os::unix::process::{CommandExt, ExitStatusExt},
+    process::{Child, ExitStatus, Output, Stdio},
+};
 
 use crate::external_cmd;
-use lu_error::{lu_source_code_item, EvalErr, LuResult, SourceCodeItem};
+use lu_error::{lu_source_code_item, EvalErr, LuErr, LuResult, SourceCodeItem};
 use once_cell::unsync::OnceCell;
 
 use crate::{ArgSignature, Command, Signature, ValueType};
@@ -33,53 +37,38 @@ pub struct RunExternalCmd {
     // Command
     #[new(default)]
     signature: OnceCell<Signature>,
+    /// Whether the cmd writes directly to the terminal, instead of its output being captured
+    #[new(default)]
+    inherit_tty: bool,
+    /// The process group to spawn the cmd in. 0 creates a new group led by the cmd
+    #[new(default)]
+    process_group: Option<i32>,
 }
 
-impl Command for RunExternalCmd {
-    fn name(&self) -> &str {
-        &self.cmd_name
-    }
-
-    fn signature(&self) -> &Signature {
-        self.signature
-            .get_or_init(|| external_cmd::external_cmd_signature())
-    }
-
-    fn signature_item(&self) -> SourceCodeItem {
-        lu_source_code_item!() // TODO fixup line number
-    }
-
-    fn parent_module(&self) -> Option<&crate::ModPath> {
-        None
+impl RunExternalCmd {
+    /// Lets the spawned process inherit the terminal of lush. Its output is not captured and
+    /// the cmd returns nil. If the cmd has no input, it reads directly from the terminal
+    pub fn inheriting_tty(mut self) -> Self {
+        self.inherit_tty = true;
+        self
     }
 
-    fn attributes(&self) -> &[CmdAttribute] {
-        EXT_CMDS_ATTRIBUTES
-            .get(self.name())
-            .map(|attrs| attrs.as_ref())
-            .unwrap_or(&EXT_CMDS_DEF_ATTRIBUTES)
+    /// Spawns the cmd in the process group `pgid` (0 for a new group), so that it can be
+    /// signaled separately from lush
+    pub fn in_process_group(mut self, pgid: i32) -> Self {
+        self.process_group = Some(pgid);
+        self
     }
 
-    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
-        let l_scope = scope.lock();
-
-        let args = self.expect_args(
-            &self
-                .signature()
-                .var_arg
-                .as_ref()
-                .expect("ExternalCmd has vararg")
-                .name,
-            &l_scope,
-        );
-
-        // Historic shells expand wildcards (*, **) to all files matching the pattern in the
-        // current PWD. Lush doesn't do the same automatically for internal cmds. For better
-        // compatability, we now expand filenames
+    /// Converts the args to strings, as passed to the external cmd.
+    /// Historic shells expand wildcards (*, **) to all files matching the pattern in the
+    /// current PWD. Lush doesn't do the same automatically for internal cmds. For better
+    /// compatability, we now expand filenames
+    pub fn args_to_strs(args: &[Value]) -> LuResult<Vec<String>> {
         let mut args_as_str = vec![];
-        for arg in &**args {
+        for arg in args {
             if let Value::FileName(f_name) = arg {
-                match glob::glob(&f_name) {
+                match glob::glob(f_name) {
                     Ok(entries) => {
                         for entry in entries {
                             match entry {
@@ -96,39 +85,155 @@ impl Command for RunExternalCmd {
                 args_as_str.push(arg.to_string())
             }
         }
+        Ok(args_as_str)
+    }
 
-        let args = args_as_str;
-        let stdin = self.get_in(&l_scope).cloned().unwrap_or(Value::Nil);
+    /// Converts `val` to the text written to the stdin of an external cmd. Like output written to
+    /// a terminal, lush values are passed as lines ending in a newline. Output of other external
+    /// cmds is passed unchanged
+    pub fn stdin_text(val: &Value) -> String {
+        let mut text = val.to_string();
+        if !matches!(val, Value::BareWord(_)) && !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text
+    }
 
-        let mut child = std::process::Command::new(self.cmd_name.clone())
-            .args(args)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .spawn()
+    /// Spawns the external cmd with its stdin, stdout and stderr connected as given
+    pub fn spawn(
+        &self,
+        args: &[String],
+        stdin: Stdio,
+        stdout: Stdio,
+        stderr: Stdio,
+    ) -> LuResult<Child> {
+        let mut cmd = std::process::Command::new(self.cmd_name.clone());
+        cmd.args(args).stdin(stdin).stdout(stdout).stderr(stderr);
+        if let Some(pgid) = self.process_group {
+            cmd.process_group(pgid);
+        }
+        cmd.spawn()
             .map_err(|e| {
-                EvalErr::SpawningExternalProcessFailed(self.cmd_node.clone(), e.to_string())
-            })?;
+                EvalErr::SpawningExternalProcessFailed(self.cmd_node.clone(), e.to_string()).into()
+            })
+    }
+
+    /// Runs the external cmd with `stdin` as input and captures its stdout and stderr. A failing
+    /// cmd is not an error
+    pub fn run_captured(&self, args: &[String], stdin: &Value) -> LuResult<Output> {
+        let mut child = self.spawn(args, Stdio::piped(), Stdio::piped(), Stdio::piped())?;
+        self.write_stdin(&mut child, stdin)?;
+        child.wait_with_output().map_err(|e| {
+            EvalErr::ExternalCmdStdoutReadErr(self.cmd_node.clone(), format!("{:?}", e)).into()
+        })
+    }
 
-        if !stdin.is_nil() {
-            child
-                .stdin
-                .as_mut()
-                .expect("Cmd stdin always correctly captured :)")
-                .write_all(stdin.to_string().as_bytes())
-                .map_err(|e| {
-                    EvalErr::ExternalCmdStdinWriteErr(self.cmd_node.clone(), format!("{:?}", e))
-                })?;
+    fn write_stdin(&self, child: &mut Child, stdin: &Value) -> LuResult<()> {
+        if stdin.is_nil() {
+            return Ok(());
         }
+        child
+            .stdin
+            .as_mut()
+            .expect("Cmd stdin always correctly captured :)")
+            .write_all(Self::stdin_text(stdin).as_bytes())
+            .map_err(|e| {
+                EvalErr::ExternalCmdStdinWriteErr(self.cmd_node.clone(), format!("{:?}", e))
+                    .into()
+            })
+    }
+}
 
-        let output = child.wait_with_output().map_err(|e| {
-            EvalErr::ExternalCmdStdoutReadErr(self.cmd_node.clone(), format!("{:?}", e))
-        })?;
+/// Returns the exit code of a finished process. Like in other shells, a process killed by a
+/// signal has the exit code 128 + signal
+pub fn exit_code_of(status: &ExitStatus) -> i32 {
+    match (status.code(), status.signal()) {
+        (Some(code), _) => code,
+        (None, Some(signal)) => 128 + signal,
+        (None, None) => unreachable!("A process either exits or is killed by a signal"),
+    }
+}
+
+pub fn external_cmd_failed_err(cmd: SourceCodeItem, status: &ExitStatus) -> LuErr {
+    EvalErr::ExternalCmdFailed {
+        cmd,
+        exit_code: exit_code_of(status),
+        signal: status.signal(),
+    }
+    .into()
+}
+
+impl Command for RunExternalCmd {
+    fn name(&self) -> &str {
+        &self.cmd_name
+    }
+
+    fn signature(&self) -> &Signature {
+        self.signature
+            .get_or_init(|| external_cmd::external_cmd_signature())
+    }
+
+    fn signature_item(&self) -> SourceCodeItem {
+        lu_source_code_item!() // TODO fixup line number
+    }
 
-        if output.status.success() {
-            let raw_output = String::from_utf8(output.stdout)?;
-            Ok(Value::BareWord(raw_output))
+    fn parent_module(&self) -> Option<&crate::ModPath> {
+        None
+    }
+
+    fn attributes(&self) -> &[CmdAttribute] {
+        EXT_CMDS_ATTRIBUTES
+            .get(self.name())
+            .map(|attrs| attrs.as_ref())
+            .unwrap_or(&EXT_CMDS_DEF_ATTRIBUTES)
+    }
+
+    fn do_run_cmd(&self, scope: &mut SyScope) -> LuResult<Value> {
+        let (args, stdin) = {
+            let l_scope = scope.lock();
+            let args = self.expect_args(
+                &self
+                    .signature()
+                    .var_arg
+                    .as_ref()
+                    .expect("ExternalCmd has vararg")
+                    .name,
+                &l_scope,
+            );
+            let stdin = self.get_in(&l_scope).cloned().unwrap_or(Value::Nil);
+            (Self::args_to_strs(args)?, stdin)
+        };
+
+        let (stdin_cfg, stdout_cfg) = if !self.inherit_tty {
+            (Stdio::piped(), Stdio::piped())
+        } else if stdin.is_nil() {
+            (Stdio::inherit(), Stdio::inherit())
+        } else {
+            (Stdio::piped(), Stdio::inherit())
+        };
+        let mut child = self.spawn(&args, stdin_cfg, stdout_cfg, Stdio::inherit())?;
+        self.write_stdin(&mut child, &stdin)?;
+
+        let (status, raw_output) = if self.inherit_tty {
+            let status = child.wait().map_err(|e| {
+                EvalErr::ExternalCmdStdoutReadErr(self.cmd_node.clone(), format!("{:?}", e))
+            })?;
+            (status, None)
         } else {
-            Err(EvalErr::ExternalCmdFailed(self.cmd_node.clone()).into())
+            let output = child.wait_with_output().map_err(|e| {
+                EvalErr::ExternalCmdStdoutReadErr(self.cmd_node.clone(), format!("{:?}", e))
+            })?;
+            (output.status, Some(output.stdout))
+        };
+        set_status_var(exit_code_of(&status), &mut scope.lock());
+
+        if !status.success() {
+            return Err(external_cmd_failed_err(self.cmd_node.clone(), &status));
+        }
+        match raw_output {
+            Some(raw_output) => Ok(Value::BareWord(String::from_utf8(raw_output)?)),
+            // Output has been written to the terminal
+            None => Ok(Value::Nil),
         }
     }
 }
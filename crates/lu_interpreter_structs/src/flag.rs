@@ -1,9 +1,6 @@
 use derive_more::Display;
 use enum_as_inner::EnumAsInner;
-use lu_syntax::{
-    ast::{FlagElement, FlagSignatureNode},
-    AstToken,
-};
+use lu_syntax::ast::{FlagElement, FlagSignatureNode};
 #[derive(Clone, Debug, PartialEq, Eq, EnumAsInner, is_enum_variant, Display)]
 pub enum FlagVariant {
     #[display(fmt = "--{}", _0)]
@@ -17,8 +14,9 @@ impl FlagVariant {
     where
         Iter: Iterator<Item = FlagElement>,
     {
-        // TODO split short flags here
-        flags.map(|flag_elem| Self::from_node(&flag_elem)).collect()
+        flags
+            .flat_map(|flag_elem| Self::from_node(&flag_elem))
+            .collect()
     }
     pub fn from_sign_node(flag_sign_node: &FlagSignatureNode) -> Self {
         if let Some(long_name) = flag_sign_node.long_name() {
@@ -29,13 +27,15 @@ impl FlagVariant {
             unreachable!()
         }
     }
-    pub fn from_node(flag_elem: &FlagElement) -> Self {
+    /// Combined short flags (`-la`) result in multiple flags
+    pub fn from_node(flag_elem: &FlagElement) -> Vec<Self> {
         match flag_elem {
-            FlagElement::ShortFlag(n) => {
-                assert!(n.text().len() == 2);
-                FlagVariant::ShortFlag(n.text().chars().nth(1).unwrap())
-            }
-            FlagElement::LongFlag(n) => FlagVariant::LongFlag(n.text()[2..].to_string()),
+            FlagElement::ShortFlag(n) => n
+                .flag_names()
+                .into_iter()
+                .map(FlagVariant::ShortFlag)
+                .collect(),
+            FlagElement::LongFlag(n) => vec![FlagVariant::LongFlag(n.flag_name())],
         }
     }
 }
@@ -0,0 +1,259 @@
+//! Jobs are external cmds run in the background (through a trailing `&`)
+use std::{fmt::Display, process::Child, sync::Arc};
+
+use lu_error::{lu_source_code_item, EvalErr, LuResult};
+use nix::{
+    errno::Errno,
+    libc::STDIN_FILENO,
+    sys::{
+        signal::{killpg, signal, SigHandler, Signal},
+        wait::{waitpid, WaitPidFlag, WaitStatus},
+    },
+    unistd::{getpgrp, isatty, tcsetpgrp, Pid},
+};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+use crate::{ModPath, Strct, StrctField, Value, ValueType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Running,
+    Stopped,
+    /// All processes have finished. Contains the exit code of the last process, or None if its
+    /// status has been lost (it has been waited for outside of the job table)
+    Done(Option<i32>),
+}
+
+impl Display for JobState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobState::Running => write!(f, "Running"),
+            JobState::Stopped => write!(f, "Stopped"),
+            JobState::Done(_) => write!(f, "Done"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct JobProcess {
+    pid: Pid,
+    exit_code: Option<i32>,
+    stopped: bool,
+    /// The process has finished, but its exit code is unknown
+    status_lost: bool,
+}
+
+impl JobProcess {
+    /// Applies the `status` reported by waitpid
+    fn apply(&mut self, status: WaitStatus) {
+        match status {
+            WaitStatus::Exited(_, code) => self.exit_code = Some(code),
+            // Like in other shells, a process killed by a signal has the exit code 128 + signal
+            WaitStatus::Signaled(_, sig, _) => self.exit_code = Some(128 + sig as i32),
+            WaitStatus::Stopped(_, _) => self.stopped = true,
+            WaitStatus::Continued(_) => self.stopped = false,
+            _ => {}
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.exit_code.is_some() || self.status_lost
+    }
+}
+
+#[derive(Debug)]
+pub struct Job {
+    pub id: u32,
+    /// The source code of the stmt run as the job
+    pub cmd: String,
+    /// All processes of a job are in the process group of its first process
+    pub pgid: Pid,
+    processes: Vec<JobProcess>,
+}
+
+impl Job {
+    pub fn state(&self) -> JobState {
+        if self.processes.iter().all(JobProcess::is_done) {
+            JobState::Done(self.processes.last().unwrap().exit_code)
+        } else if self.processes.iter().any(|p| p.stopped && !p.is_done()) {
+            JobState::Stopped
+        } else {
+            JobState::Running
+        }
+    }
+
+    /// Updates the state of all processes without blocking
+    fn poll(&mut self) {
+        let flags = WaitPidFlag::WNOHANG | WaitPidFlag::WUNTRACED | WaitPidFlag::WCONTINUED;
+        for process in self.processes.iter_mut().filter(|p| !p.is_done()) {
+            match waitpid(process.pid, Some(flags)) {
+                Ok(status) => process.apply(status),
+                // The process has already been waited for. Its status is unknown
+                Err(Errno::ECHILD) => process.status_lost = true,
+                // Retried on the next poll
+                Err(_) => {}
+            }
+        }
+    }
+
+    /// Blocks until all processes are done or one of them has been stopped
+    pub fn wait(&mut self) -> LuResult<JobState> {
+        for process in self.processes.iter_mut() {
+            while !process.is_done() {
+                match waitpid(process.pid, Some(WaitPidFlag::WUNTRACED)) {
+                    Ok(status) => process.apply(status),
+                    Err(Errno::ECHILD) => process.status_lost = true,
+                    Err(e) => {
+                        return Err(
+                            EvalErr::Message(format!("Could not wait for job: {}", e)).into()
+                        )
+                    }
+                }
+                if process.stopped {
+                    return Ok(JobState::Stopped);
+                }
+            }
+        }
+        Ok(self.state())
+    }
+
+    /// Like `wait`, but the job gets the terminal while it runs
+    pub fn wait_in_foreground(&mut self) -> LuResult<JobState> {
+        let has_tty = isatty(STDIN_FILENO).unwrap_or(false);
+        if has_tty {
+            let _ = tcsetpgrp(STDIN_FILENO, self.pgid);
+        }
+        let result = self.wait();
+        if has_tty {
+            // Lush is not in the foreground process group. Taking back the terminal would stop
+            // lush, if SIGTTOU is not ignored
+            unsafe {
+                let prev_handler = signal(Signal::SIGTTOU, SigHandler::SigIgn);
+                let _ = tcsetpgrp(STDIN_FILENO, getpgrp());
+                if let Ok(prev_handler) = prev_handler {
+                    let _ = signal(Signal::SIGTTOU, prev_handler);
+                }
+            }
+        }
+        result
+    }
+
+    /// Sends `sig` to all processes of the job
+    pub fn signal(&mut self, sig: Signal) -> LuResult<()> {
+        killpg(self.pgid, sig)
+            .map_err(|e| EvalErr::Message(format!("Could not send {} to job: {}", sig, e)))?;
+        if sig == Signal::SIGCONT {
+            self.processes.iter_mut().for_each(|p| p.stopped = false);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct JobTable {
+    jobs: Vec<Job>,
+}
+
+impl JobTable {
+    /// Adds a new job made of `children`. The first child has to be the leader of the process
+    /// group of all children
+    pub fn add(&mut self, cmd: String, children: &[Child]) -> &Job {
+        let id = self.jobs.last().map(|job| job.id + 1).unwrap_or(1);
+        let processes: Vec<_> = children
+            .iter()
+            .map(|child| JobProcess {
+                pid: Pid::from_raw(child.id() as i32),
+                exit_code: None,
+                stopped: false,
+                status_lost: false,
+            })
+            .collect();
+        self.jobs.push(Job {
+            id,
+            cmd,
+            pgid: processes[0].pid,
+            processes,
+        });
+        self.jobs.last().unwrap()
+    }
+
+    /// Updates the state of all jobs without blocking
+    pub fn poll(&mut self) {
+        self.jobs.iter_mut().for_each(Job::poll);
+    }
+
+    pub fn jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    /// Returns the job with `id`, or the most recent job if no id is given
+    pub fn find_job(&mut self, id: Option<u32>) -> LuResult<&mut Job> {
+        let job = match id {
+            Some(id) => self.jobs.iter_mut().find(|job| job.id == id),
+            None => self.jobs.last_mut(),
+        };
+        job.ok_or_else(|| match id {
+            Some(id) => EvalErr::Message(format!("No job with id {}", id)).into(),
+            None => EvalErr::Message("There are no jobs".into()).into(),
+        })
+    }
+
+    /// Removes all jobs, which are done
+    pub fn remove_done(&mut self) {
+        self.jobs
+            .retain(|job| !matches!(job.state(), JobState::Done(_)));
+    }
+}
+
+// The strct of the rows returned by the `jobs` builtin
+pub const JOB_STRCT_NAME: &str = "Job";
+const JOB_STRCT_FIELD_0: &str = "id";
+const JOB_STRCT_FIELD_1: &str = "pid";
+const JOB_STRCT_FIELD_2: &str = "state";
+const JOB_STRCT_FIELD_3: &str = "cmd";
+
+pub static JOB_STRCT: Lazy<Arc<RwLock<Strct>>> = Lazy::new(|| {
+    let decl = lu_source_code_item!();
+    let fields = [
+        (JOB_STRCT_FIELD_0, ValueType::Number),
+        (JOB_STRCT_FIELD_1, ValueType::Number),
+        (JOB_STRCT_FIELD_2, ValueType::String),
+        (JOB_STRCT_FIELD_3, ValueType::String),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, (name, ty))| StrctField::new(name.to_string(), ty.clone(), i as u32, decl.clone()))
+    .collect();
+    Arc::new(RwLock::new(Strct::new(
+        JOB_STRCT_NAME.into(),
+        fields,
+        decl,
+        ModPath::builtin(),
+    )))
+});
+
+pub fn job_strct_ty() -> ValueType {
+    ValueType::Strct(Arc::downgrade(&*JOB_STRCT))
+}
+
+pub fn job_strct_val(job: &Job) -> Value {
+    Value::new_strct(
+        JOB_STRCT.clone(),
+        vec![
+            (
+                JOB_STRCT_FIELD_0.into(),
+                Value::Number((job.id as f64).into()),
+            ),
+            (
+                JOB_STRCT_FIELD_1.into(),
+                Value::Number((job.pgid.as_raw() as f64).into()),
+            ),
+            (
+                JOB_STRCT_FIELD_2.into(),
+                Value::String(job.state().to_string()),
+            ),
+            (JOB_STRCT_FIELD_3.into(), Value::String(job.cmd.clone())),
+        ],
+    )
+}
@@ -9,10 +9,12 @@ extern crate educe;
 mod command;
 mod command_collection;
 pub mod dbg_state;
+mod err_strct;
 mod evaluate;
 pub mod external_cmd;
 mod external_cmds_attr;
 mod flag;
+pub mod job_table;
 mod module;
 pub mod prelude;
 mod scope;
@@ -27,6 +29,7 @@ mod variable;
 
 pub use command::{CmdAttribute, CmdAttributeVariant, Command};
 pub use command_collection::CommandCollection;
+pub use err_strct::*;
 pub use evaluate::*;
 pub use external_cmd::*;
 pub use flag::*;
@@ -1,12 +1,13 @@
 use crate::{
-    Command, Function, ModPath, ScopeFrame, ScopeFrameTag, Strct, StrctField, UsePath, Variable,
+    Command, Enum, EnumVariant, Function, ModPath, ScopeFrame, ScopeFrameTag, Strct, StrctField,
+    UsePath, Variable,
 };
 use itertools::Itertools;
 use log::debug;
 use lu_error::util::Outcome;
 use lu_syntax::{
-    ast::{self, SourceFileNode, StrctStmtNode, UseStmtNode},
-    AstNode, Parse,
+    ast::{self, EnumStmtNode, ImplStmtNode, SourceFileNode, StrctStmtNode, UseStmtNode},
+    AstNode, AstToken, Parse,
 };
 use lu_text_util::SourceCode;
 use std::{convert::TryInto, fmt::Display};
@@ -119,6 +120,9 @@ impl ModInfo {
             for strct in sourced_file.strcts {
                 frame.insert_var(Variable::new_strct_decl(strct));
             }
+            for enm in sourced_file.enums {
+                frame.insert_var(Variable::new_enum_decl(enm));
+            }
             frame
         })
     }
@@ -134,23 +138,31 @@ impl ModInfo {
             .use_stmts()
             .map(|use_stmt| Self::source_use_stmt(&use_stmt))
             .collect();
-        let funcs = block
+        let mut funcs: Vec<_> = block
             .fn_stmts()
             .map(|fn_stmt| Function::func_from_node(fn_stmt, source_node_id.clone()))
             .collect();
-        let strcts = block
+        let mut strcts: Vec<_> = block
             .struct_stmts()
-            .map(|strct_stmt| Self::source_struct_stmt(&strct_stmt))
+            .map(|strct_stmt| Self::source_struct_stmt(&strct_stmt, source_node_id.clone()))
+            .collect();
+        for impl_stmt in block.impl_stmts() {
+            Self::source_impl_stmt(&impl_stmt, &mut strcts, &mut funcs, &source_node_id);
+        }
+        let enums = block
+            .enum_stmts()
+            .map(|enum_stmt| Self::source_enum_stmt(&enum_stmt, source_node_id.clone()))
             .collect();
 
         SourcedFile {
             strcts,
+            enums,
             funcs,
             use_paths,
         }
     }
 
-    fn source_struct_stmt(struct_stmt: &StrctStmtNode) -> Strct {
+    fn source_struct_stmt(struct_stmt: &StrctStmtNode, mod_path: ModPath) -> Strct {
         let name = struct_stmt.name().unwrap_or("".to_string());
 
         // Source the struct fields (either user provided or default)
@@ -166,7 +178,46 @@ impl ModInfo {
             })
             .collect();
 
-        Strct::new(name, fields, struct_stmt.to_item())
+        Strct::new(name, fields, struct_stmt.to_item(), mod_path)
+    }
+
+    /// Sources the methods of impl_stmt as funcs. The methods are registered at their strct, if
+    /// the strct is declared in the same module
+    fn source_impl_stmt(
+        impl_stmt: &ImplStmtNode,
+        strcts: &mut [Strct],
+        funcs: &mut Vec<Function>,
+        mod_path: &ModPath,
+    ) {
+        let strct_name = impl_stmt
+            .strct_name()
+            .map(|name| name.text().to_string())
+            .unwrap_or_default();
+        let mut strct = strcts.iter_mut().find(|strct| strct.name == strct_name);
+        for fn_stmt in impl_stmt.fn_stmts() {
+            let method_name = match fn_stmt.name() {
+                Some(name) => name,
+                None => continue,
+            };
+            if let Some(strct) = strct.as_mut() {
+                strct.methods.push(method_name);
+            }
+            funcs.push(Function::method_from_node(
+                fn_stmt,
+                &strct_name,
+                mod_path.clone(),
+            ));
+        }
+    }
+
+    fn source_enum_stmt(enum_stmt: &EnumStmtNode, mod_path: ModPath) -> Enum {
+        let name = enum_stmt.name().unwrap_or("".to_string());
+        let variants = enum_stmt
+            .variants()
+            .map(|variant| EnumVariant::from_node(&variant))
+            .collect();
+
+        Enum::new(name, variants, enum_stmt.to_item(), mod_path)
     }
 
     fn source_use_stmt(use_stmt: &UseStmtNode) -> UsePath {
@@ -176,6 +227,7 @@ impl ModInfo {
 
 struct SourcedFile {
     strcts: Vec<Strct>,
+    enums: Vec<Enum>,
     funcs: Vec<Function>,
     use_paths: Vec<UsePath>,
 }
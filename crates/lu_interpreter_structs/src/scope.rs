@@ -17,7 +17,7 @@ use tap::Tap;
 
 pub use indextree::NodeId as ScopeFrameId;
 
-use crate::{Command, FlagVariant, ModInfo, ModPath, Strct, Variable};
+use crate::{Command, Enum, FlagVariant, ModInfo, ModPath, Strct, Variable};
 
 #[derive(Clone, Debug, PartialEq, Eq, EnumAsInner, is_enum_variant, Display)]
 pub enum ScopeFrameTag {
@@ -29,14 +29,20 @@ pub enum ScopeFrameTag {
     ModuleFrame(ModInfo),
 
     BlockFrame,
-    /// Frame for evaluating cmd (with command-name and required flags)
-    #[display(fmt = "CmdCallFrame {} {:?}", _0, _1)]
-    CmdCallFrame(String, Vec<FlagVariant>),
-    /// Fn Frame allocated during TyC (with fn-name and required flags)
+    /// Frame for evaluating cmd (with command-name and the decl of its signature)
+    #[display(fmt = "CmdCallFrame {}", _0)]
+    CmdCallFrame(String, SourceCodeItem),
+    /// Fn Frame allocated during TyC (with fn-name and the decl of its signature)
     #[display(fmt = "TyCFnFrame {}", _0)]
-    TyCFnFrame(String, Vec<FlagVariant>),
+    TyCFnFrame(String, SourceCodeItem),
     ForStmtFrame,
+    WhileStmtFrame,
     IfStmtFrame,
+    TryStmtFrame,
+    /// Frame of the catch block (with the caught error)
+    CatchStmtFrame,
+    /// Frame of the evaluated match arm (with the vars bound by the pattern)
+    MatchArmFrame,
 }
 
 impl ScopeFrameTag {}
@@ -125,6 +131,9 @@ where
 
     /// From NodeId of SourceFile to [NodeId of SourceFile]
     pub use_stmts: MultiMap<NodeId, NodeId>,
+
+    /// From a cmd stmt calling an overloaded cmd to the overload selected during typechecking
+    pub resolved_overloads: HashMap<SourceCodeItem, Rc<dyn Command>>,
 }
 
 pub type SyScope = AMtx<Scope<Variable>>;
@@ -135,6 +144,7 @@ impl<T: fmt::Debug + 'static> Scope<T> {
             arena: Arena::new(),
             cur_frame_id: None,
             use_stmts: MultiMap::new(),
+            resolved_overloads: HashMap::new(),
         }
     }
 
@@ -237,6 +247,17 @@ impl<T: fmt::Debug + 'static> Scope<T> {
             tag.is_cmd_call_frame()
         })
     }
+
+    /// Returns whether the current frame is within a loop. Loops outside of the current
+    /// function are not considered.
+    pub fn ctx_is_within_loop(&self) -> bool {
+        let cur_id = self.get_cur_frame_id();
+        cur_id
+            .ancestors(&self.arena)
+            .map(|n_id| self.arena[n_id].get().get_tag())
+            .take_while(|tag| !(tag.is_ty_c_fn_frame() || tag.is_cmd_call_frame()))
+            .any(|tag| tag.is_for_stmt_frame() || tag.is_while_stmt_frame())
+    }
 }
 
 impl Scope<Variable> {
@@ -245,10 +266,10 @@ impl Scope<Variable> {
         let cur_id = self.get_cur_frame_id();
         cur_id.ancestors(&self.arena).find_map(|n_id| {
             let tag = self.arena[n_id].get().get_tag();
-            if let Some((func_name, req_flags)) =
+            if let Some((func_name, decl)) =
                 tag.as_ty_c_fn_frame().or_else(|| tag.as_cmd_call_frame())
             {
-                self.find_func(func_name, req_flags)
+                self.find_func_by_decl(func_name, decl)
                     .tap(|func| assert!(func.is_some(), "Cmd of CmdFrame has to be always found"))
             } else {
                 None
@@ -324,6 +345,12 @@ impl Scope<Variable> {
 
     /// Find func with name name, that could be called by flags
     pub fn find_func(&self, name: &str, flags: &[FlagVariant]) -> Option<&Rc<dyn Command>> {
+        self.find_overloads(name, flags).into_iter().next()
+    }
+
+    /// Find all funcs with name name, that could be called by flags. Overloads differing only by
+    /// their arguments are all returned
+    pub fn find_overloads(&self, name: &str, flags: &[FlagVariant]) -> Vec<&Rc<dyn Command>> {
         trace!("Finding cmd {} from {} on", name, self.get_cur_frame());
         // TODO write check that no variable shadows a func name
         let start_frame = self.get_cur_frame().get_tag();
@@ -332,23 +359,21 @@ impl Scope<Variable> {
             .iter()
             .map(|frame_id| self.arena[*frame_id].get())
             .find_map(|frame| {
-                if let Some(var) = frame.get(name) {
-                    trace!("Found var {:?} with matching name", var);
-                    if let Some(func) = var.val.as_command() {
-                        Some(func)
-                    } else if let Some(cmd_collect) = var.val.as_command_collection() {
+                let var = frame.get(name)?;
+                trace!("Found var {:?} with matching name", var);
+                if let Some(func) = var.val.as_command() {
+                    Some(vec![func])
+                } else {
+                    var.val.as_command_collection().map(|cmd_collect| {
                         cmd_collect
                             .cmds
                             .iter()
                             .filter(|cmd| cmd.is_called_by(name, flags))
-                            .next()
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+                            .collect()
+                    })
                 }
             })
+            .unwrap_or_default()
             .tap(|result| {
                 trace!(
                     "Result for find_var {} from start_frame {}: {:?}",
@@ -359,6 +384,33 @@ impl Scope<Variable> {
             })
     }
 
+    /// Find the func called by cmd_stmt. If the func is overloaded, the overload selected during
+    /// typechecking is returned
+    pub fn find_called_func(
+        &self,
+        name: &str,
+        flags: &[FlagVariant],
+        cmd_stmt: &SourceCodeItem,
+    ) -> Option<&Rc<dyn Command>> {
+        self.resolved_overloads
+            .get(cmd_stmt)
+            .or_else(|| self.find_func(name, flags))
+    }
+
+    /// Find the func with name name, which has been declared at decl
+    pub fn find_func_by_decl(&self, name: &str, decl: &SourceCodeItem) -> Option<&Rc<dyn Command>> {
+        let var = self.find_var(name)?;
+        if let Some(func) = var.val.as_command() {
+            Some(func)
+        } else {
+            var.val
+                .as_command_collection()?
+                .cmds
+                .iter()
+                .find(|cmd| cmd.signature().decl == *decl)
+        }
+    }
+
     pub fn expect_func(
         &self,
         name: &str,
@@ -369,11 +421,26 @@ impl Scope<Variable> {
             .ok_or(AstErr::CmdNotInScope(usage).into())
     }
 
+    /// Find the method method_name of strct. Methods are declared in the module of their strct
+    pub fn find_method(&self, strct: &Strct, method_name: &str) -> Option<&Rc<dyn Command>> {
+        let cmd_name = strct.method_cmd_name(method_name)?;
+        let mod_frame = self.get_nid_of_sf_frame(&strct.mod_path)?;
+        self.arena[mod_frame].get().get(&cmd_name)?.val.as_command()
+    }
+
     pub fn find_strct(&self, name: &str) -> Option<&Arc<RwLock<Strct>>> {
         trace!("Finding cmd {} from {} on", name, self.get_cur_frame());
         // TODO write check that no variable shadows a func name
-        self.find_var(name)
-            .map(|var| var.val.as_strct_decl().unwrap())
+        self.find_var(name).and_then(|var| var.val.as_strct_decl())
+    }
+
+    pub fn find_enum(&self, name: &str) -> Option<&Arc<RwLock<Enum>>> {
+        self.find_var(name).and_then(|var| var.val.as_enum_decl())
+    }
+
+    pub fn expect_enum(&self, name: &str, usage: SourceCodeItem) -> LuResult<&Arc<RwLock<Enum>>> {
+        self.find_enum(name)
+            .ok_or(AstErr::EnumNotInScope(usage).into())
     }
 
     pub fn expect_strct(&self, name: &str, usage: SourceCodeItem) -> LuResult<&Arc<RwLock<Strct>>> {
@@ -432,16 +499,19 @@ impl Scope<Variable> {
         None
     }
 
-    pub fn all_vars_captured_by_closure(&self) -> Vec<Variable> {
-        let all_frames = self.frames_to_find_var_in();
-
-        // take_until includes the fn frame.
-        all_frames
+    /// Returns the variables with the given names, as seen from the current frame.
+    /// Variables of the global frame are not captured.
+    pub fn vars_captured_by_closure(&self, names: &[String]) -> Vec<Variable> {
+        let frames = self
+            .frames_to_find_var_in()
             .into_iter()
             .map(|frame_id| self.arena[frame_id].get())
             .take_while(|f| !f.get_tag().is_global_frame())
-            .map(|frame| frame.elems.values())
-            .flatten()
+            .collect_vec();
+
+        names
+            .iter()
+            .filter_map(|name| frames.iter().find_map(|frame| frame.get(name)))
             .cloned()
             .collect_vec()
     }
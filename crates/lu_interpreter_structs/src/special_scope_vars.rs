@@ -3,6 +3,7 @@ use lu_error::lu_source_code_item;
 use lu_stdx::{new_amtx, AMtx};
 
 use crate::dbg_state::DbgState;
+use crate::job_table::{JobTable, JOB_STRCT, JOB_STRCT_NAME};
 use crate::Value;
 use crate::{Scope, ScopeFrame, Variable};
 
@@ -26,6 +27,30 @@ pub fn set_new_dbg_session(frame: &mut ScopeFrame<Variable>) {
     ));
 }
 
+const JOB_TABLE_VAR_NAME: &str = "JOB_TABLE";
+/// Inserts an empty job table and the strct of its rows
+pub fn init_job_table(frame: &mut ScopeFrame<Variable>) {
+    frame.insert_var(Variable::new(
+        JOB_TABLE_VAR_NAME.to_string(),
+        Value::JobTable(new_amtx(JobTable::default())),
+        lu_source_code_item!(),
+    ));
+    frame.insert_var(Variable::new(
+        JOB_STRCT_NAME.to_string(),
+        Value::StrctDecl(JOB_STRCT.clone()),
+        lu_source_code_item!(),
+    ));
+}
+
+pub fn get_job_table(scope: &Scope<Variable>) -> Option<&AMtx<JobTable>> {
+    scope
+        .find_var(JOB_TABLE_VAR_NAME)
+        .and_then(|var| match &var.val {
+            Value::JobTable(t) => Some(t),
+            _ => None,
+        })
+}
+
 const SILENCE_VAR_NAME: &str = "SILENCE_STMT_RETURNS";
 pub fn set_silence_stmt_returns(silence: bool, frame: &mut ScopeFrame<Variable>) {
     frame.insert_var(Variable::new(
@@ -43,6 +68,24 @@ pub fn get_silence_stmt_returns(scope: &Scope<Variable>) -> Option<bool> {
         .cloned()
 }
 
+/// Holds the exit code of the last external cmd
+pub const STATUS_VAR_NAME: &str = "status";
+pub fn init_status_var(frame: &mut ScopeFrame<Variable>) {
+    frame.insert_var(Variable::new(
+        STATUS_VAR_NAME.to_string(),
+        Value::Number(0f64.into()),
+        lu_source_code_item!(),
+    ));
+}
+
+pub fn set_status_var(exit_code: i32, scope: &mut Scope<Variable>) {
+    scope.get_global_frame_mut().insert_var(Variable::new(
+        STATUS_VAR_NAME.to_string(),
+        Value::Number((exit_code as f64).into()),
+        lu_source_code_item!(),
+    ));
+}
+
 const PWD_ENV_VAR: &str = "PWD";
 pub fn get_pwd_var(scope: &Scope<Variable>) -> (&Variable, &String) {
     let pwd = scope.find_var(PWD_ENV_VAR).unwrap();
@@ -74,6 +74,11 @@ impl ModPath {
             SourceCodeVariant::InteractiveCode => Self::Interactive,
         }
     }
+
+    /// The module of the items provided by the interpreter itself (e.G. the Error strct)
+    pub fn builtin() -> Self {
+        Self::StdPath("std".into())
+    }
 }
 
 impl Display for ModPath {
@@ -1,7 +1,9 @@
+mod enum_;
 mod function;
 mod strct;
 mod table;
 
+pub use enum_::*;
 pub use function::*;
 pub use strct::*;
 pub use table::*;
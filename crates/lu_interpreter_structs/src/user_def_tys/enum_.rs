@@ -0,0 +1,63 @@
+use derive_new::new;
+use lu_error::SourceCodeItem;
+use lu_syntax::ast::EnumVariantNode;
+use lu_syntax::AstNode;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+use crate::{ModPath, StrctField};
+
+#[derive(new, PartialEq, Eq, Hash, Clone, Debug, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    /// Empty for variants without fields
+    pub fields: Vec<StrctField>,
+    pub decl: SourceCodeItem,
+}
+
+impl EnumVariant {
+    pub fn from_node(variant_node: &EnumVariantNode) -> EnumVariant {
+        let fields = variant_node
+            .fields()
+            .enumerate()
+            .map(|(i, field)| {
+                StrctField::from_node(
+                    &field,
+                    i.try_into()
+                        .expect("No variant has more than 2 billion fields"),
+                )
+            })
+            .collect();
+
+        EnumVariant::new(variant_node.name(), fields, variant_node.to_item())
+    }
+}
+
+#[derive(new, PartialEq, Eq, Hash, Clone, Debug, Serialize, Deserialize)]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<EnumVariant>,
+    pub decl: SourceCodeItem,
+    /// The module declaring this enum. Enums with the same name from different modules
+    /// are different types
+    pub mod_path: ModPath,
+}
+
+impl Enum {
+    pub fn find_variant(&self, name: &str) -> Option<&EnumVariant> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// The name of this enum, prefixed by its module (if not declared interactively)
+    pub fn qualified_name(&self) -> String {
+        match &self.mod_path {
+            ModPath::Interactive => self.name.clone(),
+            mod_path => format!("{}:{}", mod_path, self.name),
+        }
+    }
+
+    /// Whether self and other declare the same enum type
+    pub fn is_same_enum_as(&self, other: &Enum) -> bool {
+        self.name == other.name && self.mod_path == other.mod_path
+    }
+}
@@ -5,29 +5,39 @@ use crate::{
 use derive_builder::Builder;
 use derive_more::From;
 use derive_new::new;
+use itertools::Itertools;
 use log::trace;
 use lu_error::{lu_source_code_item, LuResult, SourceCodeItem};
 use lu_syntax::ast::{
-    ArgSignatureNode, ClosureExprNode, FnStmtNode, ImpureKeywordToken, MathExprNode, SignatureNode,
+    method_cmd_name, ArgSignatureNode, ClosureExprNode, FnStmtNode, ImpureKeywordToken,
+    MathExprNode, SignatureNode,
 };
 use lu_syntax::{AstNode, AstToken};
-use lu_syntax_elements::constants::{IN_ARG_NAME, RET_ARG_NAME, VAR_ARGS_DEF_NAME};
+use lu_syntax_elements::constants::{IN_ARG_NAME, RET_ARG_NAME, SELF_ARG_NAME, VAR_ARGS_DEF_NAME};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 #[derive(Clone, Debug, Hash, new, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ArgSignature {
     pub name: String,
     pub ty: ValueType,
     pub is_opt: bool, // TODO this is prob a bad idea???
+    /// Args with a default value may be omitted, but are not optional within the fn
+    pub has_default: bool,
     pub decl: SourceCodeItem,
 }
 
 impl ArgSignature {
     pub fn req(name: String, ty: ValueType, decl: SourceCodeItem) -> Self {
-        Self::new(name, ty, false, decl)
+        Self::new(name, ty, false, false, decl)
     }
     pub fn opt(name: String, ty: ValueType, decl: SourceCodeItem) -> Self {
-        Self::new(name, ty, true, decl)
+        Self::new(name, ty, true, false, decl)
+    }
+
+    /// Whether the caller may omit this arg
+    pub fn may_be_omitted(&self) -> bool {
+        self.is_opt || self.has_default
     }
 
     pub fn void(decl: SourceCodeItem) -> ArgSignature {
@@ -80,6 +90,8 @@ pub struct FlagSignature {
     pub short_name: Option<char>,
     pub ty: ValueType,
     pub is_opt: bool,
+    /// Not passed flags with a default value are not optional within the fn
+    pub has_default: bool,
     pub decl: SourceCodeItem,
 }
 
@@ -90,7 +102,7 @@ impl FlagSignature {
         ty: ValueType,
         decl: SourceCodeItem,
     ) -> Self {
-        Self::new(long_name, short_name, ty, true, decl)
+        Self::new(long_name, short_name, ty, true, false, decl)
     }
     pub fn is_named_by(&self, name: &str) -> bool {
         let mut result = false;
@@ -103,6 +115,19 @@ impl FlagSignature {
         result
     }
 
+    pub fn is_named_by_flag(&self, flag: &FlagVariant) -> bool {
+        match flag {
+            FlagVariant::LongFlag(name) => self.long_name.as_ref() == Some(name),
+            FlagVariant::ShortFlag(name) => self.short_name.as_ref() == Some(name),
+        }
+    }
+
+    /// Whether other declares the same flag (by long or short name)
+    pub fn names_same_flag_as(&self, other: &FlagSignature) -> bool {
+        (self.long_name.is_some() && self.long_name == other.long_name)
+            || (self.short_name.is_some() && self.short_name == other.short_name)
+    }
+
     pub fn best_name(&self) -> String {
         self.long_name
             .clone()
@@ -192,6 +217,127 @@ impl Signature {
         }
     }
 
+    /// The signature of fn_stmt, with its declared generics (`fn first<Elem>`) substituted
+    pub fn from_fn_stmt(fn_stmt: &FnStmtNode) -> Signature {
+        Signature::from_sign_and_stmt(fn_stmt.signature(), fn_stmt.decl_item())
+            .declare_generics(&fn_stmt.generic_param_names())
+    }
+
+    pub fn declare_generics(mut self, generic_names: &[String]) -> Signature {
+        if generic_names.is_empty() {
+            return self;
+        }
+        let declare = |ty: &mut ValueType| *ty = ty.clone().declare_generics(generic_names);
+        self.args
+            .iter_mut()
+            .chain(self.var_arg.iter_mut())
+            .chain([&mut self.in_arg, &mut self.ret_arg])
+            .for_each(|arg| declare(&mut arg.ty));
+        self.flags.iter_mut().for_each(|flag| declare(&mut flag.ty));
+        self
+    }
+
+    /// The declared arg at position `pos`. Positions after all args belong to the var_arg
+    fn arg_at(&self, pos: usize) -> Option<&ArgSignature> {
+        self.args.get(pos).or(self.var_arg.as_ref())
+    }
+
+    /// Whether both signatures declare the same tys. Names of args don't matter
+    pub fn has_same_tys_as(&self, other: &Signature) -> bool {
+        let same_arg_tys = |a: &ArgSignature, b: &ArgSignature| {
+            a.ty == b.ty && a.may_be_omitted() == b.may_be_omitted()
+        };
+        self.args.len() == other.args.len()
+            && self
+                .args
+                .iter()
+                .zip(other.args.iter())
+                .all(|(a, b)| same_arg_tys(a, b))
+            && match (&self.var_arg, &other.var_arg) {
+                (None, None) => true,
+                (Some(a), Some(b)) => a.ty == b.ty,
+                _ => false,
+            }
+            && self.in_arg.ty == other.in_arg.ty
+            && self.ret_arg.ty == other.ret_arg.ty
+            && self.flags.len() == other.flags.len()
+            && self.flags.iter().all(|flag| {
+                other.flags.iter().any(|other_flag| {
+                    flag.names_same_flag_as(other_flag)
+                        && flag.ty == other_flag.ty
+                        && flag.is_opt == other_flag.is_opt
+                })
+            })
+    }
+
+    /// Whether a func with this signature may be used where a func of signature `expected` is
+    /// expected. The func must accept every call of `expected` (arg, flag and in tys are
+    /// contravariant) and must return a value assignable to the ret of `expected` (covariant)
+    pub fn is_compatible_with(&self, expected: &Signature) -> bool {
+        // Every arg passed to expected must be accepted
+        let args_accepted = expected.args.iter().enumerate().all(|(pos, expected_arg)| {
+            self.arg_at(pos).is_some_and(|arg| {
+                expected_arg.ty.is_assignable_to(&arg.ty)
+                    && (!expected_arg.may_be_omitted()
+                        || arg.may_be_omitted()
+                        || pos >= self.args.len())
+            })
+        });
+        // Args not known to expected may not be passed
+        let rest_omittable = self
+            .args
+            .iter()
+            .skip(expected.args.len())
+            .all(|arg| arg.may_be_omitted());
+        let var_args_accepted = match (&expected.var_arg, &self.var_arg) {
+            (None, _) => true,
+            (Some(expected_var_arg), Some(var_arg)) => {
+                expected_var_arg.ty.is_assignable_to(&var_arg.ty)
+                    && self
+                        .args
+                        .iter()
+                        .skip(expected.args.len())
+                        .all(|arg| expected_var_arg.ty.is_assignable_to(&arg.ty))
+            }
+            (Some(_), None) => false,
+        };
+        let flags_accepted = expected.flags.iter().all(|expected_flag| {
+            self.flags.iter().any(|flag| {
+                flag.names_same_flag_as(expected_flag)
+                    && expected_flag.ty.is_assignable_to(&flag.ty)
+            })
+        });
+        // Required flags must be passed by all callers of expected
+        let req_flags_passed = self
+            .flags
+            .iter()
+            .filter(|flag| flag.is_required())
+            .all(|flag| {
+                expected.flags.iter().any(|expected_flag| {
+                    expected_flag.is_required() && flag.names_same_flag_as(expected_flag)
+                })
+            });
+
+        args_accepted
+            && rest_omittable
+            && var_args_accepted
+            && flags_accepted
+            && req_flags_passed
+            && expected.in_arg.ty.is_assignable_to(&self.in_arg.ty)
+            && self.ret_arg.ty.is_assignable_to(&expected.ret_arg.ty)
+    }
+
+    /// Whether any of the tys in this signature is generic
+    pub fn is_generic(&self) -> bool {
+        self.args
+            .iter()
+            .chain(self.var_arg.iter())
+            .chain([&self.in_arg, &self.ret_arg])
+            .map(|arg| &arg.ty)
+            .chain(self.flags.iter().map(|flag| &flag.ty))
+            .any(|ty| ty.contains_generic())
+    }
+
     pub fn source_signature(
         sign_node: SignatureNode,
         fallback_arg_decl: SourceCodeItem,
@@ -210,7 +356,8 @@ impl Signature {
                     .type_()
                     .map(|ty_node| ValueType::from_node(&ty_node))
                     .unwrap_or(ValueType::Unspecified);
-                ArgSignature::new(arg_name, ty, is_optional, arg_node.to_item())
+                let has_default = arg_node.default_value().is_some();
+                ArgSignature::new(arg_name, ty, is_optional, has_default, arg_node.to_item())
             })
             .collect();
         let flags = sign_node
@@ -223,7 +370,15 @@ impl Signature {
                     .map(|ty_node| ValueType::from_node(&ty_node))
                     .unwrap_or(ValueType::Bool); // Flags have a default ty of bool.
                 let optional = !flag_node.is_required();
-                FlagSignature::new(long_name, short_name, ty, optional, flag_node.to_item())
+                let has_default = flag_node.default_value().is_some();
+                FlagSignature::new(
+                    long_name,
+                    short_name,
+                    ty,
+                    optional,
+                    has_default,
+                    flag_node.to_item(),
+                )
             })
             .collect();
         let var_arg = sign_node.var_arg().map(|var_arg_node| {
@@ -240,6 +395,40 @@ impl Signature {
     }
 }
 
+impl Display for Signature {
+    /// Formats the signature as a fn type (e.G. `fn(ret: bool arg: num)`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        for (name, arg) in [(IN_ARG_NAME, &self.in_arg), (RET_ARG_NAME, &self.ret_arg)] {
+            if !matches!(arg.ty, ValueType::Unspecified | ValueType::Nil) {
+                parts.push(format!("{}: {}", name, arg.ty));
+            }
+        }
+        for arg in &self.args {
+            let opt = if arg.is_opt { "?" } else { "" };
+            parts.push(format!("{}{}: {}", arg.name, opt, arg.ty));
+        }
+        if let Some(var_arg) = &self.var_arg {
+            parts.push(format!("...{}: {}", var_arg.name, var_arg.ty));
+        }
+        for flag in &self.flags {
+            let req = if flag.is_required() { "req " } else { "" };
+            let names = flag
+                .long_name
+                .iter()
+                .map(|long_name| format!("--{}", long_name))
+                .chain(
+                    flag.short_name
+                        .iter()
+                        .map(|short_name| format!("-{}", short_name)),
+                )
+                .join(" ");
+            parts.push(format!("{}{}: {}", req, names, flag.ty));
+        }
+        write!(f, "fn({})", parts.join(" "))
+    }
+}
+
 /// A node in the ast which is evaluable as a cmd
 #[derive(From, Debug, Clone, is_enum_variant)]
 pub enum CmdEvaluableNode {
@@ -262,6 +451,14 @@ impl CmdEvaluableNode {
             CmdEvaluableNode::MathExpr(_) => None,
         }
     }
+    /// The user provided signature
+    pub fn signature(&self) -> Option<SignatureNode> {
+        match self {
+            CmdEvaluableNode::FnStmt(n) => n.signature(),
+            CmdEvaluableNode::ClsExpr(n) => n.signature(),
+            CmdEvaluableNode::MathExpr(_) => None,
+        }
+    }
 }
 /// Function is a struct containing all needed information for a function/closure
 /// This should allow for less lookup in the ast later on (and easier handling of funcs)
@@ -287,6 +484,22 @@ impl Function {
         Function::from_node(fn_stmt.into(), name, Some(source_file_id))
     }
 
+    /// A method of the strct strct_name. It is named `<StrctName>.<method>`. An untyped `self`
+    /// is of the strct
+    pub fn method_from_node(
+        fn_stmt: FnStmtNode,
+        strct_name: &str,
+        source_file_id: ModPath,
+    ) -> Function {
+        let name = method_cmd_name(strct_name, &fn_stmt.name().expect("Functions have a name"));
+        let mut method = Function::from_node(fn_stmt.into(), name, Some(source_file_id));
+        let self_arg = &mut method.signature.in_arg;
+        if self_arg.name == SELF_ARG_NAME && self_arg.ty == ValueType::Unspecified {
+            self_arg.ty = ValueType::StrctName(strct_name.to_string());
+        }
+        method
+    }
+
     pub fn closure_from_node(cls_expr: ClosureExprNode) -> Function {
         let name = Function::closure_name_from_node(&cls_expr);
         Function::from_node(cls_expr.into(), name, None)
@@ -304,9 +517,7 @@ impl Function {
     ) -> Function {
         // Source the signature (either user provided or default)
         let sign = match &fn_stmt {
-            CmdEvaluableNode::FnStmt(fn_stmt) => {
-                Signature::from_sign_and_stmt(fn_stmt.signature(), fn_stmt.decl_item())
-            }
+            CmdEvaluableNode::FnStmt(fn_stmt) => Signature::from_fn_stmt(fn_stmt),
             CmdEvaluableNode::ClsExpr(cls_expr) => {
                 Signature::from_sign_and_stmt(cls_expr.signature(), cls_expr.decl_item())
             }
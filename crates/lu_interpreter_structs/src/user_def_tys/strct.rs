@@ -1,10 +1,10 @@
 use derive_new::new;
 use itertools::Itertools;
 use lu_error::SourceCodeItem;
-use lu_syntax::ast::StrctFieldNode;
+use lu_syntax::ast::{method_cmd_name, StrctFieldNode};
 use lu_syntax::AstNode;
 
-use crate::ValueType;
+use crate::{ModPath, ValueType};
 use serde::{Deserialize, Serialize};
 
 #[derive(new, PartialEq, Eq, Hash, Clone, Debug, Serialize, Deserialize)]
@@ -33,9 +33,36 @@ pub struct Strct {
     pub name: String,
     pub fields: Vec<StrctField>,
     pub decl: SourceCodeItem,
+    /// The module declaring this strct. Strcts with the same name from different modules
+    /// are different types
+    pub mod_path: ModPath,
+    /// Names of the methods declared in `impl` blocks of this strct
+    #[new(default)]
+    pub methods: Vec<String>,
 }
 
 impl Strct {
+    /// The name of this strct, prefixed by its module (if not declared interactively)
+    pub fn qualified_name(&self) -> String {
+        match &self.mod_path {
+            ModPath::Interactive => self.name.clone(),
+            mod_path => format!("{}:{}", mod_path, self.name),
+        }
+    }
+
+    /// Whether self and other declare the same strct type
+    pub fn is_same_strct_as(&self, other: &Strct) -> bool {
+        self.name == other.name && self.mod_path == other.mod_path
+    }
+
+    /// The name under which the method is found in the module of this strct
+    pub fn method_cmd_name(&self, method_name: &str) -> Option<String> {
+        self.methods
+            .iter()
+            .find(|method| *method == method_name)
+            .map(|method| method_cmd_name(&self.name, method))
+    }
+
     pub fn fields_sorted_by_order(&self) -> Vec<&StrctField> {
         self.fields
             .iter()
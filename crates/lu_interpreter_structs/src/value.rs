@@ -1,4 +1,5 @@
 use enum_as_inner::EnumAsInner;
+use itertools::Itertools;
 use log::{debug, warn};
 use lu_error::lu_source_code_item;
 use lu_stdx::AMtx;
@@ -6,14 +7,33 @@ use lu_syntax::ast::{BareWordToken, NumberExprNode, StringExprNode};
 use ordered_float::OrderedFloat;
 use parking_lot::RwLock;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Weak};
 use std::{fmt::Display, rc::Rc};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::dbg_state::DbgState;
-use crate::{table, Command, CommandCollection, Strct, ValueType};
+use crate::job_table::JobTable;
+use crate::{table, Command, CommandCollection, Enum, Strct, ValueType};
+
+fn serialize_strct_name_only<S>(
+    strct: &Arc<RwLock<Strct>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&strct.read().name)
+}
+
+fn serialize_enum_name_only<S>(enm: &Arc<RwLock<Enum>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&enm.read().name)
+}
 
 #[derive(Clone, Serialize, Deserialize, EnumAsInner)]
 pub enum Value {
@@ -29,15 +49,35 @@ pub enum Value {
     BareWord(String),
     /// A file name. May contain wildcards
     FileName(String),
+    /// Numbers from start (inclusive) to end (exclusive) by step. The numbers are never
+    /// collected, but produced while iterating
+    Range {
+        end: OrderedFloat<f64>,
+        start: OrderedFloat<f64>,
+        step: OrderedFloat<f64>,
+    },
     // The following types are lu-copy-on-write (and therefore enclosed in a Rc)
     Array(Rc<Vec<Value>>),
+    /// Map from str to values. Entries are sorted by key
+    Map(Rc<BTreeMap<String, Value>>),
     Optional {
         inner_ty: ValueType,
         val: Option<Box<Value>>,
     },
-    // Strcts fields
-    // TODO this should contian weak pointer to decl. makes everything easier
-    Strct(String, Rc<Vec<(String, Value)>>),
+    /// A strct value. Holds the decl of its strct and the fields
+    #[serde(skip_deserializing)]
+    Strct(
+        #[serde(serialize_with = "serialize_strct_name_only")] Arc<RwLock<Strct>>,
+        Rc<Vec<(String, Value)>>,
+    ),
+    /// A variant of the enum decl. Fields are empty for variants without fields
+    #[serde(skip_deserializing)]
+    Enum {
+        fields: Rc<Vec<(String, Value)>>,
+        #[serde(rename = "name", serialize_with = "serialize_enum_name_only")]
+        decl: Arc<RwLock<Enum>>,
+        variant: String,
+    },
     #[serde(skip)]
     Command(Rc<dyn Command>),
     CommandCollection(CommandCollection),
@@ -45,7 +85,11 @@ pub enum Value {
     #[serde(skip)] // TODO serialize
     StrctDecl(Arc<RwLock<Strct>>),
     #[serde(skip)] // TODO serialize
+    EnumDecl(Arc<RwLock<Enum>>),
+    #[serde(skip)] // TODO serialize
     DbgState(AMtx<DbgState>),
+    #[serde(skip)]
+    JobTable(AMtx<JobTable>),
 }
 
 impl PartialEq for Value {
@@ -57,14 +101,49 @@ impl PartialEq for Value {
             (Value::Number(lhs), Value::Number(rhs)) => lhs == rhs,
             (Value::String(lhs), Value::String(rhs)) => lhs == rhs,
             (Value::BareWord(lhs), Value::BareWord(rhs)) => lhs == rhs,
+            (
+                Value::Range {
+                    start: lhs_start,
+                    end: lhs_end,
+                    step: lhs_step,
+                },
+                Value::Range {
+                    start: rhs_start,
+                    end: rhs_end,
+                    step: rhs_step,
+                },
+            ) => lhs_start == rhs_start && lhs_end == rhs_end && lhs_step == rhs_step,
             (Value::Array(lhs), Value::Array(rhs)) => lhs == rhs,
+            (Value::Map(lhs), Value::Map(rhs)) => lhs == rhs,
             (Value::Command(lhs), Value::Command(rhs)) => Rc::ptr_eq(lhs, rhs),
-            (Value::Strct(lhs_name, lhs_fields), Value::Strct(rhs_name, rhs_fields)) => {
-                lhs_name == rhs_name && lhs_fields == rhs_fields
+            (Value::Strct(lhs_decl, lhs_fields), Value::Strct(rhs_decl, rhs_fields)) => {
+                (Arc::ptr_eq(lhs_decl, rhs_decl)
+                    || lhs_decl.read().is_same_strct_as(&rhs_decl.read()))
+                    && lhs_fields == rhs_fields
+            }
+            (
+                Value::Enum {
+                    decl: lhs_decl,
+                    variant: lhs_variant,
+                    fields: lhs_fields,
+                },
+                Value::Enum {
+                    decl: rhs_decl,
+                    variant: rhs_variant,
+                    fields: rhs_fields,
+                },
+            ) => {
+                (Arc::ptr_eq(lhs_decl, rhs_decl)
+                    || lhs_decl.read().is_same_enum_as(&rhs_decl.read()))
+                    && lhs_variant == rhs_variant
+                    && lhs_fields == rhs_fields
             }
             (Value::StrctDecl(lhs_decl), Value::StrctDecl(rhs_decl)) => {
                 Arc::as_ptr(lhs_decl) == Arc::as_ptr(rhs_decl)
             }
+            (Value::EnumDecl(lhs_decl), Value::EnumDecl(rhs_decl)) => {
+                Arc::as_ptr(lhs_decl) == Arc::as_ptr(rhs_decl)
+            }
             _ => {
                 warn!(
                     "Not doing equality comparison for values {} {}",
@@ -85,9 +164,13 @@ impl PartialOrd for Value {
             (Value::Number(l), Value::Number(r)) => l.partial_cmp(r),
             (Value::String(l), Value::String(r)) => l.partial_cmp(r),
             (Value::BareWord(l), Value::BareWord(r)) => l.partial_cmp(r),
+            (Value::Range { .. }, Value::Range { .. }) => None,
             (Value::Array(_), Value::Array(_)) => None,
+            (Value::Map(_), Value::Map(_)) => None,
             (Value::Command(_), Value::Command(_)) => None,
             (Value::StrctDecl(_), Value::StrctDecl(_)) => None,
+            (Value::Enum { .. }, Value::Enum { .. }) => None,
+            (Value::EnumDecl(_), Value::EnumDecl(_)) => None,
             _ => {
                 unreachable!("Caught by ty checker");
             }
@@ -106,15 +189,36 @@ impl Hash for Value {
             Value::Number(v) => v.hash(state),
             Value::String(v) => v.hash(state),
             Value::BareWord(v) => v.hash(state),
+            Value::Range { start, end, step } => {
+                start.hash(state);
+                end.hash(state);
+                step.hash(state);
+            }
             Value::Array(v) => v.hash(state),
-            Value::Strct(name, v) => {
-                name.hash(state);
+            Value::Map(v) => v.hash(state),
+            Value::Strct(decl, v) => {
+                let l_decl = decl.read();
+                l_decl.name.hash(state);
+                l_decl.mod_path.hash(state);
                 v.hash(state);
             }
+            Value::Enum {
+                decl,
+                variant,
+                fields,
+            } => {
+                let l_decl = decl.read();
+                l_decl.name.hash(state);
+                l_decl.mod_path.hash(state);
+                variant.hash(state);
+                fields.hash(state);
+            }
             Value::Command(func) => Rc::as_ptr(func).hash(state),
             Value::CommandCollection(col) => col.hash(state),
             Value::StrctDecl(strct) => Arc::as_ptr(strct).hash(state),
+            Value::EnumDecl(enm) => Arc::as_ptr(enm).hash(state),
             Value::DbgState(v) => Arc::as_ptr(v).hash(state),
+            Value::JobTable(v) => Arc::as_ptr(v).hash(state),
             Value::FileName(v) => v.hash(state),
             Value::Optional { val, .. } => val.hash(state),
         }
@@ -128,11 +232,35 @@ impl Value {
     pub fn new_strct_decl(strct: Strct) -> Self {
         Value::StrctDecl(Arc::new(RwLock::new(strct)))
     }
+    pub fn new_enum_decl(enm: Enum) -> Self {
+        Value::EnumDecl(Arc::new(RwLock::new(enm)))
+    }
     pub fn new_array(vals: Vec<Value>) -> Self {
         Value::Array(Rc::new(vals))
     }
-    pub fn new_strct(name: String, vals: Vec<(String, Value)>) -> Self {
-        Value::Strct(name, Rc::new(vals))
+    pub fn new_range(start: f64, end: f64, step: f64) -> Self {
+        Value::Range {
+            start: start.into(),
+            end: end.into(),
+            step: step.into(),
+        }
+    }
+    pub fn new_map(entries: BTreeMap<String, Value>) -> Self {
+        Value::Map(Rc::new(entries))
+    }
+    pub fn new_strct(decl: Arc<RwLock<Strct>>, vals: Vec<(String, Value)>) -> Self {
+        Value::Strct(decl, Rc::new(vals))
+    }
+    pub fn new_enum(
+        decl: Arc<RwLock<Enum>>,
+        variant: String,
+        fields: Vec<(String, Value)>,
+    ) -> Self {
+        Value::Enum {
+            decl,
+            variant,
+            fields: Rc::new(fields),
+        }
     }
     pub fn new_optional(inner_ty: ValueType, inner_val: Option<Value>) -> Self {
         Value::Optional {
@@ -167,11 +295,16 @@ impl Value {
             Value::Number(n) => Some(*n != OrderedFloat::from(0f64)),
             Value::String(s) | Value::BareWord(s) => Some(!s.is_empty()),
             Value::Array(arr) => Some(!arr.is_empty()),
+            Value::Map(map) => Some(!map.is_empty()),
+            Value::Range { .. } => None,
             Value::Command(_) => None,
             Value::StrctDecl(_) => None,
             Value::Strct(_, _) => None,
+            Value::Enum { .. } => None,
+            Value::EnumDecl(_) => None,
             Value::CommandCollection(_) => None,
             Value::DbgState(_) => None,
+            Value::JobTable(_) => None,
             Value::Optional { .. } => None,
         }
     }
@@ -185,14 +318,18 @@ impl Value {
             | (Value::BareWord(_), ValueType::String)
             | (Value::BareWord(_), ValueType::FileName)
             | (Value::FileName(_), ValueType::String)
-            | (Value::FileName(_), ValueType::FileName) => return true,
-            (Value::Strct(name, _), ValueType::Strct(strct)) => {
+            | (Value::FileName(_), ValueType::FileName)
+            | (Value::Range { .. }, ValueType::Range) => return true,
+            (Value::Strct(decl, _), ValueType::Strct(strct)) => {
                 let strct = Weak::upgrade(strct).unwrap();
-                let l_strct = strct.read();
-                *name == l_strct.name
+                Arc::ptr_eq(decl, &strct) || decl.read().is_same_strct_as(&strct.read())
+            }
+            (Value::Enum { decl, .. }, ValueType::Enum(enm)) => {
+                let enm = Weak::upgrade(enm).unwrap();
+                Arc::ptr_eq(decl, &enm) || decl.read().is_same_enum_as(&enm.read())
             }
-            (Value::Command(_), ValueType::Func(_)) => {
-                todo!("Assert signatures are the same")
+            (Value::Command(cmd), ValueType::Func(sign)) => {
+                cmd.signature().is_compatible_with(sign)
             }
             (Value::Array(inner), ValueType::Array { inner_ty, .. }) => {
                 // TODO add array ty to value
@@ -202,6 +339,9 @@ impl Value {
                     inner[0].is_of_type(inner_ty)
                 }
             }
+            (Value::Map(map), ValueType::Map { inner_ty, .. }) => {
+                map.values().all(|val| val.is_of_type(inner_ty))
+            }
             _ => false,
         }
     }
@@ -214,17 +354,28 @@ impl Value {
             Value::String(_) => ValueType::String,
             Value::BareWord(_) => ValueType::BareWord,
             Value::FileName(_) => ValueType::FileName,
+            Value::Range { .. } => ValueType::Range,
             // TODO better inner_ty
             Value::Array(_) => ValueType::Array {
                 inner_ty: Box::new(ValueType::Unspecified),
                 inner_ty_decl: lu_source_code_item!(),
             },
-            // TODO if strct contains pointer to decl return proper strct here
-            Value::Strct(name, _) => ValueType::StrctName(name.clone()),
+            Value::Map(_) => ValueType::Map {
+                inner_ty: Box::new(ValueType::Unspecified),
+                inner_ty_decl: lu_source_code_item!(),
+            },
+            Value::Strct(decl, _) => ValueType::Strct(Arc::downgrade(decl)),
+            Value::Enum { decl, .. } => ValueType::Enum(Arc::downgrade(decl)),
+            // Enum decls are only referred to as tys (`EntryKind.File`)
+            Value::EnumDecl(enm) => ValueType::Enum(Arc::downgrade(enm)),
             Value::Command(cmd) => ValueType::Func(Box::new(cmd.signature().clone())),
             // TODO these should never be reachable
             Value::StrctDecl(_) => todo!("Add pseudo ValueType::StructDecl"),
             Value::DbgState(_) => todo!("Add pseudo ValueType::DbgState"),
+            // The job table is only inserted into the global frame of the interpreter. The ty
+            // checker only knows vars of the source code and env vars. Referring to the job table
+            // is therefore a VarNotInScope error and the value never flows into code
+            Value::JobTable(_) => unreachable!("The job table is not visible to lush code"),
             Value::CommandCollection(_) => todo!(),
             Value::Optional { inner_ty, .. } => ValueType::Optional {
                 inner_ty: Box::new(inner_ty.clone()),
@@ -240,6 +391,20 @@ impl Value {
         }
     }
 
+    /// Returns the string representation of self, as used when interpolated into a string.
+    /// Only primitive values can be interpolated
+    pub fn coerce_to_interpolated_string(&self) -> Option<String> {
+        match self {
+            Value::Nil
+            | Value::Bool(_)
+            | Value::Number(_)
+            | Value::String(_)
+            | Value::BareWord(_)
+            | Value::FileName(_) => Some(self.to_string()),
+            _ => None,
+        }
+    }
+
     pub fn coerce_to_filename(&self) -> Option<&String> {
         match self {
             Value::BareWord(s) | Value::FileName(s) | Value::String(s) => Some(s),
@@ -265,6 +430,13 @@ impl Display for Value {
             Value::Number(v) => v.fmt(f),
             Value::String(v) => v.fmt(f),
             Value::BareWord(v) => v.fmt(f),
+            Value::Range { start, end, step } => {
+                write!(f, "{}..{}", start, end)?;
+                if *step != OrderedFloat::from(1f64) {
+                    write!(f, "..{}", step)?;
+                }
+                Ok(())
+            }
             Value::Array(arr) => {
                 if arr.is_empty() {
                     write!(f, "")
@@ -274,12 +446,36 @@ impl Display for Value {
                     write!(f, "{:?}", arr)
                 }
             }
+            Value::Map(map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, val)| format!("{}: {}", key, val))
+                    .join(" ");
+                write!(f, "{{{}}}", entries)
+            }
             Value::Command(v) => write!(f, "Command: {} {:?}", v.name(), v.signature_item()),
             Value::StrctDecl(v) => write!(f, "{:p}", Arc::as_ptr(v)),
-            Value::Strct(name, fields) => write!(f, "{}{:?}", name, fields),
+            Value::Strct(decl, fields) => write!(f, "{}{:?}", decl.read().name, fields),
+            Value::Enum {
+                decl,
+                variant,
+                fields,
+            } => {
+                write!(f, "{}.{}", decl.read().name, variant)?;
+                if !fields.is_empty() {
+                    let fields = fields
+                        .iter()
+                        .map(|(name, val)| format!("{}: {}", name, val))
+                        .join(" ");
+                    write!(f, "{{{}}}", fields)?;
+                }
+                Ok(())
+            }
+            Value::EnumDecl(v) => write!(f, "{:p}", Arc::as_ptr(v)),
             // TODO nice display here
             Value::CommandCollection(col) => write!(f, "{:?}", col),
             Value::DbgState(dbg_state) => write!(f, "{:?}", dbg_state),
+            Value::JobTable(job_table) => write!(f, "{:?}", job_table),
             Value::FileName(s) => write!(f, "{}", s),
             Value::Optional { val, .. } => match val {
                 Some(val) => write!(f, "Some({})", val),
@@ -15,25 +15,33 @@ use parking_lot::RwLock;
 use rusttyc::{types::Arity, Constructable, Partial, Variant as TcVariant};
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::{Scope, Signature, Strct, Variable};
+use crate::{Enum, Scope, Signature, Strct, Variable};
 
-fn cmp_sign_types(_: &Box<Signature>, _: &Box<Signature>) -> bool {
-    todo!()
-    // a.in_type == b.in_type && a.ret_type == b.ret_type &&
-    //     a.iter
+fn cmp_sign_types(a: &Signature, b: &Signature) -> bool {
+    a.has_same_tys_as(b)
 }
 fn cmp_strcts(a: &Weak<RwLock<Strct>>, b: &Weak<RwLock<Strct>>) -> bool {
-    // TODO also compare that they are declared in same module !!!
     let (a, b) = (Weak::upgrade(a).unwrap(), Weak::upgrade(b).unwrap()); // TODO no unwrap
-    let (l_a, l_b) = (a.read(), b.read());
-    l_a.name == l_b.name
+    if Arc::ptr_eq(&a, &b) {
+        return true;
+    }
+    let (l_a, l_b) = (a.read_recursive(), b.read_recursive());
+    l_a.is_same_strct_as(&l_b)
+}
+fn cmp_enums(a: &Weak<RwLock<Enum>>, b: &Weak<RwLock<Enum>>) -> bool {
+    let (a, b) = (Weak::upgrade(a).unwrap(), Weak::upgrade(b).unwrap());
+    if Arc::ptr_eq(&a, &b) {
+        return true;
+    }
+    let (l_a, l_b) = (a.read_recursive(), b.read_recursive());
+    l_a.is_same_enum_as(&l_b)
 }
 fn cmp_inner_tys(a: &Box<ValueType>, b: &Box<ValueType>) -> bool {
     *a == *b
 }
 
-fn hash_as_ptr<H: Hasher>(strct: &Weak<RwLock<Strct>>, state: &mut H) {
-    Hash::hash(&strct.as_ptr(), state)
+fn hash_as_ptr<T, H: Hasher>(decl: &Weak<RwLock<T>>, state: &mut H) {
+    Hash::hash(&decl.as_ptr(), state)
 }
 
 fn serialize_name_only<S>(strct: &Weak<RwLock<Strct>>, serializer: S) -> Result<S::Ok, S::Error>
@@ -46,6 +54,15 @@ where
     serializer.serialize_newtype_struct("StrctName", &l_strct.name)
 }
 
+fn serialize_enum_name_only<S>(enm: &Weak<RwLock<Enum>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let enm = Weak::upgrade(enm).unwrap();
+    let l_enm = enm.read();
+    serializer.serialize_newtype_struct("EnumName", &l_enm.name)
+}
+
 #[derive(Educe, Clone, Debug, Serialize, Deserialize, EnumAsInner, is_enum_variant)]
 #[educe(Hash, PartialEq)]
 pub enum ValueType {
@@ -67,6 +84,8 @@ pub enum ValueType {
     String,
     BareWord,
     FileName,
+    /// A range of numbers (start..end..step). Iterable like an array of numbers
+    Range,
     /// Struct with name (Final Strct type when)
     #[serde(skip_deserializing)]
     #[serde(serialize_with = "serialize_name_only")]
@@ -80,6 +99,14 @@ pub enum ValueType {
     /// However we need to create a ValueType when sourcing functions etc. Therefore we introduce
     /// this temporary type
     StrctName(String),
+    /// Enum with name. Before resolving, enums are referred to by ValueType::StrctName
+    #[serde(skip_deserializing)]
+    #[serde(serialize_with = "serialize_enum_name_only")]
+    Enum(
+        #[educe(Hash(method = "hash_as_ptr"))]
+        #[educe(PartialEq(method = "cmp_enums"))]
+        Weak<RwLock<Enum>>,
+    ),
     /// Box with inner ty and inner_ty_decl
     Array {
         #[educe(PartialEq(method = "cmp_inner_tys"))]
@@ -93,6 +120,13 @@ pub enum ValueType {
         #[educe(PartialEq(ignore))]
         inner_ty_decl: SourceCodeItem,
     },
+    /// Map from str to inner_ty
+    Map {
+        #[educe(PartialEq(method = "cmp_inner_tys"))]
+        inner_ty: Box<ValueType>,
+        #[educe(PartialEq(ignore))]
+        inner_ty_decl: SourceCodeItem,
+    },
     Func(#[educe(PartialEq(method = "cmp_sign_types"))] Box<Signature>),
 }
 
@@ -113,6 +147,13 @@ impl ValueType {
         }
     }
 
+    pub fn new_map(inner_ty: ValueType, inner_ty_decl: SourceCodeItem) -> Self {
+        ValueType::Map {
+            inner_ty: Box::new(inner_ty),
+            inner_ty_decl,
+        }
+    }
+
     pub fn new_func(sign: Signature) -> Self {
         ValueType::Func(Box::new(sign))
     }
@@ -121,6 +162,10 @@ impl ValueType {
         ValueType::Strct(strct)
     }
 
+    pub fn new_enum(enm: Weak<RwLock<Enum>>) -> Self {
+        ValueType::Enum(enm)
+    }
+
     /// TODO this func feels like a halfway solution
     /// Function and Strct from_node can't use it (as it happens in the resolve step)
     /// Therefore these would neet a resolve_strct_names step after creation, called in typecheck
@@ -136,6 +181,9 @@ impl ValueType {
     ) -> Outcome<ValueType> {
         let ty = Self::from_node(node);
         if let Self::StrctName(strct_name) = ty {
+            if let Some(enm) = scope.find_enum(&strct_name) {
+                return Outcome::ok(ValueType::Enum(Arc::downgrade(enm)));
+            }
             let strct = scope
                 .expect_strct(&strct_name, node.to_item())
                 .map(|strct| Arc::downgrade(strct))
@@ -159,6 +207,7 @@ impl ValueType {
             LuTypeSpecifierElement::BoolKeyword(_) => ValueType::Bool,
             LuTypeSpecifierElement::PathKeyword(_) => ValueType::FileName,
             LuTypeSpecifierElement::StringKeyword(_) => ValueType::String,
+            LuTypeSpecifierElement::RangeKeyword(_) => ValueType::Range,
             LuTypeSpecifierElement::BareWord(_) => ValueType::BareWord,
             LuTypeSpecifierElement::GenericType(n) => ValueType::Generic(n.text().to_string()),
             LuTypeSpecifierElement::StrctName(n) => ValueType::StrctName(n.text().to_string()),
@@ -170,6 +219,14 @@ impl ValueType {
                 };
                 ValueType::new_array(inner_ty, inner_ty_decl)
             }
+            LuTypeSpecifierElement::MapType(map) => {
+                let (inner_ty, inner_ty_decl) = if let Some(inner) = map.inner_type() {
+                    (ValueType::from_node(&inner), inner.to_item())
+                } else {
+                    (ValueType::Unspecified, map.to_item())
+                };
+                ValueType::new_map(inner_ty, inner_ty_decl)
+            }
             LuTypeSpecifierElement::FnType(fn_ty) => {
                 let sign = Signature::from_sign_and_stmt(fn_ty.signature(), fn_ty.to_item());
                 ValueType::new_func(sign)
@@ -197,6 +254,34 @@ impl ValueType {
             inner_ty.map_from_strct_to_strct_name_test_only();
         } else if let ValueType::Optional { inner_ty, .. } = self {
             inner_ty.map_from_strct_to_strct_name_test_only();
+        } else if let ValueType::Map { inner_ty, .. } = self {
+            inner_ty.map_from_strct_to_strct_name_test_only();
+        }
+    }
+
+    /// Whether values of this ty may be interpolated into a string. Tys, which are not
+    /// (yet) known, are assumed to be interpolatable
+    pub fn is_interpolatable_into_string(&self) -> bool {
+        match self {
+            ValueType::Error
+            | ValueType::Unspecified
+            | ValueType::Any
+            | ValueType::Generic(_)
+            | ValueType::Nil
+            | ValueType::Bool
+            | ValueType::Number
+            | ValueType::String
+            | ValueType::BareWord
+            | ValueType::FileName => true,
+            ValueType::Void
+            | ValueType::Range
+            | ValueType::Strct(_)
+            | ValueType::Enum(_)
+            | ValueType::StrctName(_)
+            | ValueType::Array { .. }
+            | ValueType::Optional { .. }
+            | ValueType::Map { .. }
+            | ValueType::Func(_) => false,
         }
     }
 
@@ -212,7 +297,9 @@ impl ValueType {
             | ValueType::String
             | ValueType::BareWord
             | ValueType::FileName
+            | ValueType::Range
             | ValueType::Strct(_)
+            | ValueType::Enum(_)
             | ValueType::StrctName(_) => self,
             ValueType::Generic(_) => {
                 debug!("Substituting generic ty {} with {}", self, subst_with);
@@ -232,12 +319,102 @@ impl ValueType {
                 inner_ty: Box::new(inner_ty.subst_generic_ty(subst_with)),
                 inner_ty_decl,
             },
+            ValueType::Map {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Map {
+                inner_ty: Box::new(inner_ty.subst_generic_ty(subst_with)),
+                inner_ty_decl,
+            },
             ValueType::Func(_) => {
                 warn!("Not substituting generics in func ValueType");
                 self
             }
         }
     }
+
+    pub fn contains_generic(&self) -> bool {
+        match self {
+            ValueType::Generic(_) => true,
+            ValueType::Array { inner_ty, .. }
+            | ValueType::Optional { inner_ty, .. }
+            | ValueType::Map { inner_ty, .. } => inner_ty.contains_generic(),
+            ValueType::Func(sign) => sign.is_generic(),
+            _ => false,
+        }
+    }
+
+    /// Whether a value of this ty may be used where a value of ty `expected` is expected.
+    /// Funcs follow the usual variance rules (see Signature::is_compatible_with)
+    pub fn is_assignable_to(&self, expected: &ValueType) -> bool {
+        match (self, expected) {
+            (ValueType::Error, _)
+            | (_, ValueType::Error)
+            | (ValueType::Unspecified, _)
+            | (_, ValueType::Unspecified)
+            | (_, ValueType::Any)
+            | (_, ValueType::Generic(_))
+            | (ValueType::Generic(_), _) => true,
+            (ValueType::FileName, ValueType::String)
+            | (ValueType::BareWord, ValueType::String)
+            | (ValueType::BareWord, ValueType::FileName) => true,
+            (
+                ValueType::Array { inner_ty, .. },
+                ValueType::Array {
+                    inner_ty: expected_inner_ty,
+                    ..
+                },
+            )
+            | (
+                ValueType::Optional { inner_ty, .. },
+                ValueType::Optional {
+                    inner_ty: expected_inner_ty,
+                    ..
+                },
+            )
+            | (
+                ValueType::Map { inner_ty, .. },
+                ValueType::Map {
+                    inner_ty: expected_inner_ty,
+                    ..
+                },
+            ) => inner_ty.is_assignable_to(expected_inner_ty),
+            (ValueType::Func(sign), ValueType::Func(expected_sign)) => {
+                sign.is_compatible_with(expected_sign)
+            }
+            _ => self == expected,
+        }
+    }
+
+    /// Turns all StrctNames, which name one of the declared generics, into generic tys
+    pub fn declare_generics(self, generic_names: &[String]) -> Self {
+        match self {
+            ValueType::StrctName(name) if generic_names.contains(&name) => ValueType::Generic(name),
+            ValueType::Array {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Array {
+                inner_ty: Box::new(inner_ty.declare_generics(generic_names)),
+                inner_ty_decl,
+            },
+            ValueType::Optional {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Optional {
+                inner_ty: Box::new(inner_ty.declare_generics(generic_names)),
+                inner_ty_decl,
+            },
+            ValueType::Map {
+                inner_ty,
+                inner_ty_decl,
+            } => ValueType::Map {
+                inner_ty: Box::new(inner_ty.declare_generics(generic_names)),
+                inner_ty_decl,
+            },
+            ValueType::Func(sign) => ValueType::new_func(sign.declare_generics(generic_names)),
+            _ => self,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -335,6 +512,33 @@ impl TcVariant for ValueType {
                     Some(ValueType::new_optional(inner.variant, lhs_decl.clone()))
                     // TODO the decl may be wrong for some meets
                 }
+                (
+                    ValueType::Map {
+                        inner_ty: lhs_inner,
+                        inner_ty_decl: lhs_decl,
+                    },
+                    ValueType::Map {
+                        inner_ty: rhs_inner,
+                        ..
+                    },
+                ) => {
+                    let (lhs_arity, rhs_arity) = match (lhs_inner.arity(), rhs_inner.arity()) {
+                        (Arity::Fixed(l), Arity::Fixed(r)) => (l, r),
+                        _ => unreachable!("All types have fixed arity"),
+                    };
+                    let inner = ValueType::meet(
+                        Partial {
+                            variant: *lhs_inner.clone(),
+                            least_arity: lhs_arity,
+                        },
+                        Partial {
+                            variant: *rhs_inner.clone(),
+                            least_arity: rhs_arity,
+                        },
+                    )?;
+                    Some(ValueType::new_map(inner.variant, lhs_decl.clone()))
+                    // TODO the decl may be wrong for some meets
+                }
                 _ => None,
             };
             coercable_ty.ok_or_else(|| ValueTypeErr::NotMeetAble {
@@ -366,9 +570,13 @@ impl TcVariant for ValueType {
             | ValueType::String
             | ValueType::Func(_)
             | ValueType::Strct(_)
+            | ValueType::Enum(_)
             | ValueType::FileName
+            | ValueType::Range
             | ValueType::BareWord => Arity::Fixed(0),
-            ValueType::Array { .. } | ValueType::Optional { .. } => Arity::Fixed(1),
+            ValueType::Array { .. } | ValueType::Optional { .. } | ValueType::Map { .. } => {
+                Arity::Fixed(1)
+            }
             ValueType::Error => Self::arity(&ValueType::Any),
             ValueType::StrctName(name) => {
                 warn!(
@@ -401,22 +609,25 @@ impl Display for ValueType {
             ValueType::Number => write!(f, "num"),
             ValueType::String => write!(f, "str"),
             ValueType::BareWord => write!(f, "bare_word"),
+            ValueType::Range => write!(f, "range"),
             ValueType::Array { inner_ty, .. } => write!(f, "[{}]", *inner_ty),
             ValueType::Strct(strct) => {
                 let strct = Weak::upgrade(strct).unwrap();
                 let l_strct = strct.read();
-                write!(f, "{}{{ ", l_strct.name)?;
-                for field in &l_strct.fields {
-                    write!(f, "{}: {}", field.name, field.ty)?;
-                }
-                write!(f, "}}")
+                write!(f, "{}", l_strct.qualified_name())
+            }
+            ValueType::Enum(enm) => {
+                let enm = Weak::upgrade(enm).unwrap();
+                let l_enm = enm.read();
+                write!(f, "{}", l_enm.qualified_name())
             }
-            ValueType::Func(_) => todo!(),
+            ValueType::Func(sign) => write!(f, "{}", sign),
             ValueType::Void => write!(f, "void"),
             ValueType::Generic(name) => write!(f, "{}", name),
             ValueType::StrctName(name) => write!(f, "{}", name),
             ValueType::FileName => write!(f, "path"),
             ValueType::Optional { inner_ty, .. } => write!(f, "Opt({})", *inner_ty),
+            ValueType::Map { inner_ty, .. } => write!(f, "{{str: {}}}", *inner_ty),
         }
     }
 }
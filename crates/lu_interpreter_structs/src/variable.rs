@@ -5,7 +5,7 @@ use lu_syntax_elements::constants::IN_ARG_NAME;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
-use crate::{Command, CommandCollection, Strct, Value};
+use crate::{Command, CommandCollection, Enum, Strct, Value};
 
 #[derive(Clone, Debug, Eq, PartialEq, new, Serialize, Deserialize, Hash)]
 pub enum VarAttributes {
@@ -52,6 +52,17 @@ impl Variable {
         Variable::new(name, Value::StrctDecl(strct), decl)
     }
 
+    pub fn new_enum_decl(enm: Enum) -> Variable {
+        let decl = enm.decl.clone();
+        Variable::new(enm.name.clone(), Value::new_enum_decl(enm), decl)
+    }
+
+    pub fn new_enum_decl_arc(enm: Arc<RwLock<Enum>>) -> Variable {
+        let name = enm.read().name.clone();
+        let decl = enm.read().decl.clone();
+        Variable::new(name, Value::EnumDecl(enm), decl)
+    }
+
     pub fn new_in(val: Value, decl: SourceCodeItem) -> Self {
         Self::new(IN_ARG_NAME.to_string(), val, decl)
     }
@@ -11,6 +11,10 @@ pub enum SyntaxKind {
     ImpureKeyword,
     #[token("struct")]
     StrctKeyword,
+    #[token("enum")]
+    EnumKeyword,
+    #[token("impl")]
+    ImplKeyword,
     #[token("opt")]
     OptKeyword,
     #[token("Some")]
@@ -25,6 +29,8 @@ pub enum SyntaxKind {
     OrKeyword,
     #[token("and")]
     AndKeyword,
+    #[token("not")]
+    NotKeyword,
     #[token("use")]
     UseKeyword,
     #[token("let")]
@@ -67,14 +73,29 @@ pub enum SyntaxKind {
     NumberKeyword,
     #[token("str")]
     StringKeyword,
+    #[token("range")]
+    RangeKeyword,
     #[token("cls")]
     ClsKeyword,
     #[token("ret")]
     RetKeyword,
+    #[token("break")]
+    BreakKeyword,
+    #[token("continue")]
+    ContinueKeyword,
+    #[token("try")]
+    TryKeyword,
+    #[token("catch")]
+    CatchKeyword,
+    #[token("match")]
+    MatchKeyword,
+    #[token("case")]
+    CaseKeyword,
     #[regex("[TU][0-9]?", priority = 1000)]
     GenericType,
     ArrayType,
     FnType,
+    MapType,
     OptModifier,
     #[token("(")]
     LeftParenthesis,
@@ -92,12 +113,18 @@ pub enum SyntaxKind {
     PlusSign,
     #[token("-")]
     MinusSign,
+    #[token("--")]
+    DoubleMinus,
     #[token("*", priority = 3)]
     MultSign,
     #[token("/")]
     FilePathSep,
     #[token("//")]
     DivSign,
+    #[token("%")]
+    ModSign,
+    #[token("**", priority = 3)]
+    PowSign,
     #[token("<")]
     LessThanSign,
     #[token("<=")]
@@ -112,6 +139,8 @@ pub enum SyntaxKind {
     BiggerOrEqualSign,
     #[token(">>")]
     RightStream,
+    #[token(">|")]
+    TruncRightStream,
     #[token("/=")]
     DivAssignSign,
     #[token("*=")]
@@ -124,6 +153,8 @@ pub enum SyntaxKind {
     AssignSign,
     #[token("|")]
     Pipe,
+    #[token("&")]
+    Ampersand,
     #[token("$")]
     Dollar,
     #[token("?")]
@@ -136,6 +167,8 @@ pub enum SyntaxKind {
     DoubleQuote,
     #[token("'")]
     SingleQuote,
+    #[token("\\")]
+    BackSlash,
     #[error]
     Error,
     ParserInternal,
@@ -152,6 +185,7 @@ pub enum SyntaxKind {
     StringContent,
     VarDeclName,
     FnDeclName,
+    GenericParamName,
     ArgName,
     VarArgName,
     #[regex("--[_a-zA-Z]+[_a-zA-Z0-9]*")]
@@ -163,6 +197,8 @@ pub enum SyntaxKind {
     #[regex("[A-Z]+[_a-zA-Z0-9]*")]
     StrctName,
     StrctFieldName,
+    EnumVariantName,
+    MapKey,
     AbsFileName,
     RelFileName,
     SourceFile,
@@ -175,8 +211,13 @@ pub enum SyntaxKind {
     StrctField,
     StrctCtorExpr,
     StrctFieldCtorStmt,
+    EnumStmt,
+    EnumVariant,
+    EnumCtorExpr,
+    ImplStmt,
     LetStmt,
     FnStmt,
+    GenericParams,
     RetStmt,
     IfElifElseStmt,
     IfOptElifOptStmt,
@@ -186,8 +227,25 @@ pub enum SyntaxKind {
     ElifBlock,
     ElseBlock,
     ForStmt,
+    WhileStmt,
+    BreakStmt,
+    ContinueStmt,
+    TryStmt,
+    CatchStmt,
+    MatchStmt,
+    MatchArm,
+    LiteralPattern,
+    BindingPattern,
+    NonePattern,
+    SomePattern,
+    StrctPattern,
+    StrctFieldPattern,
+    EnumPattern,
+    ArrayPattern,
+    ArrayRestPattern,
     CmdStmt,
     PipedCmdsStmt,
+    BackgroundStmt,
     BlockStmt,
     Signature,
     FlagSignature,
@@ -199,7 +257,11 @@ pub enum SyntaxKind {
     StringExpr,
     NumberExpr,
     ValuePathExpr,
+    UnaryExpr,
+    RangeExpr,
     ArrayExpr,
+    MapExpr,
+    MapEntry,
     OptionalExpr,
     TableExpr,
     BooleanExpr,
@@ -207,6 +269,7 @@ pub enum SyntaxKind {
     Statement,
     Condition,
     OperatorExpr,
+    UnaryOperator,
     LuTypeSpecifier,
     CmdArg,
     Flag,
@@ -214,6 +277,8 @@ pub enum SyntaxKind {
     FileName,
     FileNamePart,
     IfElifElseStmtPart,
+    MatchPattern,
+    StringExprPart,
     PipeOrValueExpr,
     __LAST,
 }
@@ -223,6 +288,8 @@ impl SyntaxKind{
         match self {
             SyntaxKind::ImpureKeyword => "ImpureKeyword",
             SyntaxKind::StrctKeyword => "StrctKeyword",
+            SyntaxKind::EnumKeyword => "EnumKeyword",
+            SyntaxKind::ImplKeyword => "ImplKeyword",
             SyntaxKind::OptKeyword => "OptKeyword",
             SyntaxKind::SomeKeyword => "SomeKeyword",
             SyntaxKind::NoneKeyword => "NoneKeyword",
@@ -230,6 +297,7 @@ impl SyntaxKind{
             SyntaxKind::ReqKeyword => "ReqKeyword",
             SyntaxKind::OrKeyword => "OrKeyword",
             SyntaxKind::AndKeyword => "AndKeyword",
+            SyntaxKind::NotKeyword => "NotKeyword",
             SyntaxKind::UseKeyword => "UseKeyword",
             SyntaxKind::LetKeyword => "LetKeyword",
             SyntaxKind::FnKeyword => "FnKeyword",
@@ -251,11 +319,19 @@ impl SyntaxKind{
             SyntaxKind::FalseKeyword => "FalseKeyword",
             SyntaxKind::NumberKeyword => "NumberKeyword",
             SyntaxKind::StringKeyword => "StringKeyword",
+            SyntaxKind::RangeKeyword => "RangeKeyword",
             SyntaxKind::ClsKeyword => "ClsKeyword",
             SyntaxKind::RetKeyword => "RetKeyword",
+            SyntaxKind::BreakKeyword => "BreakKeyword",
+            SyntaxKind::ContinueKeyword => "ContinueKeyword",
+            SyntaxKind::TryKeyword => "TryKeyword",
+            SyntaxKind::CatchKeyword => "CatchKeyword",
+            SyntaxKind::MatchKeyword => "MatchKeyword",
+            SyntaxKind::CaseKeyword => "CaseKeyword",
             SyntaxKind::GenericType => "GenericType",
             SyntaxKind::ArrayType => "ArrayType",
             SyntaxKind::FnType => "FnType",
+            SyntaxKind::MapType => "MapType",
             SyntaxKind::OptModifier => "OptModifier",
             SyntaxKind::LeftParenthesis => "LeftParenthesis",
             SyntaxKind::RightParenthesis => "RightParenthesis",
@@ -265,9 +341,12 @@ impl SyntaxKind{
             SyntaxKind::RightRectangularBrackets => "RightRectangularBrackets",
             SyntaxKind::PlusSign => "PlusSign",
             SyntaxKind::MinusSign => "MinusSign",
+            SyntaxKind::DoubleMinus => "DoubleMinus",
             SyntaxKind::MultSign => "MultSign",
             SyntaxKind::FilePathSep => "FilePathSep",
             SyntaxKind::DivSign => "DivSign",
+            SyntaxKind::ModSign => "ModSign",
+            SyntaxKind::PowSign => "PowSign",
             SyntaxKind::LessThanSign => "LessThanSign",
             SyntaxKind::LessOrEqualSign => "LessOrEqualSign",
             SyntaxKind::EqualitySign => "EqualitySign",
@@ -275,18 +354,21 @@ impl SyntaxKind{
             SyntaxKind::BiggerThanSign => "BiggerThanSign",
             SyntaxKind::BiggerOrEqualSign => "BiggerOrEqualSign",
             SyntaxKind::RightStream => "RightStream",
+            SyntaxKind::TruncRightStream => "TruncRightStream",
             SyntaxKind::DivAssignSign => "DivAssignSign",
             SyntaxKind::MulAssignSign => "MulAssignSign",
             SyntaxKind::AddAssignSign => "AddAssignSign",
             SyntaxKind::MinAssignSign => "MinAssignSign",
             SyntaxKind::AssignSign => "AssignSign",
             SyntaxKind::Pipe => "Pipe",
+            SyntaxKind::Ampersand => "Ampersand",
             SyntaxKind::Dollar => "Dollar",
             SyntaxKind::QuestionMark => "QuestionMark",
             SyntaxKind::Point => "Point",
             SyntaxKind::DoublePoint => "DoublePoint",
             SyntaxKind::DoubleQuote => "DoubleQuote",
             SyntaxKind::SingleQuote => "SingleQuote",
+            SyntaxKind::BackSlash => "BackSlash",
             SyntaxKind::Error => "Error",
             SyntaxKind::ParserInternal => "ParserInternal",
             SyntaxKind::Eof => "Eof",
@@ -298,6 +380,7 @@ impl SyntaxKind{
             SyntaxKind::StringContent => "StringContent",
             SyntaxKind::VarDeclName => "VarDeclName",
             SyntaxKind::FnDeclName => "FnDeclName",
+            SyntaxKind::GenericParamName => "GenericParamName",
             SyntaxKind::ArgName => "ArgName",
             SyntaxKind::VarArgName => "VarArgName",
             SyntaxKind::LongFlag => "LongFlag",
@@ -305,6 +388,8 @@ impl SyntaxKind{
             SyntaxKind::Number => "Number",
             SyntaxKind::StrctName => "StrctName",
             SyntaxKind::StrctFieldName => "StrctFieldName",
+            SyntaxKind::EnumVariantName => "EnumVariantName",
+            SyntaxKind::MapKey => "MapKey",
             SyntaxKind::AbsFileName => "AbsFileName",
             SyntaxKind::RelFileName => "RelFileName",
             SyntaxKind::SourceFile => "SourceFile",
@@ -317,8 +402,13 @@ impl SyntaxKind{
             SyntaxKind::StrctField => "StrctField",
             SyntaxKind::StrctCtorExpr => "StrctCtorExpr",
             SyntaxKind::StrctFieldCtorStmt => "StrctFieldCtorStmt",
+            SyntaxKind::EnumStmt => "EnumStmt",
+            SyntaxKind::EnumVariant => "EnumVariant",
+            SyntaxKind::EnumCtorExpr => "EnumCtorExpr",
+            SyntaxKind::ImplStmt => "ImplStmt",
             SyntaxKind::LetStmt => "LetStmt",
             SyntaxKind::FnStmt => "FnStmt",
+            SyntaxKind::GenericParams => "GenericParams",
             SyntaxKind::RetStmt => "RetStmt",
             SyntaxKind::IfElifElseStmt => "IfElifElseStmt",
             SyntaxKind::IfOptElifOptStmt => "IfOptElifOptStmt",
@@ -328,8 +418,25 @@ impl SyntaxKind{
             SyntaxKind::ElifBlock => "ElifBlock",
             SyntaxKind::ElseBlock => "ElseBlock",
             SyntaxKind::ForStmt => "ForStmt",
+            SyntaxKind::WhileStmt => "WhileStmt",
+            SyntaxKind::BreakStmt => "BreakStmt",
+            SyntaxKind::ContinueStmt => "ContinueStmt",
+            SyntaxKind::TryStmt => "TryStmt",
+            SyntaxKind::CatchStmt => "CatchStmt",
+            SyntaxKind::MatchStmt => "MatchStmt",
+            SyntaxKind::MatchArm => "MatchArm",
+            SyntaxKind::LiteralPattern => "LiteralPattern",
+            SyntaxKind::BindingPattern => "BindingPattern",
+            SyntaxKind::NonePattern => "NonePattern",
+            SyntaxKind::SomePattern => "SomePattern",
+            SyntaxKind::StrctPattern => "StrctPattern",
+            SyntaxKind::StrctFieldPattern => "StrctFieldPattern",
+            SyntaxKind::EnumPattern => "EnumPattern",
+            SyntaxKind::ArrayPattern => "ArrayPattern",
+            SyntaxKind::ArrayRestPattern => "ArrayRestPattern",
             SyntaxKind::CmdStmt => "CmdStmt",
             SyntaxKind::PipedCmdsStmt => "PipedCmdsStmt",
+            SyntaxKind::BackgroundStmt => "BackgroundStmt",
             SyntaxKind::BlockStmt => "BlockStmt",
             SyntaxKind::Signature => "Signature",
             SyntaxKind::FlagSignature => "FlagSignature",
@@ -341,7 +448,11 @@ impl SyntaxKind{
             SyntaxKind::StringExpr => "StringExpr",
             SyntaxKind::NumberExpr => "NumberExpr",
             SyntaxKind::ValuePathExpr => "ValuePathExpr",
+            SyntaxKind::UnaryExpr => "UnaryExpr",
+            SyntaxKind::RangeExpr => "RangeExpr",
             SyntaxKind::ArrayExpr => "ArrayExpr",
+            SyntaxKind::MapExpr => "MapExpr",
+            SyntaxKind::MapEntry => "MapEntry",
             SyntaxKind::OptionalExpr => "OptionalExpr",
             SyntaxKind::TableExpr => "TableExpr",
             SyntaxKind::BooleanExpr => "BooleanExpr",
@@ -349,6 +460,7 @@ impl SyntaxKind{
             SyntaxKind::Statement => "Statement",
             SyntaxKind::Condition => "Condition",
             SyntaxKind::OperatorExpr => "OperatorExpr",
+            SyntaxKind::UnaryOperator => "UnaryOperator",
             SyntaxKind::LuTypeSpecifier => "LuTypeSpecifier",
             SyntaxKind::CmdArg => "CmdArg",
             SyntaxKind::Flag => "Flag",
@@ -356,6 +468,8 @@ impl SyntaxKind{
             SyntaxKind::FileName => "FileName",
             SyntaxKind::FileNamePart => "FileNamePart",
             SyntaxKind::IfElifElseStmtPart => "IfElifElseStmtPart",
+            SyntaxKind::MatchPattern => "MatchPattern",
+            SyntaxKind::StringExprPart => "StringExprPart",
             SyntaxKind::PipeOrValueExpr => "PipeOrValueExpr",
             #[allow(unreachable_patterns)]
             _ => "", // For the future
@@ -367,6 +481,8 @@ impl SyntaxKind{
 macro_rules! T {
     [impure] => {$crate::SyntaxKind::ImpureKeyword };
     [struct] => {$crate::SyntaxKind::StrctKeyword };
+    [enum] => {$crate::SyntaxKind::EnumKeyword };
+    [impl] => {$crate::SyntaxKind::ImplKeyword };
     [opt] => {$crate::SyntaxKind::OptKeyword };
     [Some] => {$crate::SyntaxKind::SomeKeyword };
     [None] => {$crate::SyntaxKind::NoneKeyword };
@@ -374,6 +490,7 @@ macro_rules! T {
     [req] => {$crate::SyntaxKind::ReqKeyword };
     [or] => {$crate::SyntaxKind::OrKeyword };
     [and] => {$crate::SyntaxKind::AndKeyword };
+    [not] => {$crate::SyntaxKind::NotKeyword };
     [use] => {$crate::SyntaxKind::UseKeyword };
     [let] => {$crate::SyntaxKind::LetKeyword };
     [fn] => {$crate::SyntaxKind::FnKeyword };
@@ -395,8 +512,15 @@ macro_rules! T {
     [false] => {$crate::SyntaxKind::FalseKeyword };
     [num] => {$crate::SyntaxKind::NumberKeyword };
     [str] => {$crate::SyntaxKind::StringKeyword };
+    [range] => {$crate::SyntaxKind::RangeKeyword };
     [cls] => {$crate::SyntaxKind::ClsKeyword };
     [ret] => {$crate::SyntaxKind::RetKeyword };
+    [break] => {$crate::SyntaxKind::BreakKeyword };
+    [continue] => {$crate::SyntaxKind::ContinueKeyword };
+    [try] => {$crate::SyntaxKind::TryKeyword };
+    [catch] => {$crate::SyntaxKind::CatchKeyword };
+    [match] => {$crate::SyntaxKind::MatchKeyword };
+    [case] => {$crate::SyntaxKind::CaseKeyword };
     ["("] => {$crate::SyntaxKind::LeftParenthesis };
     [")"] => {$crate::SyntaxKind::RightParenthesis };
     ["{"] => {$crate::SyntaxKind::LeftCurlyBrackets };
@@ -405,9 +529,12 @@ macro_rules! T {
     ["]"] => {$crate::SyntaxKind::RightRectangularBrackets };
     [+] => {$crate::SyntaxKind::PlusSign };
     [-] => {$crate::SyntaxKind::MinusSign };
+    [--] => {$crate::SyntaxKind::DoubleMinus };
     [*] => {$crate::SyntaxKind::MultSign };
     [/] => {$crate::SyntaxKind::FilePathSep };
     ["//"] => {$crate::SyntaxKind::DivSign };
+    [%] => {$crate::SyntaxKind::ModSign };
+    [**] => {$crate::SyntaxKind::PowSign };
     [<] => {$crate::SyntaxKind::LessThanSign };
     [<=] => {$crate::SyntaxKind::LessOrEqualSign };
     [==] => {$crate::SyntaxKind::EqualitySign };
@@ -415,16 +542,19 @@ macro_rules! T {
     [>] => {$crate::SyntaxKind::BiggerThanSign };
     [>=] => {$crate::SyntaxKind::BiggerOrEqualSign };
     [>>] => {$crate::SyntaxKind::RightStream };
+    [>|] => {$crate::SyntaxKind::TruncRightStream };
     [/=] => {$crate::SyntaxKind::DivAssignSign };
     [*=] => {$crate::SyntaxKind::MulAssignSign };
     [+=] => {$crate::SyntaxKind::AddAssignSign };
     [-=] => {$crate::SyntaxKind::MinAssignSign };
     [=] => {$crate::SyntaxKind::AssignSign };
     [|] => {$crate::SyntaxKind::Pipe };
+    [&] => {$crate::SyntaxKind::Ampersand };
     [$] => {$crate::SyntaxKind::Dollar };
     [?] => {$crate::SyntaxKind::QuestionMark };
     [.] => {$crate::SyntaxKind::Point };
     [:] => {$crate::SyntaxKind::DoublePoint };
     [DoubleQuote] => {$crate::SyntaxKind::DoubleQuote };
     [SingleQuote] => {$crate::SyntaxKind::SingleQuote };
+    [BackSlash] => {$crate::SyntaxKind::BackSlash };
     }
@@ -28,24 +28,30 @@
 //! Non-opt rules typically start with `assert!(p.at(FIRST_TOKEN))`, the
 //! caller is responsible for branching on the first token.
 
+mod background_stmt;
 mod block_stmt;
 mod cls_expr;
 mod cmd_stmt;
 mod comments;
+mod enum_stmt;
 mod expr;
 mod file_name_expr;
 mod fn_stmt;
 mod for_stmt;
 mod if_stmt;
+mod impl_stmt;
 mod incomplete_input;
 mod let_stmt;
+mod match_stmt;
 mod piped_cmds_stmt;
 mod ret_stmt;
 mod signature;
 mod struct_stmt;
+mod try_stmt;
 mod type_;
 mod use_stmt;
 mod value_path_expr;
+mod while_stmt;
 
 use itertools::Itertools;
 use log::debug;
@@ -60,21 +66,29 @@ use crate::{
     Token,
 };
 
+pub use background_stmt::BackgroundStmtRule;
 pub use block_stmt::BlockStmtRule;
 pub use cls_expr::ClsExprRule;
 pub use cmd_stmt::CmdStmtRule;
-pub use expr::{ArrayExprRule, NumberExprRule, StringExprRule, TableExprRule, ValueExprRule};
+pub use enum_stmt::{EnumCtorExprRule, EnumStmtRule};
+pub use expr::{
+    ArrayExprRule, MapExprRule, NumberExprRule, StringExprRule, TableExprRule, ValueExprRule,
+};
 pub use fn_stmt::FnStmtRule;
 pub use for_stmt::ForStmtRule;
 pub use if_stmt::IfElifElseRule;
+pub use impl_stmt::ImplStmtRule;
 pub use let_stmt::LetStmtRule;
+pub use match_stmt::MatchStmtRule;
 pub use piped_cmds_stmt::PipedCmdsStmtRule;
 pub use ret_stmt::RetStmtRule;
 pub use signature::SignatureRule;
 pub use struct_stmt::{StrctCtorExprRule, StrctStmtRule};
-pub use type_::{ArrayTypeRule, FnTypeRule, LuTypeRule};
+pub use try_stmt::TryStmtRule;
+pub use type_::{ArrayTypeRule, FnTypeRule, LuTypeRule, MapTypeRule};
 pub use use_stmt::UseStmtRule;
 pub use value_path_expr::ValuePathExprRule;
+pub use while_stmt::{LoopCtrlStmtRule, WhileStmtRule};
 
 pub trait Rule {
     /// Returns the name of the rule
@@ -233,9 +247,13 @@ fn second_level_stmt() -> OrRule {
         rules: vec_box![
             LetStmtRule {},
             BlockStmtRule::new_lazy_rule(),
-            PipedCmdsStmtRule {},
+            BackgroundStmtRule {},
             RetStmtRule {},
             ForStmtRule {},
+            WhileStmtRule {},
+            LoopCtrlStmtRule {},
+            TryStmtRule {},
+            MatchStmtRule {},
             IfElifElseRule {},
         ],
     }
@@ -246,6 +264,8 @@ fn top_level_stmt() -> OrRule {
     second_level_stmt.rules.push(Box::new(UseStmtRule {}));
     second_level_stmt.rules.push(Box::new(FnStmtRule {}));
     second_level_stmt.rules.push(Box::new(StrctStmtRule {}));
+    second_level_stmt.rules.push(Box::new(EnumStmtRule {}));
+    second_level_stmt.rules.push(Box::new(ImplStmtRule {}));
     second_level_stmt.kind = None;
 
     second_level_stmt
@@ -0,0 +1,48 @@
+use super::*;
+
+use crate::{
+    parser::{CompletedMarker, Parser, CMT_WS},
+    T,
+};
+
+/// Rule for stmts, which may be run in the background. Example: `make | tee build.log &`
+pub struct BackgroundStmtRule;
+impl Rule for BackgroundStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        PipedCmdsStmtRule {}.matches(p)
+    }
+
+    fn name(&self) -> String {
+        // Without a trailing & this is just a piped cmds stmt
+        PipedCmdsStmtRule {}.name()
+    }
+
+    /// If no trailing & is detected, this returns the marker of the PipedCmdsStmtRule
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let stmt = PipedCmdsStmtRule {}.parse(p)?;
+        if p.next_non(CMT_WS) != T![&] {
+            return Some(stmt);
+        }
+        let m = stmt.precede(p);
+        p.eat_while(CMT_WS);
+        p.expect(T![&]);
+        Some(m.complete(p, BackgroundStmt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_env_logger::env_logger;
+
+    use crate::{parse_as, Event};
+
+    use super::BackgroundStmtRule;
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/background_stmt/background_simple.yaml_test")]
+    fn parse_cmds(s: &str) -> Vec<Event> {
+        let _ = env_logger::builder().is_test(true).try_init();
+        parse_as(s, &BackgroundStmtRule {})
+    }
+}
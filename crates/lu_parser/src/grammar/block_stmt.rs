@@ -62,6 +62,28 @@ impl BlockStmtRule {
         }
     }
 
+    /// BlockRule for the try block of a try stmt
+    pub fn try_block() -> Self {
+        Self {
+            parse_begin: false,
+            eat_end: false,
+            end_kinds: [CatchKeyword].into(),
+            statement_rule: Box::new(second_level_stmt()),
+            try_recover: false,
+        }
+    }
+
+    /// BlockRule for the arm of a match stmt
+    pub fn match_arm_block() -> Self {
+        Self {
+            parse_begin: false,
+            eat_end: false,
+            end_kinds: [CaseKeyword, EndKeyword].into(),
+            statement_rule: Box::new(second_level_stmt()),
+            try_recover: false,
+        }
+    }
+
     pub fn else_block() -> Self {
         Self {
             parse_begin: false,
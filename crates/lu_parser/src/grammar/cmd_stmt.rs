@@ -1,10 +1,14 @@
 use log::debug;
 
-use super::{expr::ValueExprRule, file_name_expr::file_name_rule, Rule};
+use super::{
+    expr::ValueExprRule,
+    file_name_expr::{file_name_rule, redir_file_name_rule},
+    Rule,
+};
 use crate::{
     grammar::{OrRule, ValuePathExprRule},
     parser::{CompletedMarker, Parser, CMT_NL_WS},
-    SyntaxKind::*,
+    SyntaxKind::{self, *},
     T,
 };
 use vec_box::vec_box;
@@ -16,24 +20,29 @@ impl Rule for RedirStmt {
     }
 
     fn matches(&self, p: &mut Parser) -> bool {
-        p.next_non(CMT_NL_WS) == T![>>]
+        let next = p.next_non(CMT_NL_WS);
+        next == T![>>] || next == T![>|]
     }
 
     fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
         let m = p.start();
-        if !p.expect_after(T![>>], CMT_NL_WS) {
+        if !p.expect_after([T![>>], T![>|]], CMT_NL_WS) {
             m.abandon(p);
             return None;
         }
         let redir_to = OrRule {
             kind: Some("RedirToValue".to_string()),
-            rules: vec_box![ValuePathExprRule {}, BareWord],
+            rules: vec_box![ValuePathExprRule {}, redir_file_name_rule()],
         };
         redir_to.parse(p);
         Some(m.complete(p, RedirStmt))
     }
 }
 
+/// Keywords, which are only keywords at the start of a statement, in operator position or in a
+/// type. As arguments of a cmd they are bare words otherwise
+const CONTEXTUAL_KEYWORDS: [SyntaxKind; 5] = [T![impl], T![not], T![match], T![case], T![range]];
+
 pub struct CmdStmtRule;
 impl Rule for CmdStmtRule {
     fn name(&self) -> String {
@@ -41,13 +50,17 @@ impl Rule for CmdStmtRule {
     }
 
     fn matches(&self, p: &mut Parser) -> bool {
-        p.next_non(CMT_NL_WS) == BareWord
+        p.next_non(CMT_NL_WS) == BareWord || at_method_path(p)
     }
 
     fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
         p.eat_while(CMT_NL_WS);
         let m = p.start();
         // Eat commands name
+        if at_method_path(p) {
+            p.bump(StrctName);
+            p.bump(T![.]);
+        }
         if !p.expect_after(BareWord, CMT_NL_WS) {
             m.abandon(p);
             return None;
@@ -56,6 +69,18 @@ impl Rule for CmdStmtRule {
         //consume all ws delimited cmd's and arguments
         let arg_rule = ValueExprRule {};
         let file_name_rule = file_name_rule(true);
+        // Give BareWord precedence over ValueExprRule CmdStmt
+        // We allow simple barewords as cmdarg
+        // But filenames still require precedence over barewords...
+        // TODO integrate bareword optionally into ValueExpr
+        let eat_value_arg = |p: &mut Parser| {
+            file_name_rule.opt(p).is_some()
+                || p.eat_after(BareWord, Whitespace)
+                || arg_rule.opt(p).is_some()
+                || p.eat_after_as(CONTEXTUAL_KEYWORDS, BareWord, Whitespace)
+        };
+        // After `--` all arguments are values, even if they look like flags
+        let mut flags_ended = false;
         loop {
             debug!("CmdStmtRule checking for arg or cmd_name");
             let next_token = p.next_non(Whitespace);
@@ -63,18 +88,28 @@ impl Rule for CmdStmtRule {
                 p.eat_until(&[Eof, Newline].into());
                 break;
             }
-            if
-            // Give BareWord precedence over ValueExprRule CmdStmt
-            // We allow simple barewords as cmdarg
-            // But filenames still require precedence over barewords...
-            // TODO integrate bareword optionally into ValueExpr
-            file_name_rule.opt(p).is_some()
-                || p.eat_after(BareWord, Whitespace)
-                || arg_rule.opt(p).is_some()
-                || p.eat_after(ShortFlag, Whitespace)
-                || p.eat_after(LongFlag, Whitespace)
-                || p.eat_after_as(StrctName, BareWord, Whitespace)
-            {
+            if flags_ended {
+                if eat_value_arg(p)
+                    || p.eat_after_as([ShortFlag, LongFlag, T![--]], BareWord, Whitespace)
+                    || p.eat_after_as(StrctName, BareWord, Whitespace)
+                {
+                    continue;
+                } else {
+                    break;
+                }
+            }
+            if eat_value_arg(p) || p.eat_after(ShortFlag, Whitespace) {
+                continue;
+            } else if p.eat_after(LongFlag, Whitespace) {
+                // --flag=value
+                if p.eat(T![=]) && !eat_value_arg(p) {
+                    p.error("Expected a value after the =".to_string());
+                }
+                continue;
+            } else if p.eat_after(T![--], Whitespace) {
+                flags_ended = true;
+                continue;
+            } else if p.eat_after_as(StrctName, BareWord, Whitespace) {
                 continue;
             } else {
                 break;
@@ -87,6 +122,13 @@ impl Rule for CmdStmtRule {
     }
 }
 
+/// Whether the name of a method `<StrctName>.<method>` is next
+fn at_method_path(p: &mut Parser) -> bool {
+    p.next_non(CMT_NL_WS) == StrctName
+        && p.next_token_after(StrctName) == T![.]
+        && p.next_token_after(T![.]) == BareWord
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_env_logger::env_logger;
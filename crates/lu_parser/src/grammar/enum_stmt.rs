@@ -0,0 +1,134 @@
+use super::*;
+
+use crate::{
+    grammar::struct_stmt::{StrctFieldCtorStmtRule, StrctFieldRule},
+    parser::{CompletedMarker, Parser, CMT_NL_WS},
+    SyntaxKind::*,
+    T,
+};
+
+/// Rule for `<VariantName>` or `<VariantName>{ <field>: <type> ... }`
+struct EnumVariantRule;
+impl Rule for EnumVariantRule {
+    fn name(&self) -> String {
+        "EnumVariantRule".to_string()
+    }
+
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == StrctName
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        if !p.expect_after_as(StrctName, EnumVariantName, CMT_NL_WS) {
+            m.abandon(p);
+            return None;
+        }
+
+        if p.next_non(CMT_NL_WS) == T!["{"] {
+            p.eat_after(T!["{"], CMT_NL_WS);
+            let field_rule = StrctFieldRule {};
+            while field_rule.matches(p) {
+                field_rule.parse(p);
+            }
+            p.expect_after(T!["}"], CMT_NL_WS);
+        }
+
+        Some(m.complete(p, EnumVariant))
+    }
+}
+
+/// Rule for
+/// enum <Name> {
+///     <Variant>
+///     <Variant>{ <field>: <type> }
+/// }
+pub struct EnumStmtRule;
+impl Rule for EnumStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == EnumKeyword
+    }
+
+    fn name(&self) -> String {
+        "EnumStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        if !p.expect_after(EnumKeyword, CMT_NL_WS) {
+            m.abandon(p);
+            return None;
+        }
+        p.expect_after(StrctName, CMT_NL_WS);
+        p.expect_after(T!["{"], CMT_NL_WS);
+
+        let variant_rule = EnumVariantRule {};
+        while variant_rule.matches(p) {
+            variant_rule.parse(p);
+        }
+
+        p.expect_after(T!["}"], CMT_NL_WS);
+        Some(m.complete(p, EnumStmt))
+    }
+}
+
+/// Whether `<EnumName>.<VariantName>` is next
+pub(super) fn at_enum_variant_path(p: &mut Parser) -> bool {
+    p.next_non(CMT_NL_WS) == StrctName
+        && p.next_token_after(StrctName) == T![.]
+        && p.next_token_after(T![.]) == StrctName
+}
+
+/// Rule for `<EnumName>.<VariantName>` or `<EnumName>.<VariantName>{ <field>: <value> ... }`
+pub struct EnumCtorExprRule;
+impl Rule for EnumCtorExprRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        at_enum_variant_path(p)
+    }
+
+    fn name(&self) -> String {
+        "EnumCtorExprRule".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        if !p.expect_after(StrctName, CMT_NL_WS) {
+            m.abandon(p);
+            return None;
+        }
+        p.expect(T![.]);
+        p.expect_as(StrctName, EnumVariantName);
+
+        if p.next_non(CMT_NL_WS) == T!["{"] {
+            p.eat_after(T!["{"], CMT_NL_WS);
+            let field_rule = StrctFieldCtorStmtRule {};
+            while field_rule.matches(p) {
+                field_rule.parse(p);
+            }
+            p.expect_after(T!["}"], CMT_NL_WS);
+        }
+
+        Some(m.complete(p, EnumCtorExpr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_as, Event};
+
+    use super::{EnumCtorExprRule, EnumStmtRule};
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/enum_stmt/general.yaml_test")]
+    fn parse(s: &str) -> Vec<Event> {
+        lu_test_support::init_logger();
+        parse_as(s, &EnumStmtRule {})
+    }
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/enum_stmt/ctor.yaml_test")]
+    fn parse_ctor(s: &str) -> Vec<Event> {
+        lu_test_support::init_logger();
+        parse_as(s, &EnumCtorExprRule {})
+    }
+}
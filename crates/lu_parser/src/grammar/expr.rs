@@ -2,10 +2,11 @@ use super::*;
 
 use crate::{
     grammar::file_name_expr::file_name_rule,
-    parser::{CompletedMarker, Parser, CMT_NL_WS},
+    parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
     SyntaxKind::{self, *},
 };
 use crate::{Token, T};
+use text_size::TextSize;
 
 /// An expression is a source code element resembling a lu-Value
 pub struct ValueExprRule;
@@ -15,7 +16,7 @@ impl Rule for ValueExprRule {
     }
 
     fn matches(&self, p: &mut Parser) -> bool {
-        value_expr_rule().matches(p) || p.next_non(CMT_NL_WS) == T!["("]
+        value_expr_rule().matches(p) || p.next_non(CMT_NL_WS) == T!["("] || at_unary_op(p)
     }
 
     fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
@@ -48,7 +49,12 @@ fn next_op(p: &mut Parser) -> (u8, SyntaxKind) {
         T![+]                         => (10, T![+]),
         T!["//"]                      => (11, T!["//"]),
         T![*]                         => (11, T![*]),
-        T![-]                         => (10, T![-]),
+        T![%]                         => (11, T![%]),
+        // A - at the start of a line is an unary minus, not the continuation of the prev line
+        T![-] if p.next_non(CMT_WS) == T![-]
+                                      => (10, T![-]),
+        // Right associative
+        T![**]                        => (13, T![**]),
         // Right associative ops
         T![=]                         => (1, T![=]),
         T![+=]                        => (1, T![+=]),
@@ -77,6 +83,13 @@ fn expr_bp(p: &mut Parser, bp: u8) -> Option<CompletedMarker> {
     let mut expr_m = lhs(p)?;
 
     loop {
+        if at_range_op(p) {
+            if RANGE_BP < bp {
+                break;
+            }
+            expr_m = range_expr(expr_m, p);
+            continue;
+        }
         let (op_bp, op) = next_op(p);
         debug!(
             "Found op ({:?}) with precedence: {} (curent_precedence: {})",
@@ -98,6 +111,9 @@ fn expr_bp(p: &mut Parser, bp: u8) -> Option<CompletedMarker> {
         // It should rather pass: allow_type_specifiers=true to expr_bp and continue as normal
         if op == SyntaxKind::AsKeyword {
             LuTypeRule {}.parse(p);
+        } else if op == T![**] {
+            // Right associative: 2 ** 3 ** 2 == 2 ** (3 ** 2)
+            expr_bp(p, op_bp);
         } else {
             expr_bp(p, op_bp + 1); // This will complete the rhs of the expr
         }
@@ -108,12 +124,85 @@ fn expr_bp(p: &mut Parser, bp: u8) -> Option<CompletedMarker> {
     Some(expr_m)
 }
 
+/// Binding power of `..`. Binds weaker than arithmetic (`0..$n - 1` is `0..($n - 1)`), but
+/// stronger than comparisons
+const RANGE_BP: u8 = 8;
+
+/// Whether a `..` follows directly (without whitespace)
+fn at_range_op(p: &mut Parser) -> bool {
+    p.at(T![.]) && p.nth_at(1, T![.])
+}
+
+/// Parses the remainder of a range `start..end` or `start..end..step`, with start being lhs
+fn range_expr(lhs: CompletedMarker, p: &mut Parser) -> CompletedMarker {
+    let m = lhs.precede(p);
+    p.bump(T![.]);
+    p.bump(T![.]);
+    if expr_bp(p, RANGE_BP + 1).is_none() {
+        p.error("Expected the end of the range".to_string());
+    }
+    if at_range_op(p) {
+        p.bump(T![.]);
+        p.bump(T![.]);
+        if expr_bp(p, RANGE_BP + 1).is_none() {
+            p.error("Expected the step of the range".to_string());
+        }
+    }
+    m.complete(p, RangeExpr)
+}
+
+/// Binding power of the operand of an unary operator
+#[rustfmt::skip]
+fn unary_op_bp(op: SyntaxKind) -> u8 {
+    match op {
+        // not a == b is not (a == b)
+        T![not] => 5,
+        // -$x ** 2 is -($x ** 2), but -$x * 2 is (-$x) * 2. A number literal includes its sign:
+        // -2 ** 2 is (-2) ** 2
+        T![-]   => 12,
+        _       => unreachable!(),
+    }
+}
+
+/// Whether an unary operator is next. A `-` is only an unary operator if directly followed by
+/// a var or a parenthesis (`-$x`, `-(1 + 2)`), as otherwise it may be part of a file name or
+/// a number. A `not` without operand is a bare word (`echo not`)
+fn at_unary_op(p: &mut Parser) -> bool {
+    match p.next_non(CMT_NL_WS) {
+        T![not] => !matches!(
+            p.next_non_after(T![not], Whitespace),
+            Eof | Newline | Comment | T![")"] | T!["]"] | T!["}"] | T![|]
+        ),
+        T![-] => matches!(p.next_token_after(T![-]), T![$] | T!["("]),
+        _ => false,
+    }
+}
+
 fn lhs(p: &mut Parser) -> Option<CompletedMarker> {
-    if p.next_non(CMT_NL_WS) == T!["("] {
-        p.eat_after(T!["("], CMT_NL_WS);
-        let parsed_val = expr(p);
+    if at_unary_op(p) {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        let op = p.current();
+        p.bump(op);
+        if expr_bp(p, unary_op_bp(op)).is_none() {
+            p.error("Expected an expression".to_string());
+        }
+        Some(m.complete(p, UnaryExpr))
+    } else if p.next_non(CMT_NL_WS) == T!["("] {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.bump(T!["("]);
+        p.eat_while(CMT_NL_WS);
+        let parsed_val = PipedCmdsStmtRule {}.parse(p);
         p.expect_after(T![")"], CMT_NL_WS);
-        parsed_val
+        match parsed_val {
+            // Only pipes need a node. (1 + 2) is represented by the MathExpr itself
+            Some(piped) if piped.kind() == PipedCmdsStmt => Some(m.complete(p, ParentherizedExpr)),
+            _ => {
+                m.abandon(p);
+                parsed_val
+            }
+        }
     } else {
         value_expr_rule().parse(p)
     }
@@ -128,9 +217,11 @@ pub(crate) fn value_expr_rule() -> OrRule {
             Box::new(ValuePathExprRule {}),
             Box::new(StringExprRule {}),
             Box::new(BooleanExprRule {}),
+            Box::new(EnumCtorExprRule {}),
             Box::new(StrctCtorExprRule {}),
             Box::new(TableExprRule {}),
             Box::new(ArrayExprRule {}),
+            Box::new(MapExprRule {}),
             Box::new(OptionalExprRule {}),
             Box::new(file_name_rule(true)),
             Box::new(CmdStmtRule {}),
@@ -226,6 +317,66 @@ impl Rule for TableExprRule {
     }
 }
 
+/// Rule for a single `<key>: <value>` of a map. Keys are bare words or strings
+struct MapEntryRule;
+impl Rule for MapEntryRule {
+    fn name(&self) -> String {
+        "MapEntry".into()
+    }
+
+    fn matches(&self, p: &mut Parser) -> bool {
+        matches!(
+            p.next_non(CMT_NL_WS),
+            BareWord | StrctName | SingleQuote | DoubleQuote
+        )
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        let string_key_rule = StringExprRule {};
+        let key_parsed = if string_key_rule.matches(p) {
+            string_key_rule.parse(p).is_some()
+        } else {
+            p.expect_after_as([BareWord, StrctName], MapKey, CMT_NL_WS)
+        };
+        if !key_parsed {
+            m.abandon(p);
+            return None;
+        }
+
+        if p.expect_after(T![:], CMT_NL_WS) {
+            ValueExprRule {}.parse(p);
+        }
+
+        Some(m.complete(p, MapEntry))
+    }
+}
+
+/// Rule for `{<key>: <value> ...}`
+pub struct MapExprRule;
+impl Rule for MapExprRule {
+    fn name(&self) -> String {
+        "Map".into()
+    }
+
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == T!["{"]
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(T!["{"]);
+        // maps are allowed to span multiple lines
+        let entry_rule = MapEntryRule {};
+        while entry_rule.matches(p) {
+            entry_rule.parse(p);
+        }
+        p.expect_after(T!["}"], CMT_NL_WS);
+        Some(m.complete(p, MapExpr))
+    }
+}
+
 pub struct NumberExprRule;
 impl Rule for NumberExprRule {
     fn name(&self) -> String {
@@ -259,22 +410,38 @@ impl Rule for StringExprRule {
         p.eat_while(CMT_NL_WS);
 
         let m = p.start();
-        // TODO assert is quote type
         let quote_type = p.current();
         if !p.expect(&[DoubleQuote, SingleQuote]) {
             // If quote_type is not " or ' we better don't eat any more tokens
             m.abandon(p);
             return None;
         }
-        // We don't eat the content, as that would produce the string content as many
-        // multiple tokens. We want the content as one token
-        let str_content = p.discard_until(&[quote_type, Newline]);
-        p.do_bump(Token::new(
-            SyntaxKind::StringContent,
-            str_content.iter().map(|t| t.len).sum(),
-        ));
-
-        if p.current() == Newline {
+        if p.at(quote_type) && p.nth_at(1, quote_type) {
+            // """ or ''' start a multi-line string
+            p.bump(quote_type);
+            p.bump(quote_type);
+            multi_line_string_content(p, quote_type);
+            if p.current() == Eof {
+                p.error("Unterminated string literal".to_string());
+            }
+            for _ in 0..3 {
+                p.eat(quote_type);
+            }
+            return Some(m.complete(p, StringExpr));
+        }
+        if quote_type == SingleQuote {
+            // Single quoted strings are raw. We don't eat the content, as that would produce
+            // the string content as many multiple tokens. We want the content as one token
+            let str_content = p.discard_until([SingleQuote, Newline]);
+            p.do_bump(Token::new(
+                SyntaxKind::StringContent,
+                str_content.iter().map(|t| t.len).sum(),
+            ));
+        } else {
+            string_parts(p);
+        }
+
+        if p.current() == Newline || p.current() == Eof {
             p.error("Unterminated string literal".to_string());
         }
 
@@ -283,6 +450,80 @@ impl Rule for StringExprRule {
     }
 }
 
+/// Eats everything (including newlines) up to the closing triple quote as one StringContent
+/// token. Multi-line strings are raw
+fn multi_line_string_content(p: &mut Parser, quote_type: SyntaxKind) {
+    let mut len = TextSize::from(0);
+    while !(p.at(quote_type) && p.nth_at(1, quote_type) && p.nth_at(2, quote_type)) && !p.at(Eof) {
+        len += p.discard_cur().len;
+    }
+    p.do_bump(Token::new(SyntaxKind::StringContent, len));
+}
+
+/// Parses the content of a double quoted string. The content is split into StringContent
+/// tokens, interpolated ValuePathExpr's (`$var.field`) and ParentherizedExpr's (`(cmd ...)`)
+fn string_parts(p: &mut Parser) {
+    let mut parsed_part = false;
+    loop {
+        match p.current() {
+            DoubleQuote | Newline | Eof => break,
+            T![$] if is_var_name(p.nth(1)) => string_value_path(p),
+            T!["("] => string_parentherized_expr(p),
+            _ => string_content(p),
+        }
+        parsed_part = true;
+    }
+    if !parsed_part {
+        // Empty strings still have a (empty) StringContent
+        p.do_bump(Token::new(SyntaxKind::StringContent, TextSize::from(0)));
+    }
+}
+
+fn is_var_name(kind: SyntaxKind) -> bool {
+    kind == BareWord || kind == StrctName || kind == InKeyword
+}
+
+/// Eats all tokens up to the next interpolated part as one StringContent token.
+/// A backslash escapes the following token
+fn string_content(p: &mut Parser) {
+    let mut len = TextSize::from(0);
+    loop {
+        match p.current() {
+            DoubleQuote | Newline | Eof | T!["("] => break,
+            T![$] if is_var_name(p.nth(1)) => break,
+            BackSlash => {
+                len += p.discard_cur().len;
+                if !p.at([Newline, Eof]) {
+                    len += p.discard_cur().len;
+                }
+            }
+            _ => len += p.discard_cur().len,
+        }
+    }
+    p.do_bump(Token::new(SyntaxKind::StringContent, len));
+}
+
+/// Like ValuePathExprRule, but a `.` only continues the path if followed by a name
+/// ("Hello $name." should not be an error)
+fn string_value_path(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![$]);
+    p.expect_as([BareWord, StrctName, InKeyword], BareWord);
+    while p.current() == Point && is_var_name(p.nth(1)) {
+        p.bump(Point);
+        p.expect_as([BareWord, StrctName, InKeyword], BareWord);
+    }
+    m.complete(p, ValuePathExpr);
+}
+
+fn string_parentherized_expr(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!["("]);
+    PipedCmdsStmtRule {}.parse(p);
+    p.expect_after(T![")"], CMT_NL_WS);
+    m.complete(p, ParentherizedExpr);
+}
+
 pub struct BooleanExprRule;
 impl Rule for BooleanExprRule {
     fn name(&self) -> String {
@@ -308,7 +549,7 @@ impl Rule for BooleanExprRule {
 mod tests {
     use crate::{parse_as, Event};
 
-    use super::TableExprRule;
+    use super::{MapExprRule, StringExprRule, TableExprRule};
 
     use {conformance, serde_yaml};
 
@@ -317,4 +558,16 @@ mod tests {
         lu_test_support::init_logger();
         parse_as(s, &TableExprRule {})
     }
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/map_expr/map_simple.yaml_test")]
+    fn parse_maps(s: &str) -> Vec<Event> {
+        lu_test_support::init_logger();
+        parse_as(s, &MapExprRule {})
+    }
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/string_expr/string_simple.yaml_test")]
+    fn parse_strings(s: &str) -> Vec<Event> {
+        lu_test_support::init_logger();
+        parse_as(s, &StringExprRule {})
+    }
 }
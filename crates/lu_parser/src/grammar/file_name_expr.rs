@@ -12,13 +12,34 @@ pub(crate) fn file_name_rule(allow_wildcards: bool) -> OrRule {
         kind: Some("FileName".into()),
         rules: vec_box![
             AbsFileNameRule { allow_wildcards },
-            RelFileNameRule { allow_wildcards }
+            RelFileNameRule {
+                allow_wildcards,
+                allow_plain_bare_word: false
+            }
+        ],
+    }
+}
+
+/// File names as the target of a redirection. In contrast to `file_name_rule`, a bareword
+/// without a `/` (e.g. `out.txt`) is parsed as a relative file name
+pub(crate) fn redir_file_name_rule() -> OrRule {
+    OrRule {
+        kind: Some("FileName".into()),
+        rules: vec_box![
+            AbsFileNameRule {
+                allow_wildcards: false
+            },
+            RelFileNameRule {
+                allow_wildcards: false,
+                allow_plain_bare_word: true
+            }
         ],
     }
 }
 
 pub struct RelFileNameRule {
     allow_wildcards: bool,
+    allow_plain_bare_word: bool,
 }
 
 impl Rule for RelFileNameRule {
@@ -31,8 +52,8 @@ impl Rule for RelFileNameRule {
         if next_token == BareWord {
             // FileSep has to come right after
             let token_after_bw = p.next_token_after(BareWord);
-            token_after_bw == T![/]
-        } else if next_token == T![*] && self.allow_wildcards {
+            token_after_bw == T![/] || self.allow_plain_bare_word
+        } else if (next_token == T![*] || next_token == T![**]) && self.allow_wildcards {
             true
         } else {
             // ./<bw> rule
@@ -3,6 +3,7 @@ use super::*;
 use crate::{
     parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
     SyntaxKind::*,
+    T,
 };
 
 pub struct FnStmtRule {}
@@ -25,6 +26,7 @@ impl Rule for FnStmtRule {
 
         // eat the name
         p.eat_delimited_as(BareWord, FnDeclName, Whitespace, true);
+        generic_params(p);
         debug!("Testing for optional signature");
         SignatureRule {}.opt(p);
         p.expect_after(Newline, CMT_WS);
@@ -34,6 +36,21 @@ impl Rule for FnStmtRule {
     }
 }
 
+/// Parses the optional generic parameter list of a fn: `<Elem Key>`
+fn generic_params(p: &mut Parser) {
+    if !p.at(T![<]) {
+        return;
+    }
+    let m = p.start();
+    p.expect(T![<]);
+    p.eat_while(CMT_NL_WS);
+    while p.eat_as([StrctName, GenericType], GenericParamName) {
+        p.eat_while(CMT_NL_WS);
+    }
+    p.expect_after(T![>], CMT_NL_WS);
+    m.complete(p, GenericParams);
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_env_logger::env_logger;
@@ -0,0 +1,57 @@
+use super::*;
+
+use crate::{
+    grammar::FnStmtRule,
+    parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
+    SyntaxKind::*,
+};
+
+/// Rule for
+/// impl <StrctName>
+///     fn <name> (self: <StrctName>)
+///         <stmts>
+///     end
+/// end
+pub struct ImplStmtRule;
+impl Rule for ImplStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == ImplKeyword
+    }
+
+    fn name(&self) -> String {
+        "ImplStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        if !p.expect_after(ImplKeyword, CMT_NL_WS) {
+            m.abandon(p);
+            return None;
+        }
+        p.expect_after(StrctName, CMT_WS);
+        p.expect_after(Newline, CMT_WS);
+
+        let fn_rule = FnStmtRule {};
+        while fn_rule.matches(p) {
+            fn_rule.parse(p);
+        }
+
+        p.expect_after(EndKeyword, CMT_NL_WS);
+        Some(m.complete(p, ImplStmt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_as, Event};
+
+    use super::ImplStmtRule;
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/impl_stmt/general.yaml_test")]
+    fn parse_impl_stmt(s: &str) -> Vec<Event> {
+        lu_test_support::init_logger();
+        parse_as(s, &ImplStmtRule {})
+    }
+}
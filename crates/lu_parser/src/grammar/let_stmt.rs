@@ -1,4 +1,4 @@
-use crate::{SyntaxKind::*, T, grammar::{LuTypeRule, PipedCmdsStmtRule}, parser::{CompletedMarker, Parser, CMT_NL_WS}};
+use crate::{SyntaxKind::*, T, grammar::{LuTypeRule, PipedCmdsStmtRule, match_stmt::{ArrayPatternRule, MatchPatternRule}}, parser::{CompletedMarker, Parser, CMT_NL_WS}};
 
 use super::Rule;
 
@@ -21,7 +21,18 @@ impl Rule for LetStmtRule {
             return None;
         }
         p.eat_while(CMT_NL_WS);
-        p.expect_as(BareWord, VarDeclName);
+        match p.current() {
+            // Destructuring: let Ip{a b} = ... / let [first ...rest] = ...
+            StrctName => {
+                MatchPatternRule {}.parse(p);
+            }
+            T!["["] => {
+                ArrayPatternRule {}.parse(p);
+            }
+            _ => {
+                p.expect_as(BareWord, VarDeclName);
+            }
+        }
         if p.eat_after(T![:], CMT_NL_WS) {
             LuTypeRule {}.parse(p);
         }
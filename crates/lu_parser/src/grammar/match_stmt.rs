@@ -0,0 +1,237 @@
+use super::*;
+
+use crate::{
+    grammar::{enum_stmt::at_enum_variant_path, expr::BooleanExprRule},
+    parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
+    SyntaxKind::*,
+    T,
+};
+
+/// Rule for
+/// match <value_expr>
+///     case <pattern>
+///         <block>
+///     ...
+/// end
+pub struct MatchStmtRule;
+impl Rule for MatchStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == MatchKeyword
+    }
+
+    fn name(&self) -> String {
+        "MatchStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(MatchKeyword);
+        ValueExprRule {}.parse(p);
+        p.expect_after(Newline, CMT_WS);
+
+        let arm_rule = MatchArmRule {};
+        while arm_rule.matches(p) {
+            arm_rule.parse(p);
+        }
+
+        p.expect_after(EndKeyword, CMT_NL_WS);
+        Some(m.complete(p, MatchStmt))
+    }
+}
+
+/// Rule for `case <pattern> <block>`
+struct MatchArmRule;
+impl Rule for MatchArmRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == CaseKeyword
+    }
+
+    fn name(&self) -> String {
+        "MatchArm".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(CaseKeyword);
+        MatchPatternRule {}.parse(p);
+        p.expect_after(Newline, CMT_WS);
+        BlockStmtRule::match_arm_block().parse(p);
+        Some(m.complete(p, MatchArm))
+    }
+}
+
+/// Rule for all patterns of a match arm:
+/// - Literals: `1`, `"str"`, `true`
+/// - Bindings: `x` (`_` matches everything without binding)
+/// - Optionals: `None`, `Some{<pattern>}`
+/// - Structs: `Ip{a: <pattern> b}`
+/// - Enums: `EntryKind.File` or `EntryKind.Symlink{target: <pattern>}`
+pub(crate) struct MatchPatternRule;
+impl Rule for MatchPatternRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        matches!(
+            p.next_non(CMT_NL_WS),
+            Number
+                | SingleQuote
+                | DoubleQuote
+                | TrueKeyword
+                | FalseKeyword
+                | BareWord
+                | NoneKeyword
+                | SomeKeyword
+                | StrctName
+        )
+    }
+
+    fn name(&self) -> String {
+        "MatchPattern".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        match p.current() {
+            Number => {
+                NumberExprRule {}.parse(p);
+                Some(m.complete(p, LiteralPattern))
+            }
+            SingleQuote | DoubleQuote => {
+                StringExprRule {}.parse(p);
+                Some(m.complete(p, LiteralPattern))
+            }
+            TrueKeyword | FalseKeyword => {
+                BooleanExprRule {}.parse(p);
+                Some(m.complete(p, LiteralPattern))
+            }
+            BareWord => {
+                p.expect_as(BareWord, VarDeclName);
+                Some(m.complete(p, BindingPattern))
+            }
+            NoneKeyword => {
+                p.bump(NoneKeyword);
+                Some(m.complete(p, NonePattern))
+            }
+            SomeKeyword => {
+                p.bump(SomeKeyword);
+                p.expect_after(T!["{"], CMT_NL_WS);
+                self.parse(p);
+                p.expect_after(T!["}"], CMT_NL_WS);
+                Some(m.complete(p, SomePattern))
+            }
+            StrctName if at_enum_variant_path(p) => {
+                p.bump(StrctName);
+                p.bump(T![.]);
+                p.expect_as(StrctName, EnumVariantName);
+                if p.next_non(CMT_NL_WS) == T!["{"] {
+                    p.eat_after(T!["{"], CMT_NL_WS);
+                    parse_field_patterns(p);
+                    p.expect_after(T!["}"], CMT_NL_WS);
+                }
+                Some(m.complete(p, EnumPattern))
+            }
+            StrctName => {
+                p.bump(StrctName);
+                p.expect_after(T!["{"], CMT_NL_WS);
+                parse_field_patterns(p);
+                p.expect_after(T!["}"], CMT_NL_WS);
+                Some(m.complete(p, StrctPattern))
+            }
+            _ => {
+                p.error(format!("Expected a pattern, but found {}", p.current()));
+                m.abandon(p);
+                None
+            }
+        }
+    }
+}
+
+/// Rule for `[<pattern>... ...<rest>]`. Used to destructure arrays in let statements
+pub(crate) struct ArrayPatternRule;
+impl Rule for ArrayPatternRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == T!["["]
+    }
+
+    fn name(&self) -> String {
+        "ArrayPattern".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(T!["["]);
+        let elem_rule = MatchPatternRule {};
+        loop {
+            if at_rest_pattern(p) {
+                let rest_m = p.start();
+                p.bump(T![.]);
+                p.bump(T![.]);
+                p.bump(T![.]);
+                p.expect_as(BareWord, VarDeclName);
+                rest_m.complete(p, ArrayRestPattern);
+                // The rest has to be the last element
+                break;
+            } else if elem_rule.matches(p) {
+                elem_rule.parse(p);
+            } else {
+                break;
+            }
+        }
+        p.expect_after(T!["]"], CMT_NL_WS);
+        Some(m.complete(p, ArrayPattern))
+    }
+}
+
+/// Whether `...<name>` follows. Eats leading whitespace
+fn at_rest_pattern(p: &mut Parser) -> bool {
+    p.eat_while(CMT_NL_WS);
+    p.at(T![.]) && p.nth_at(1, T![.]) && p.nth_at(2, T![.])
+}
+
+fn parse_field_patterns(p: &mut Parser) {
+    let field_rule = StrctFieldPatternRule {};
+    while field_rule.matches(p) {
+        field_rule.parse(p);
+    }
+}
+
+/// Rule for `<field_name>` or `<field_name>: <pattern>` within a struct pattern
+struct StrctFieldPatternRule;
+impl Rule for StrctFieldPatternRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == BareWord
+    }
+
+    fn name(&self) -> String {
+        "StrctFieldPattern".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect_as(BareWord, StrctFieldName);
+        if p.eat_after(T![:], CMT_NL_WS) {
+            MatchPatternRule {}.parse(p);
+        }
+        Some(m.complete(p, StrctFieldPattern))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_env_logger::env_logger;
+
+    use crate::{parse_as, Event};
+
+    use super::MatchStmtRule;
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/match_stmt/match_simple.yaml_test")]
+    fn parse_cmds(s: &str) -> Vec<Event> {
+        let _ = env_logger::builder().is_test(true).try_init();
+        parse_as(s, &MatchStmtRule {})
+    }
+}
@@ -1,4 +1,4 @@
-use crate::grammar::LuTypeRule;
+use crate::grammar::{LuTypeRule, ValueExprRule};
 use crate::parser::CMT_WS;
 use crate::token_set::TokenSet;
 use crate::T;
@@ -6,6 +6,7 @@ use crate::{
     parser::{CompletedMarker, Parser, CMT_NL_WS},
     SyntaxKind::*,
 };
+use text_size::TextSize;
 
 use super::Rule;
 
@@ -20,17 +21,18 @@ impl Rule for FlagSignatureRule {
         flag_kinds.contains(p.next_non(CMT_NL_WS))
     }
 
-    ///    req? --long_flag (-short_flag)? (<:> type)?
+    ///    req? --long_flag (-short_flag)? (<:> type)? (<=> default)?
     ///    or
-    ///    req? -short_flag (<:> type)?
+    ///    req? -short_flag (<:> type)? (<=> default)?
     fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
         let m = p.start();
         p.eat_after(ReqKeyword, CMT_NL_WS);
         if p.eat_after(LongFlag, CMT_NL_WS) {
-            p.eat_after(ShortFlag, CMT_WS); // shortflag belongs to longflag, must be on same line
+            eat_short_flag_sign(p, CMT_WS); // shortflag belongs to longflag, must be on same line
         } else {
             // no long_flag, expect shortflag then (otherwise FlagSignatureRule wouldn't match)
-            if !p.expect_after(ShortFlag, CMT_NL_WS) {
+            if !eat_short_flag_sign(p, CMT_NL_WS) {
+                p.expect_after(ShortFlag, CMT_NL_WS);
                 m.abandon(p);
                 return None;
             }
@@ -39,11 +41,25 @@ impl Rule for FlagSignatureRule {
         if p.eat_after(T![:], CMT_NL_WS) {
             LuTypeRule {}.parse(p);
         }
+        default_value(p);
 
         Some(m.complete(p, FlagSignature))
     }
 }
 
+/// Short flags of a signature consist of a single char. Only passed short flags may be
+/// combined (`-la`)
+fn eat_short_flag_sign<TS: Into<TokenSet> + Copy>(p: &mut Parser, after: TS) -> bool {
+    if p.next_non(after) != ShortFlag {
+        return false;
+    }
+    p.eat_while(after);
+    if p.current_token().len != TextSize::from(2) {
+        p.error("Short flags in a signature consist of a single character".to_string());
+    }
+    p.eat(ShortFlag)
+}
+
 /// Ret In VarArg NormalArg Rule
 struct ArgSignatureRule {}
 
@@ -58,7 +74,7 @@ impl Rule for ArgSignatureRule {
         ts.contains(p.next_non(CMT_NL_WS))
     }
 
-    /// name (<:> type)?
+    /// name (<:> type)? (<=> default)?
     fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
         let m = p.start();
         if p.eat_after_as([InKeyword, RetKeyword, BareWord], ArgName, CMT_NL_WS) {
@@ -77,10 +93,18 @@ impl Rule for ArgSignatureRule {
         if p.eat_after(T![:], CMT_NL_WS) {
             LuTypeRule {}.parse(p);
         }
+        default_value(p);
         Some(m.complete(p, ArgSignature))
     }
 }
 
+/// Parses `= <value>`, the value of an arg or flag if not passed
+fn default_value(p: &mut Parser) {
+    if p.eat_after(T![=], CMT_NL_WS) {
+        ValueExprRule {}.parse(p);
+    }
+}
+
 pub struct SignatureRule;
 impl Rule for SignatureRule {
     fn name(&self) -> String {
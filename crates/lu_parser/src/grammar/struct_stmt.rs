@@ -6,7 +6,7 @@ use crate::{
     T,
 };
 
-struct StrctFieldRule;
+pub(super) struct StrctFieldRule;
 impl Rule for StrctFieldRule {
     fn name(&self) -> String {
         "StructFieldRule".to_string()
@@ -61,7 +61,7 @@ impl Rule for StrctStmtRule {
     }
 }
 
-struct StrctFieldCtorStmtRule;
+pub(super) struct StrctFieldCtorStmtRule;
 impl Rule for StrctFieldCtorStmtRule {
     fn name(&self) -> String {
         "StructFieldCtorRule".to_string()
@@ -0,0 +1,66 @@
+use super::*;
+
+use crate::{
+    parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
+    SyntaxKind::*,
+};
+
+pub struct TryStmtRule;
+impl Rule for TryStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == TryKeyword
+    }
+
+    fn name(&self) -> String {
+        "TryStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(TryKeyword);
+        p.expect_after(Newline, CMT_WS);
+        BlockStmtRule::try_block().parse(p);
+        CatchStmtRule {}.parse(p);
+        Some(m.complete(p, TryStmt))
+    }
+}
+
+/// Rule for `catch <var_name> <block> end`
+struct CatchStmtRule;
+impl Rule for CatchStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == CatchKeyword
+    }
+
+    fn name(&self) -> String {
+        "CatchStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        p.expect_after(CatchKeyword, CMT_NL_WS);
+        // The var holding the caught error
+        p.expect_after_as(BareWord, VarDeclName, CMT_WS);
+        p.expect_after(Newline, CMT_WS);
+        BlockStmtRule::fn_for_block().parse(p);
+        Some(m.complete(p, CatchStmt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_env_logger::env_logger;
+
+    use crate::{parse_as, Event};
+
+    use super::TryStmtRule;
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/try_stmt/try_simple.yaml_test")]
+    fn parse_cmds(s: &str) -> Vec<Event> {
+        let _ = env_logger::builder().is_test(true).try_init();
+        parse_as(s, &TryStmtRule {})
+    }
+}
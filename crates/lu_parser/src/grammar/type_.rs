@@ -29,6 +29,29 @@ impl Rule for ArrayTypeRule {
     }
 }
 
+/// Rule for `{str: <type>}`. Keys of maps are always strings
+pub struct MapTypeRule;
+impl Rule for MapTypeRule {
+    fn name(&self) -> String {
+        "MapType".into()
+    }
+
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == T!["{"]
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        let m = p.start();
+        p.eat_while(CMT_NL_WS);
+        p.expect(T!["{"]);
+        p.expect_after(StringKeyword, CMT_NL_WS);
+        p.expect_after(T![:], CMT_NL_WS);
+        LuTypeRule {}.parse(p);
+        p.expect_after(T!["}"], CMT_NL_WS);
+        Some(m.complete(p, MapType))
+    }
+}
+
 pub struct FnTypeRule;
 impl Rule for FnTypeRule {
     fn name(&self) -> String {
@@ -59,8 +82,10 @@ fn lu_type_specifier() -> OrRule {
             PathKeyword,
             BoolKeyword,
             StringKeyword,
+            RangeKeyword,
             FnTypeRule {},
             ArrayTypeRule {},
+            MapTypeRule {},
             StrctName,
         ],
     }
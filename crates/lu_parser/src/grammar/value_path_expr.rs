@@ -20,13 +20,21 @@ impl Rule for ValuePathExprRule {
         p.eat_while(CMT_NL_WS);
         let m = p.start();
         p.expect(T![$]);
-        loop {
-            if !p.expect_as([BareWord, StrctName, InKeyword], BareWord) {
-                // in`$in` in is represented as in keyword
-                break;
-            }
-            if !p.eat(Point) {
-                break;
+        // `$in` is lexed as in keyword
+        if p.expect_as([BareWord, StrctName, InKeyword], BareWord) {
+            // A `..` after the path starts a range ($n..10)
+            while p.at(Point) && !p.nth_at(1, Point) {
+                p.bump(Point);
+                if p.eat(Number) {
+                    // Indexing into an array ($arr.0) or slicing it ($arr.1..3 / $arr.1..)
+                    if p.at(Point) && p.nth_at(1, Point) {
+                        p.bump(Point);
+                        p.bump(Point);
+                        p.eat(Number);
+                    }
+                } else if !p.expect_as([BareWord, StrctName, InKeyword], BareWord) {
+                    break;
+                }
             }
         }
         Some(m.complete(p, ValuePathExpr))
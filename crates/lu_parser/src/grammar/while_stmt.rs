@@ -0,0 +1,69 @@
+use super::*;
+
+use crate::{
+    parser::{CompletedMarker, Parser, CMT_NL_WS, CMT_WS},
+    SyntaxKind::*,
+};
+
+pub struct WhileStmtRule;
+impl Rule for WhileStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        p.next_non(CMT_NL_WS) == WhileKeyword
+    }
+
+    fn name(&self) -> String {
+        "WhileStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        p.expect(WhileKeyword);
+        // The condition
+        PipedCmdsStmtRule {}.parse(p);
+        p.expect_after(Newline, CMT_WS);
+        BlockStmtRule::fn_for_block().parse(p);
+        Some(m.complete(p, WhileStmt))
+    }
+}
+
+/// Rule for `break` and `continue`
+pub struct LoopCtrlStmtRule;
+impl Rule for LoopCtrlStmtRule {
+    fn matches(&self, p: &mut Parser) -> bool {
+        let next = p.next_non(CMT_NL_WS);
+        next == BreakKeyword || next == ContinueKeyword
+    }
+
+    fn name(&self) -> String {
+        "BreakStmt | ContinueStmt".into()
+    }
+
+    fn parse_rule(&self, p: &mut Parser) -> Option<CompletedMarker> {
+        p.eat_while(CMT_NL_WS);
+        let m = p.start();
+        if p.eat(BreakKeyword) {
+            Some(m.complete(p, BreakStmt))
+        } else {
+            p.expect(ContinueKeyword);
+            Some(m.complete(p, ContinueStmt))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_env_logger::env_logger;
+
+    use crate::{parse_as, Event};
+
+    use super::WhileStmtRule;
+
+    use {conformance, serde_yaml};
+
+    #[conformance::tests(exact, serde=serde_yaml, file="test_data/grammar/while_stmt/while_simple.yaml_test")]
+    fn parse_cmds(s: &str) -> Vec<Event> {
+        let _ = env_logger::builder().is_test(true).try_init();
+        parse_as(s, &WhileStmtRule {})
+    }
+}
@@ -88,9 +88,41 @@ impl TokenVec {
 }
 
 pub fn lex_tokens(input: &str) -> Vec<Token> {
-    let lex = SyntaxKind::lexer(input).spanned();
-    lex.map(|(kind, span)| Token::new(kind, span.len().try_into().unwrap()))
-        .collect()
+    let mut tokens = Vec::new();
+    let mut in_value_path = false;
+    for (kind, span) in SyntaxKind::lexer(input).spanned() {
+        in_value_path = match kind {
+            Dollar => true,
+            BareWord | StrctName | InKeyword | Point | Number => in_value_path,
+            _ => false,
+        };
+        let text = &input[span.clone()];
+        // `0..3` is lexed as `0.` and `.3`
+        let is_range_bound = (text.ends_with('.') && input[span.end..].starts_with('.'))
+            || (text.starts_with('.') && input[..span.start].ends_with('.'));
+        if kind == Number && text.contains('.') && (in_value_path || is_range_bound) {
+            // The points are separators ($arr.0.1 / $arr.1..3 / 0..3)
+            tokens.extend(split_number_at_points(text));
+        } else {
+            tokens.push(Token::new(kind, span.len().try_into().unwrap()));
+        }
+    }
+    tokens
+}
+
+/// Splits the text of a number into integers and points
+fn split_number_at_points(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut parts = text.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if !part.is_empty() {
+            tokens.push(Token::new(Number, part.len().try_into().unwrap()));
+        }
+        if parts.peek().is_some() {
+            tokens.push(Token::new(Point, 1.into()));
+        }
+    }
+    tokens
 }
 
 pub fn lex(input: &str) -> TokenVec {
@@ -95,6 +95,22 @@ impl Parser {
             .unwrap_or(Eof)
     }
 
+    /// The first token after the next `kind`, which is not in `ts`
+    pub(crate) fn next_non_after<TS: Into<TokenSet>>(
+        &self,
+        kind: SyntaxKind,
+        ts: TS,
+    ) -> SyntaxKind {
+        let ts: TokenSet = ts.into();
+        self.token_source
+            .iter()
+            .map(|t| t.kind)
+            .skip_while(|t| *t != kind)
+            .skip(1)
+            .find(|t| !ts.contains(*t))
+            .unwrap_or(Eof)
+    }
+
     /// Consume the next token if `kind` matches.
     pub(crate) fn eat<TS: Into<TokenSet>>(&mut self, kinds: TS) -> bool {
         let kinds: TokenSet = kinds.into();
@@ -262,6 +278,11 @@ impl Parser {
         discarded
     }
 
+    /// Discards the current token and returns it
+    pub(crate) fn discard_cur(&mut self) -> Token {
+        self.token_source.take_and_advance()
+    }
+
     pub(crate) fn eat_empty_or_cmt_line(&mut self) -> bool {
         if self
             .token_source
@@ -340,7 +361,7 @@ impl Parser {
             || *token == T![-]
             || *token == Number
             || if allow_wildcards {
-                *token == T![*]
+                *token == T![*] || *token == T![**]
             } else {
                 false
             }
@@ -357,7 +378,7 @@ impl Parser {
         let mut eaten_once = false;
         loop {
             let wildcard_eaten = if allow_wildcards {
-                self.eat(T![*])
+                self.eat([T![*], T![**]])
             } else {
                 false
             };
@@ -5,82 +5,142 @@ use lu_error::{util::Outcome, AstErr, LuErr, SourceCodeItem};
 use lu_interpreter_structs::*;
 use parking_lot::RwLock;
 
+/// All user defined types, which may be referred to by a ValueType::StrctName
+struct UserDefTys {
+    strcts: HashMap<String, Arc<RwLock<Strct>>>,
+    enums: HashMap<String, Arc<RwLock<Enum>>>,
+}
+
+impl UserDefTys {
+    fn of_modules<'a>(modules: impl Iterator<Item = &'a ScopeFrame<Variable>>) -> Self {
+        let mut strcts = HashMap::new();
+        let mut enums = HashMap::new();
+        for (_, var) in modules.flat_map(|module| module.elems.iter()) {
+            if let Some(strct) = var.val.as_strct_decl() {
+                let name = strct.read().name.clone();
+                strcts.insert(name, strct.clone());
+            } else if let Some(enm) = var.val.as_enum_decl() {
+                let name = enm.read().name.clone();
+                enums.insert(name, enm.clone());
+            }
+        }
+        UserDefTys { strcts, enums }
+    }
+
+    /// Adds all tys of other, which are not yet part of self
+    fn extended_by(mut self, other: &UserDefTys) -> Self {
+        for (name, strct) in &other.strcts {
+            self.strcts
+                .entry(name.clone())
+                .or_insert_with(|| strct.clone());
+        }
+        for (name, enm) in &other.enums {
+            self.enums
+                .entry(name.clone())
+                .or_insert_with(|| enm.clone());
+        }
+        self
+    }
+}
+
 pub(crate) fn resolve_strct_types(
     mut modules: Vec<ScopeFrame<Variable>>,
 ) -> Outcome<Vec<ScopeFrame<Variable>>> {
     debug!("Resolving ValueType::StrctName");
     let mut errs = vec![];
 
-    let all_struct_decls: HashMap<_, _> = modules
+    let all_user_def_tys = UserDefTys::of_modules(modules.iter());
+    // A StrctName refers to the ty declared in the module itself, otherwise to the one of a used
+    // module. Two modules may declare tys with the same name.
+    let user_def_tys_per_module: Vec<_> = modules
         .iter()
-        .map(|module| module.elems.iter())
-        .flatten()
-        .filter_map(|(_, var)| var.val.as_strct_decl().cloned())
-        .map(|strct| {
-            let name = strct.read().name.clone();
-            (name, strct)
+        .map(|module| {
+            let mod_info = module.get_mod_tag();
+            let used_modules = modules.iter().filter(|other| {
+                mod_info
+                    .use_paths
+                    .iter()
+                    .any(|use_path| use_path.mod_path == other.get_mod_tag().id)
+            });
+            UserDefTys::of_modules(std::iter::once(module))
+                .extended_by(&UserDefTys::of_modules(used_modules))
+                .extended_by(&all_user_def_tys)
         })
         .collect();
 
-    for frame in modules.iter_mut() {
+    for (frame, user_def_tys) in modules.iter_mut().zip(user_def_tys_per_module) {
         for (_, var) in frame.elems.iter_mut() {
-            subst_value_tys(&mut var.val, &all_struct_decls, &mut errs);
+            subst_value_tys(&mut var.val, &user_def_tys, &mut errs);
         }
     }
     Outcome::new(modules, errs)
 }
 
-fn subst_value_tys(
-    value: &mut Value,
-    all_struct_decls: &HashMap<String, Arc<RwLock<Strct>>>,
-    errs: &mut Vec<LuErr>,
-) {
+fn subst_value_tys(value: &mut Value, user_def_tys: &UserDefTys, errs: &mut Vec<LuErr>) {
     if let Value::StrctDecl(strct) = value {
         let mut w_strct = strct.write();
         for field in &mut w_strct.fields {
-            subst_strct_name(&mut field.ty, &all_struct_decls, &field.decl)
-                .map(|err| errs.push(err));
+            subst_strct_name(&mut field.ty, user_def_tys, &field.decl).map(|err| errs.push(err));
+        }
+    } else if let Value::EnumDecl(enm) = value {
+        let mut w_enm = enm.write();
+        for field in w_enm
+            .variants
+            .iter_mut()
+            .flat_map(|variant| variant.fields.iter_mut())
+        {
+            subst_strct_name(&mut field.ty, user_def_tys, &field.decl).map(|err| errs.push(err));
         }
     } else if let Value::Command(func) = value {
-        let l_func = Rc::get_mut(func).expect("No references yet");
-        if let Some(func) = l_func.as_function_mut() {
-            let sign = &mut func.signature;
-            sign.args.iter_mut().for_each(|arg| {
-                subst_strct_name(&mut arg.ty, &all_struct_decls, &arg.decl)
-                    .map(|err| errs.push(err));
-            });
-            sign.flags.iter_mut().for_each(|flag| {
-                subst_strct_name(&mut flag.ty, &all_struct_decls, &flag.decl)
-                    .map(|err| errs.push(err));
-            });
-            subst_strct_name(&mut sign.in_arg.ty, &all_struct_decls, &sign.in_arg.decl)
-                .map(|err| errs.push(err));
-            subst_strct_name(&mut sign.ret_arg.ty, &all_struct_decls, &sign.ret_arg.decl)
-                .map(|err| errs.push(err));
-            if let Some(var_arg) = &mut sign.var_arg {
-                subst_strct_name(&mut var_arg.ty, &all_struct_decls, &var_arg.decl)
-                    .map(|err| errs.push(err));
-            }
+        subst_cmd_tys(func, user_def_tys, errs);
+    } else if let Value::CommandCollection(cmd_collect) = value {
+        for func in cmd_collect.cmds.iter_mut() {
+            subst_cmd_tys(func, user_def_tys, errs);
         }
     } else {
-        unreachable!("Only commands and strcts are yet sourced")
+        unreachable!("Only commands, strcts and enums are yet sourced")
     }
 }
+
+fn subst_cmd_tys(func: &mut Rc<dyn Command>, user_def_tys: &UserDefTys, errs: &mut Vec<LuErr>) {
+    let l_func = Rc::get_mut(func).expect("No references yet");
+    if let Some(func) = l_func.as_function_mut() {
+        let sign = &mut func.signature;
+        sign.args.iter_mut().for_each(|arg| {
+            subst_strct_name(&mut arg.ty, user_def_tys, &arg.decl).map(|err| errs.push(err));
+        });
+        sign.flags.iter_mut().for_each(|flag| {
+            subst_strct_name(&mut flag.ty, user_def_tys, &flag.decl).map(|err| errs.push(err));
+        });
+        subst_strct_name(&mut sign.in_arg.ty, user_def_tys, &sign.in_arg.decl)
+            .map(|err| errs.push(err));
+        subst_strct_name(&mut sign.ret_arg.ty, user_def_tys, &sign.ret_arg.decl)
+            .map(|err| errs.push(err));
+        if let Some(var_arg) = &mut sign.var_arg {
+            subst_strct_name(&mut var_arg.ty, user_def_tys, &var_arg.decl)
+                .map(|err| errs.push(err));
+        }
+    }
+}
+
 fn subst_strct_name(
     ty: &mut ValueType,
-    all_struct_decls: &HashMap<String, Arc<RwLock<Strct>>>,
+    user_def_tys: &UserDefTys,
     decl: &SourceCodeItem,
 ) -> Option<LuErr> {
     if let ValueType::StrctName(strct_name) = ty {
         debug!("Found ValueType::StrctName({}) to substitute", strct_name);
-        if let Some(strct_decl) = all_struct_decls.get(strct_name) {
+        if let Some(strct_decl) = user_def_tys.strcts.get(strct_name) {
             *ty = ValueType::Strct(Arc::downgrade(strct_decl));
             None
+        } else if let Some(enum_decl) = user_def_tys.enums.get(strct_name) {
+            *ty = ValueType::Enum(Arc::downgrade(enum_decl));
+            None
         } else {
             Some(AstErr::StrctNotInScope(decl.clone()).into())
         }
     } else if let ValueType::Array { inner_ty, .. } = ty {
-        subst_strct_name(inner_ty, all_struct_decls, decl)
+        subst_strct_name(inner_ty, user_def_tys, decl)
     } else {
         warn!("Not substituting ValueType::StrctName in inner fn tys");
         None
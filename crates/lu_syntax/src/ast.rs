@@ -1,14 +1,18 @@
 //! Abstract Syntax Tree, layered on top of untyped `SyntaxNode`s
+mod background_stmt;
 mod block_stmt;
 mod cmd_stmt;
+mod enum_stmt;
 mod expr;
 mod file_name_expr;
 mod fn_stmt;
 mod for_stmt;
 mod generated;
 mod if_stmt;
+mod impl_stmt;
 mod cls_expr;
 mod let_stmt;
+mod match_stmt;
 mod math_expr;
 mod optional_expr;
 mod piped_cmds_stmt;
@@ -16,9 +20,11 @@ mod ret_stmt;
 mod signature;
 mod strct_stmt;
 mod table_expr;
+mod try_stmt;
 mod type_;
 mod use_stmt;
 mod value_path_expr;
+mod while_stmt;
 use std::marker::PhantomData;
 
 use lu_error::SourceCodeItem;
@@ -31,6 +37,9 @@ use crate::{
 };
 
 pub use self::generated::nodes::*;
+pub use impl_stmt::method_cmd_name;
+pub use match_stmt::WILDCARD_PATTERN;
+pub use value_path_expr::ValuePathPart;
 
 pub trait HasRule {
     fn get_belonging_rule() -> Box<dyn Rule>;
@@ -0,0 +1,10 @@
+use crate::AstNode;
+
+use super::{support, BackgroundStmtNode, PipeOrValueExprElement};
+
+impl BackgroundStmtNode {
+    /// The stmt being run in the background
+    pub fn stmt(&self) -> Option<PipeOrValueExprElement> {
+        support::element_child(self.syntax())
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{AstElementChildren, AstNode, AstNodeChildren};
 
-use super::{BlockStmtNode, FnStmtNode, StatementElement, StrctStmtNode, UseStmtNode, support};
+use super::{
+    support, BlockStmtNode, EnumStmtNode, FnStmtNode, ImplStmtNode, StatementElement,
+    StrctStmtNode, UseStmtNode,
+};
 
 impl BlockStmtNode {
     pub fn statements(&self) -> AstElementChildren<StatementElement> {
@@ -17,6 +20,12 @@ impl BlockStmtNode {
     pub fn struct_stmts(&self) -> AstNodeChildren<StrctStmtNode> {
         support::node_children(self.syntax())
     }
+    pub fn enum_stmts(&self) -> AstNodeChildren<EnumStmtNode> {
+        support::node_children(self.syntax())
+    }
+    pub fn impl_stmts(&self) -> AstNodeChildren<ImplStmtNode> {
+        support::node_children(self.syntax())
+    }
     pub fn use_stmts(&self) -> AstNodeChildren<UseStmtNode> {
         support::node_children(self.syntax())
     }
@@ -4,8 +4,8 @@ use rowan::TextRange;
 use crate::{AstNode, AstToken};
 
 use super::{
-    addr_of_mod_node_contained_in, support, BlockStmtNode, ClosureExprNode, ClsKeywordToken,
-    ImpureKeywordToken, SignatureNode,
+    addr_of_mod_node_contained_in, support, BareWordToken, BlockStmtNode, ClosureExprNode,
+    ClsKeywordToken, CmdStmtNode, ImpureKeywordToken, SignatureNode, ValuePathExprNode,
 };
 
 impl ClosureExprNode {
@@ -62,4 +62,27 @@ impl ClosureExprNode {
     pub fn block_stmt(&self) -> Option<BlockStmtNode> {
         support::node_child(self.syntax())
     }
+
+    /// Returns the names of all variables referenced within the closure (including nested
+    /// closures). Each name is returned once. As a variable may hold a closure, the names of
+    /// called cmds are returned as well.
+    pub fn referenced_var_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for node in self.syntax().descendants() {
+            let name = if let Some(path) = ValuePathExprNode::cast(node.clone()) {
+                path.var_name_with_item().0
+            } else if let Some(cmd) = CmdStmtNode::cast(node) {
+                match support::token_child::<BareWordToken>(cmd.syntax()) {
+                    Some(name) => name.text().to_string(),
+                    None => continue,
+                }
+            } else {
+                continue;
+            };
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
 }
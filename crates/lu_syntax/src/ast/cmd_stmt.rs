@@ -3,7 +3,10 @@ use crate::{
     AstElementChildren, AstNode, AstToken,
 };
 
-use super::{support, BareWordToken, CmdStmtNode, FlagElement};
+use super::{
+    method_cmd_name, support, BareWordToken, CmdStmtNode, FlagElement, RightStreamToken,
+    StrctNameToken,
+};
 
 impl CmdStmtNode {
     /// Returns the longest possible name of the called command
@@ -28,10 +31,15 @@ impl CmdStmtNode {
     }
 
     pub fn get_cmd_name(&self) -> String {
-        support::token_child::<BareWordToken>(self.syntax())
+        let name = support::token_child::<BareWordToken>(self.syntax())
             .unwrap()
             .text()
-            .to_string()
+            .to_string();
+        // Methods are called by `<StrctName>.<method>`
+        match support::token_child::<StrctNameToken>(self.syntax()) {
+            Some(strct_name) => method_cmd_name(strct_name.text(), &name),
+            None => name,
+        }
     }
     pub fn redir_stmt(&self) -> Option<RedirStmtNode> {
         support::node_child(self.syntax())
@@ -42,4 +50,9 @@ impl RedirStmtNode {
     pub fn get_redir_to(&self) -> Option<RedirToValueElement> {
         support::element_child(self.syntax())
     }
+
+    /// `>>` appends to the target, `>|` truncates it
+    pub fn is_append(&self) -> bool {
+        support::token_child::<RightStreamToken>(self.syntax()).is_some()
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{AstNode, AstNodeChildren, AstToken};
+
+use super::{
+    support, EnumCtorExprNode, EnumPatternNode, EnumStmtNode, EnumVariantNameToken,
+    EnumVariantNode, StrctFieldCtorStmtNode, StrctFieldNode, StrctFieldPatternNode, StrctNameToken,
+};
+
+impl EnumStmtNode {
+    pub fn name(&self) -> Option<String> {
+        support::token_child::<StrctNameToken>(self.syntax()).map(|n| n.text().to_string())
+    }
+    pub fn variants(&self) -> AstNodeChildren<EnumVariantNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl EnumVariantNode {
+    pub fn name(&self) -> String {
+        support::token_child::<EnumVariantNameToken>(self.syntax())
+            .expect("Always Some")
+            .text()
+            .to_string()
+    }
+
+    /// The fields of the variant. Empty for variants without fields
+    pub fn fields(&self) -> AstNodeChildren<StrctFieldNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl EnumCtorExprNode {
+    pub fn enum_name(&self) -> StrctNameToken {
+        support::token_child(self.syntax()).expect("Always Some")
+    }
+    pub fn variant_name(&self) -> Option<EnumVariantNameToken> {
+        support::token_child(self.syntax())
+    }
+    pub fn fields(&self) -> AstNodeChildren<StrctFieldCtorStmtNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl EnumPatternNode {
+    pub fn enum_name(&self) -> StrctNameToken {
+        support::token_child(self.syntax()).expect("Always Some")
+    }
+    pub fn variant_name(&self) -> Option<EnumVariantNameToken> {
+        support::token_child(self.syntax())
+    }
+    pub fn fields(&self) -> AstNodeChildren<StrctFieldPatternNode> {
+        support::node_children(self.syntax())
+    }
+}
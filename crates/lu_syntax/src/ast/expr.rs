@@ -1,8 +1,10 @@
-use crate::{AstElementChildren, AstNode, AstToken};
+use crate::{AstElementChildren, AstNode, AstNodeChildren, AstToken};
 
 use super::{
-    support, ArrayExprNode, BareWordToken, BooleanExprNode, FalseKeywordToken, NumberExprNode,
-    NumberToken, StringContentToken, StringExprNode, ValueExprElement,
+    support, ArrayExprNode, BareWordToken, BooleanExprNode, DoubleQuoteToken, FalseKeywordToken,
+    MapEntryNode, MapExprNode, MapKeyToken, NumberExprNode, NumberToken, ParentherizedExprNode,
+    PipeOrValueExprElement, RangeExprNode, SingleQuoteToken, StringContentToken, StringExprNode,
+    StringExprPartElement, ValueExprElement,
 };
 
 impl NumberExprNode {
@@ -34,15 +36,144 @@ impl ArrayExprNode {
     }
 }
 
+impl MapExprNode {
+    pub fn entries(&self) -> AstNodeChildren<MapEntryNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl MapEntryNode {
+    /// The key of this entry. Quoted keys are taken literally (without interpolation)
+    pub fn key(&self) -> String {
+        match support::token_child::<MapKeyToken>(self.syntax()) {
+            Some(key) => key.text().to_string(),
+            None => support::node_child::<StringExprNode>(self.syntax())
+                .expect("Key is either MapKey or StringExpr")
+                .value(),
+        }
+    }
+
+    pub fn value(&self) -> Option<ValueExprElement> {
+        // A quoted key is a value expr itself
+        let n_key_elems = if support::token_child::<MapKeyToken>(self.syntax()).is_some() {
+            0
+        } else {
+            1
+        };
+        support::element_children::<ValueExprElement>(self.syntax()).nth(n_key_elems)
+    }
+}
+
+impl RangeExprNode {
+    pub fn start(&self) -> ValueExprElement {
+        // Always some by parsing
+        support::element_children(self.syntax()).next().unwrap()
+    }
+
+    pub fn end(&self) -> Option<ValueExprElement> {
+        support::element_children(self.syntax()).nth(1)
+    }
+
+    pub fn step(&self) -> Option<ValueExprElement> {
+        support::element_children(self.syntax()).nth(2)
+    }
+}
+
 impl StringExprNode {
+    /// Single quoted and multi-line strings are raw. They are neither interpolated nor escaped
+    pub fn is_raw(&self) -> bool {
+        support::token_child::<SingleQuoteToken>(self.syntax()).is_some() || self.is_multi_line()
+    }
+
+    /// Whether this string is delimited by triple quotes
+    pub fn is_multi_line(&self) -> bool {
+        let quote_count = support::token_children::<DoubleQuoteToken>(self.syntax()).len()
+            + support::token_children::<SingleQuoteToken>(self.syntax()).len();
+        quote_count >= 3
+    }
+
+    /// The literal content, interpolated values and parenthesized commands of this string
+    pub fn parts(&self) -> AstElementChildren<StringExprPartElement> {
+        support::element_children(self.syntax())
+    }
+
+    /// The value of the string, ignoring all interpolated parts
     pub fn value(&self) -> String {
-        self.text()
+        self.parts()
+            .filter_map(|part| part.into_string_content().ok())
+            .map(|content| {
+                if self.is_multi_line() {
+                    strip_indentation(content.text())
+                } else if self.is_raw() {
+                    content.text().to_string()
+                } else {
+                    content.unescaped()
+                }
+            })
+            .collect()
     }
-    pub fn text(&self) -> String {
-        support::token_child::<StringContentToken>(self.syntax())
-            .unwrap()
-            .text()
-            .to_string()
+}
+
+/// If the content of a multi-line string starts on a new line, the first newline, a last line
+/// consisting only of whitespace and the indentation common to all non blank lines are removed.
+/// Otherwise the content is returned as is
+fn strip_indentation(content: &str) -> String {
+    let content = match content
+        .strip_prefix('\n')
+        .or_else(|| content.strip_prefix("\r\n"))
+    {
+        Some(content) => content,
+        None => return content.to_string(),
+    };
+    let mut lines: Vec<&str> = content.split('\n').collect();
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        // The line of the closing quotes
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_else(|| line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl ParentherizedExprNode {
+    pub fn inner(&self) -> Option<PipeOrValueExprElement> {
+        support::element_child(self.syntax())
+    }
+}
+
+impl StringContentToken {
+    /// The content with escape sequences (`\n`, `\t`, `\"`, ...) replaced.
+    /// Unknown escape sequences are kept as is.
+    pub fn unescaped(&self) -> String {
+        let mut result = String::new();
+        let mut chars = self.text().chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('t') => result.push('\t'),
+                Some('r') => result.push('\r'),
+                Some('0') => result.push('\0'),
+                Some(c @ ('"' | '\\' | '$' | '(' | ')')) => result.push(c),
+                Some(c) => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                None => result.push('\\'),
+            }
+        }
+        result
     }
 }
 
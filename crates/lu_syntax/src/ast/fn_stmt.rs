@@ -5,7 +5,7 @@ use crate::{AstNode, AstToken};
 
 use super::{
     addr_of_mod_node_contained_in, support, BlockStmtNode, FnDeclNameToken, FnKeywordToken,
-    FnStmtNode, ImpureKeywordToken, SignatureNode,
+    FnStmtNode, GenericParamNameToken, GenericParamsNode, ImpureKeywordToken, SignatureNode,
 };
 
 impl FnStmtNode {
@@ -83,4 +83,19 @@ impl FnStmtNode {
     pub fn block_stmt(&self) -> Option<BlockStmtNode> {
         support::node_child(self.syntax())
     }
+
+    /// The explicitly declared generics (`fn first<Elem>`)
+    pub fn generic_params(&self) -> Vec<GenericParamNameToken> {
+        support::node_child::<GenericParamsNode>(self.syntax())
+            .map(|params| support::token_children(params.syntax()))
+            .unwrap_or_default()
+    }
+
+    /// The names of the explicitly declared generics (`fn first<Elem>`)
+    pub fn generic_param_names(&self) -> Vec<String> {
+        self.generic_params()
+            .iter()
+            .map(|name| name.text().to_string())
+            .collect()
+    }
 }
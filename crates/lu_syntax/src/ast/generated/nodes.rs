@@ -74,6 +74,70 @@ impl Display for StrctKeywordToken {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EnumKeywordToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for EnumKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumKeyword }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for EnumKeywordToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for EnumKeywordToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for EnumKeywordToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ImplKeywordToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for ImplKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ImplKeyword }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for ImplKeywordToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ImplKeywordToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ImplKeywordToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct OptKeywordToken {
     pub(crate) syntax: SyntaxToken,
@@ -298,6 +362,38 @@ impl Display for AndKeywordToken {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct NotKeywordToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for NotKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::NotKeyword }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for NotKeywordToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for NotKeywordToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for NotKeywordToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct UseKeywordToken {
     pub(crate) syntax: SyntaxToken,
@@ -971,11 +1067,11 @@ impl Display for StringKeywordToken {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ClsKeywordToken {
+pub struct RangeKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for ClsKeywordToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ClsKeyword }
+impl AstToken for RangeKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RangeKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -985,29 +1081,29 @@ impl AstToken for ClsKeywordToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ClsKeywordToken{
+impl HasSyntaxKind for RangeKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ClsKeywordToken{
+impl HasTextRange for RangeKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ClsKeywordToken {
+impl Display for RangeKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RetKeywordToken {
+pub struct ClsKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for RetKeywordToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RetKeyword }
+impl AstToken for ClsKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ClsKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1017,29 +1113,29 @@ impl AstToken for RetKeywordToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for RetKeywordToken{
+impl HasSyntaxKind for ClsKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RetKeywordToken{
+impl HasTextRange for ClsKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RetKeywordToken {
+impl Display for ClsKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct GenericTypeToken {
+pub struct RetKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for GenericTypeToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::GenericType }
+impl AstToken for RetKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RetKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1049,107 +1145,93 @@ impl AstToken for GenericTypeToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for GenericTypeToken{
+impl HasSyntaxKind for RetKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for GenericTypeToken{
+impl HasTextRange for RetKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for GenericTypeToken {
+impl Display for RetKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ArrayTypeNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct BreakKeywordToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for ArrayTypeNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayType }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for BreakKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BreakKeyword }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ArrayTypeNode{
+impl HasSyntaxKind for BreakKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ArrayTypeNode{
+impl HasTextRange for BreakKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-
-use lu_parser::grammar::ArrayTypeRule;
-impl HasRule for ArrayTypeNode{
-    fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(ArrayTypeRule{})
-    }
-}
-impl Display for ArrayTypeNode {
+impl Display for BreakKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct FnTypeNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct ContinueKeywordToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for FnTypeNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnType }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for ContinueKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ContinueKeyword }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for FnTypeNode{
+impl HasSyntaxKind for ContinueKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for FnTypeNode{
+impl HasTextRange for ContinueKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-
-use lu_parser::grammar::FnTypeRule;
-impl HasRule for FnTypeNode{
-    fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(FnTypeRule{})
-    }
-}
-impl Display for FnTypeNode {
+impl Display for ContinueKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct OptModifierToken {
+pub struct TryKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for OptModifierToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::OptModifier }
+impl AstToken for TryKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::TryKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1159,29 +1241,29 @@ impl AstToken for OptModifierToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for OptModifierToken{
+impl HasSyntaxKind for TryKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for OptModifierToken{
+impl HasTextRange for TryKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for OptModifierToken {
+impl Display for TryKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LeftParenthesisToken {
+pub struct CatchKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for LeftParenthesisToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftParenthesis }
+impl AstToken for CatchKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::CatchKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1191,29 +1273,29 @@ impl AstToken for LeftParenthesisToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for LeftParenthesisToken{
+impl HasSyntaxKind for CatchKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LeftParenthesisToken{
+impl HasTextRange for CatchKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LeftParenthesisToken {
+impl Display for CatchKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RightParenthesisToken {
+pub struct MatchKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for RightParenthesisToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightParenthesis }
+impl AstToken for MatchKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MatchKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1223,29 +1305,29 @@ impl AstToken for RightParenthesisToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for RightParenthesisToken{
+impl HasSyntaxKind for MatchKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RightParenthesisToken{
+impl HasTextRange for MatchKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RightParenthesisToken {
+impl Display for MatchKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LeftCurlyBracketsToken {
+pub struct CaseKeywordToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for LeftCurlyBracketsToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftCurlyBrackets }
+impl AstToken for CaseKeywordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::CaseKeyword }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1255,29 +1337,29 @@ impl AstToken for LeftCurlyBracketsToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for LeftCurlyBracketsToken{
+impl HasSyntaxKind for CaseKeywordToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LeftCurlyBracketsToken{
+impl HasTextRange for CaseKeywordToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LeftCurlyBracketsToken {
+impl Display for CaseKeywordToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RightCurlyBracketsToken {
+pub struct GenericTypeToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for RightCurlyBracketsToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightCurlyBrackets }
+impl AstToken for GenericTypeToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::GenericType }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1287,125 +1369,146 @@ impl AstToken for RightCurlyBracketsToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for RightCurlyBracketsToken{
+impl HasSyntaxKind for GenericTypeToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RightCurlyBracketsToken{
+impl HasTextRange for GenericTypeToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RightCurlyBracketsToken {
+impl Display for GenericTypeToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LeftRectangularBracketsToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct ArrayTypeNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for LeftRectangularBracketsToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftRectangularBrackets }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for ArrayTypeNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayType }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for LeftRectangularBracketsToken{
+impl HasSyntaxKind for ArrayTypeNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LeftRectangularBracketsToken{
+impl HasTextRange for ArrayTypeNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LeftRectangularBracketsToken {
+
+use lu_parser::grammar::ArrayTypeRule;
+impl HasRule for ArrayTypeNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(ArrayTypeRule{})
+    }
+}
+impl Display for ArrayTypeNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RightRectangularBracketsToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct FnTypeNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for RightRectangularBracketsToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightRectangularBrackets }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for FnTypeNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnType }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for RightRectangularBracketsToken{
+impl HasSyntaxKind for FnTypeNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RightRectangularBracketsToken{
+impl HasTextRange for FnTypeNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RightRectangularBracketsToken {
+
+use lu_parser::grammar::FnTypeRule;
+impl HasRule for FnTypeNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(FnTypeRule{})
+    }
+}
+impl Display for FnTypeNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct PlusSignToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct MapTypeNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for PlusSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::PlusSign }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for MapTypeNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MapType }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for PlusSignToken{
+impl HasSyntaxKind for MapTypeNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for PlusSignToken{
+impl HasTextRange for MapTypeNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for PlusSignToken {
+
+use lu_parser::grammar::MapTypeRule;
+impl HasRule for MapTypeNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(MapTypeRule{})
+    }
+}
+impl Display for MapTypeNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct MinusSignToken {
+pub struct OptModifierToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for MinusSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MinusSign }
+impl AstToken for OptModifierToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::OptModifier }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1415,29 +1518,29 @@ impl AstToken for MinusSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for MinusSignToken{
+impl HasSyntaxKind for OptModifierToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for MinusSignToken{
+impl HasTextRange for OptModifierToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for MinusSignToken {
+impl Display for OptModifierToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct MultSignToken {
+pub struct LeftParenthesisToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for MultSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MultSign }
+impl AstToken for LeftParenthesisToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftParenthesis }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1447,29 +1550,29 @@ impl AstToken for MultSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for MultSignToken{
+impl HasSyntaxKind for LeftParenthesisToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for MultSignToken{
+impl HasTextRange for LeftParenthesisToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for MultSignToken {
+impl Display for LeftParenthesisToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct FilePathSepToken {
+pub struct RightParenthesisToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for FilePathSepToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FilePathSep }
+impl AstToken for RightParenthesisToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightParenthesis }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1479,29 +1582,29 @@ impl AstToken for FilePathSepToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for FilePathSepToken{
+impl HasSyntaxKind for RightParenthesisToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for FilePathSepToken{
+impl HasTextRange for RightParenthesisToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for FilePathSepToken {
+impl Display for RightParenthesisToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct DivSignToken {
+pub struct LeftCurlyBracketsToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for DivSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DivSign }
+impl AstToken for LeftCurlyBracketsToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftCurlyBrackets }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1511,29 +1614,29 @@ impl AstToken for DivSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for DivSignToken{
+impl HasSyntaxKind for LeftCurlyBracketsToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for DivSignToken{
+impl HasTextRange for LeftCurlyBracketsToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for DivSignToken {
+impl Display for LeftCurlyBracketsToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LessThanSignToken {
+pub struct RightCurlyBracketsToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for LessThanSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LessThanSign }
+impl AstToken for RightCurlyBracketsToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightCurlyBrackets }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1543,29 +1646,29 @@ impl AstToken for LessThanSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for LessThanSignToken{
+impl HasSyntaxKind for RightCurlyBracketsToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LessThanSignToken{
+impl HasTextRange for RightCurlyBracketsToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LessThanSignToken {
+impl Display for RightCurlyBracketsToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LessOrEqualSignToken {
+pub struct LeftRectangularBracketsToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for LessOrEqualSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LessOrEqualSign }
+impl AstToken for LeftRectangularBracketsToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LeftRectangularBrackets }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1575,29 +1678,29 @@ impl AstToken for LessOrEqualSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for LessOrEqualSignToken{
+impl HasSyntaxKind for LeftRectangularBracketsToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LessOrEqualSignToken{
+impl HasTextRange for LeftRectangularBracketsToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LessOrEqualSignToken {
+impl Display for LeftRectangularBracketsToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct EqualitySignToken {
+pub struct RightRectangularBracketsToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for EqualitySignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EqualitySign }
+impl AstToken for RightRectangularBracketsToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightRectangularBrackets }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1607,29 +1710,29 @@ impl AstToken for EqualitySignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for EqualitySignToken{
+impl HasSyntaxKind for RightRectangularBracketsToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for EqualitySignToken{
+impl HasTextRange for RightRectangularBracketsToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for EqualitySignToken {
+impl Display for RightRectangularBracketsToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct InequalitySignToken {
+pub struct PlusSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for InequalitySignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::InequalitySign }
+impl AstToken for PlusSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::PlusSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1639,29 +1742,29 @@ impl AstToken for InequalitySignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for InequalitySignToken{
+impl HasSyntaxKind for PlusSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for InequalitySignToken{
+impl HasTextRange for PlusSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for InequalitySignToken {
+impl Display for PlusSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct BiggerThanSignToken {
+pub struct MinusSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for BiggerThanSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BiggerThanSign }
+impl AstToken for MinusSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MinusSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1671,29 +1774,29 @@ impl AstToken for BiggerThanSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for BiggerThanSignToken{
+impl HasSyntaxKind for MinusSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for BiggerThanSignToken{
+impl HasTextRange for MinusSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for BiggerThanSignToken {
+impl Display for MinusSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct BiggerOrEqualSignToken {
+pub struct DoubleMinusToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for BiggerOrEqualSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BiggerOrEqualSign }
+impl AstToken for DoubleMinusToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DoubleMinus }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1703,29 +1806,29 @@ impl AstToken for BiggerOrEqualSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for BiggerOrEqualSignToken{
+impl HasSyntaxKind for DoubleMinusToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for BiggerOrEqualSignToken{
+impl HasTextRange for DoubleMinusToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for BiggerOrEqualSignToken {
+impl Display for DoubleMinusToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RightStreamToken {
+pub struct MultSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for RightStreamToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightStream }
+impl AstToken for MultSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MultSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1735,29 +1838,29 @@ impl AstToken for RightStreamToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for RightStreamToken{
+impl HasSyntaxKind for MultSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RightStreamToken{
+impl HasTextRange for MultSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RightStreamToken {
+impl Display for MultSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct DivAssignSignToken {
+pub struct FilePathSepToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for DivAssignSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DivAssignSign }
+impl AstToken for FilePathSepToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FilePathSep }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1767,29 +1870,29 @@ impl AstToken for DivAssignSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for DivAssignSignToken{
+impl HasSyntaxKind for FilePathSepToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for DivAssignSignToken{
+impl HasTextRange for FilePathSepToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for DivAssignSignToken {
+impl Display for FilePathSepToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct MulAssignSignToken {
+pub struct DivSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for MulAssignSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MulAssignSign }
+impl AstToken for DivSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DivSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1799,29 +1902,29 @@ impl AstToken for MulAssignSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for MulAssignSignToken{
+impl HasSyntaxKind for DivSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for MulAssignSignToken{
+impl HasTextRange for DivSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for MulAssignSignToken {
+impl Display for DivSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct AddAssignSignToken {
+pub struct ModSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for AddAssignSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AddAssignSign }
+impl AstToken for ModSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ModSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1831,29 +1934,29 @@ impl AstToken for AddAssignSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for AddAssignSignToken{
+impl HasSyntaxKind for ModSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for AddAssignSignToken{
+impl HasTextRange for ModSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for AddAssignSignToken {
+impl Display for ModSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct MinAssignSignToken {
+pub struct PowSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for MinAssignSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MinAssignSign }
+impl AstToken for PowSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::PowSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1863,29 +1966,29 @@ impl AstToken for MinAssignSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for MinAssignSignToken{
+impl HasSyntaxKind for PowSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for MinAssignSignToken{
+impl HasTextRange for PowSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for MinAssignSignToken {
+impl Display for PowSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct AssignSignToken {
+pub struct LessThanSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for AssignSignToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AssignSign }
+impl AstToken for LessThanSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LessThanSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1895,29 +1998,29 @@ impl AstToken for AssignSignToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for AssignSignToken{
+impl HasSyntaxKind for LessThanSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for AssignSignToken{
+impl HasTextRange for LessThanSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for AssignSignToken {
+impl Display for LessThanSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct PipeToken {
+pub struct LessOrEqualSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for PipeToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Pipe }
+impl AstToken for LessOrEqualSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LessOrEqualSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1927,29 +2030,29 @@ impl AstToken for PipeToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for PipeToken{
+impl HasSyntaxKind for LessOrEqualSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for PipeToken{
+impl HasTextRange for LessOrEqualSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for PipeToken {
+impl Display for LessOrEqualSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct DollarToken {
+pub struct EqualitySignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for DollarToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Dollar }
+impl AstToken for EqualitySignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EqualitySign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1959,29 +2062,29 @@ impl AstToken for DollarToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for DollarToken{
+impl HasSyntaxKind for EqualitySignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for DollarToken{
+impl HasTextRange for EqualitySignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for DollarToken {
+impl Display for EqualitySignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct QuestionMarkToken {
+pub struct InequalitySignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for QuestionMarkToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::QuestionMark }
+impl AstToken for InequalitySignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::InequalitySign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -1991,29 +2094,29 @@ impl AstToken for QuestionMarkToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for QuestionMarkToken{
+impl HasSyntaxKind for InequalitySignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for QuestionMarkToken{
+impl HasTextRange for InequalitySignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for QuestionMarkToken {
+impl Display for InequalitySignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct PointToken {
+pub struct BiggerThanSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for PointToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Point }
+impl AstToken for BiggerThanSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BiggerThanSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2023,29 +2126,29 @@ impl AstToken for PointToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for PointToken{
+impl HasSyntaxKind for BiggerThanSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for PointToken{
+impl HasTextRange for BiggerThanSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for PointToken {
+impl Display for BiggerThanSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct DoublePointToken {
+pub struct BiggerOrEqualSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for DoublePointToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DoublePoint }
+impl AstToken for BiggerOrEqualSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BiggerOrEqualSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2055,29 +2158,29 @@ impl AstToken for DoublePointToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for DoublePointToken{
+impl HasSyntaxKind for BiggerOrEqualSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for DoublePointToken{
+impl HasTextRange for BiggerOrEqualSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for DoublePointToken {
+impl Display for BiggerOrEqualSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct DoubleQuoteToken {
+pub struct RightStreamToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for DoubleQuoteToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DoubleQuote }
+impl AstToken for RightStreamToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RightStream }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2087,29 +2190,29 @@ impl AstToken for DoubleQuoteToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for DoubleQuoteToken{
+impl HasSyntaxKind for RightStreamToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for DoubleQuoteToken{
+impl HasTextRange for RightStreamToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for DoubleQuoteToken {
+impl Display for RightStreamToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct SingleQuoteToken {
+pub struct TruncRightStreamToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for SingleQuoteToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::SingleQuote }
+impl AstToken for TruncRightStreamToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::TruncRightStream }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2119,29 +2222,29 @@ impl AstToken for SingleQuoteToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for SingleQuoteToken{
+impl HasSyntaxKind for TruncRightStreamToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for SingleQuoteToken{
+impl HasTextRange for TruncRightStreamToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for SingleQuoteToken {
+impl Display for TruncRightStreamToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ErrorToken {
+pub struct DivAssignSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for ErrorToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Error }
+impl AstToken for DivAssignSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DivAssignSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2151,29 +2254,29 @@ impl AstToken for ErrorToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ErrorToken{
+impl HasSyntaxKind for DivAssignSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ErrorToken{
+impl HasTextRange for DivAssignSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ErrorToken {
+impl Display for DivAssignSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ParserInternalToken {
+pub struct MulAssignSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for ParserInternalToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ParserInternal }
+impl AstToken for MulAssignSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MulAssignSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2183,93 +2286,93 @@ impl AstToken for ParserInternalToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ParserInternalToken{
+impl HasSyntaxKind for MulAssignSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ParserInternalToken{
+impl HasTextRange for MulAssignSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ParserInternalToken {
+impl Display for MulAssignSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct EofNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct AddAssignSignToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for EofNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Eof }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for AddAssignSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AddAssignSign }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for EofNode{
+impl HasSyntaxKind for AddAssignSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for EofNode{
+impl HasTextRange for AddAssignSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for EofNode {
+impl Display for AddAssignSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct TombstoneNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct MinAssignSignToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for TombstoneNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Tombstone }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for MinAssignSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MinAssignSign }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for TombstoneNode{
+impl HasSyntaxKind for MinAssignSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for TombstoneNode{
+impl HasTextRange for MinAssignSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for TombstoneNode {
+impl Display for MinAssignSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct WhitespaceToken {
+pub struct AssignSignToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for WhitespaceToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Whitespace }
+impl AstToken for AssignSignToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AssignSign }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2279,29 +2382,29 @@ impl AstToken for WhitespaceToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for WhitespaceToken{
+impl HasSyntaxKind for AssignSignToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for WhitespaceToken{
+impl HasTextRange for AssignSignToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for WhitespaceToken {
+impl Display for AssignSignToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CommentToken {
+pub struct PipeToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for CommentToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Comment }
+impl AstToken for PipeToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Pipe }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2311,29 +2414,29 @@ impl AstToken for CommentToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for CommentToken{
+impl HasSyntaxKind for PipeToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for CommentToken{
+impl HasTextRange for PipeToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for CommentToken {
+impl Display for PipeToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct NewlineToken {
+pub struct AmpersandToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for NewlineToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Newline }
+impl AstToken for AmpersandToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Ampersand }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2343,29 +2446,29 @@ impl AstToken for NewlineToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for NewlineToken{
+impl HasSyntaxKind for AmpersandToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for NewlineToken{
+impl HasTextRange for AmpersandToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for NewlineToken {
+impl Display for AmpersandToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct BareWordToken {
+pub struct DollarToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for BareWordToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BareWord }
+impl AstToken for DollarToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Dollar }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2375,29 +2478,29 @@ impl AstToken for BareWordToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for BareWordToken{
+impl HasSyntaxKind for DollarToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for BareWordToken{
+impl HasTextRange for DollarToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for BareWordToken {
+impl Display for DollarToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StringContentToken {
+pub struct QuestionMarkToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for StringContentToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StringContent }
+impl AstToken for QuestionMarkToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::QuestionMark }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2407,29 +2510,29 @@ impl AstToken for StringContentToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for StringContentToken{
+impl HasSyntaxKind for QuestionMarkToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StringContentToken{
+impl HasTextRange for QuestionMarkToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StringContentToken {
+impl Display for QuestionMarkToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct VarDeclNameToken {
+pub struct PointToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for VarDeclNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::VarDeclName }
+impl AstToken for PointToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Point }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2439,29 +2542,29 @@ impl AstToken for VarDeclNameToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for VarDeclNameToken{
+impl HasSyntaxKind for PointToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for VarDeclNameToken{
+impl HasTextRange for PointToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for VarDeclNameToken {
+impl Display for PointToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct FnDeclNameToken {
+pub struct DoublePointToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for FnDeclNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnDeclName }
+impl AstToken for DoublePointToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DoublePoint }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2471,29 +2574,29 @@ impl AstToken for FnDeclNameToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for FnDeclNameToken{
+impl HasSyntaxKind for DoublePointToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for FnDeclNameToken{
+impl HasTextRange for DoublePointToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for FnDeclNameToken {
+impl Display for DoublePointToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ArgNameToken {
+pub struct DoubleQuoteToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for ArgNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArgName }
+impl AstToken for DoubleQuoteToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::DoubleQuote }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2503,29 +2606,29 @@ impl AstToken for ArgNameToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ArgNameToken{
+impl HasSyntaxKind for DoubleQuoteToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ArgNameToken{
+impl HasTextRange for DoubleQuoteToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ArgNameToken {
+impl Display for DoubleQuoteToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct VarArgNameToken {
+pub struct SingleQuoteToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for VarArgNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::VarArgName }
+impl AstToken for SingleQuoteToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::SingleQuote }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2535,29 +2638,29 @@ impl AstToken for VarArgNameToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for VarArgNameToken{
+impl HasSyntaxKind for SingleQuoteToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for VarArgNameToken{
+impl HasTextRange for SingleQuoteToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for VarArgNameToken {
+impl Display for SingleQuoteToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LongFlagToken {
+pub struct BackSlashToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for LongFlagToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LongFlag }
+impl AstToken for BackSlashToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BackSlash }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2567,29 +2670,29 @@ impl AstToken for LongFlagToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for LongFlagToken{
+impl HasSyntaxKind for BackSlashToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LongFlagToken{
+impl HasTextRange for BackSlashToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for LongFlagToken {
+impl Display for BackSlashToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ShortFlagToken {
+pub struct ErrorToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for ShortFlagToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ShortFlag }
+impl AstToken for ErrorToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Error }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2599,29 +2702,29 @@ impl AstToken for ShortFlagToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for ShortFlagToken{
+impl HasSyntaxKind for ErrorToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ShortFlagToken{
+impl HasTextRange for ErrorToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ShortFlagToken {
+impl Display for ErrorToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct NumberToken {
+pub struct ParserInternalToken {
     pub(crate) syntax: SyntaxToken,
 }
-impl AstToken for NumberToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Number }
+impl AstToken for ParserInternalToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ParserInternal }
     fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2631,154 +2734,698 @@ impl AstToken for NumberToken {
     }
     fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for NumberToken{
+impl HasSyntaxKind for ParserInternalToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for NumberToken{
+impl HasTextRange for ParserInternalToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for NumberToken {
+impl Display for ParserInternalToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctNameToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct EofNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for StrctNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctName }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for EofNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Eof }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctNameToken{
+impl HasSyntaxKind for EofNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctNameToken{
+impl HasTextRange for EofNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctNameToken {
+impl Display for EofNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctFieldNameToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct TombstoneNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for StrctFieldNameToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctFieldName }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for TombstoneNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Tombstone }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctFieldNameToken{
+impl HasSyntaxKind for TombstoneNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctFieldNameToken{
+impl HasTextRange for TombstoneNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctFieldNameToken {
+impl Display for TombstoneNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct AbsFileNameNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct WhitespaceToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for AbsFileNameNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AbsFileName }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for WhitespaceToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Whitespace }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for AbsFileNameNode{
+impl HasSyntaxKind for WhitespaceToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for AbsFileNameNode{
+impl HasTextRange for WhitespaceToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for AbsFileNameNode {
+impl Display for WhitespaceToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RelFileNameNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct CommentToken {
+    pub(crate) syntax: SyntaxToken,
 }
-impl AstNode for RelFileNameNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RelFileName }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstToken for CommentToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Comment }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
 }
-impl HasSyntaxKind for RelFileNameNode{
+impl HasSyntaxKind for CommentToken{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RelFileNameNode{
+impl HasTextRange for CommentToken{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RelFileNameNode {
+impl Display for CommentToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct SourceFileNode {
-    pub(crate) syntax: SyntaxNode,
+pub struct NewlineToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for NewlineToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Newline }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for NewlineToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for NewlineToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for NewlineToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct BareWordToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for BareWordToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BareWord }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for BareWordToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for BareWordToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for BareWordToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StringContentToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for StringContentToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StringContent }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for StringContentToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StringContentToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StringContentToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct VarDeclNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for VarDeclNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::VarDeclName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for VarDeclNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for VarDeclNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for VarDeclNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct FnDeclNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for FnDeclNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnDeclName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for FnDeclNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for FnDeclNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for FnDeclNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct GenericParamNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for GenericParamNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::GenericParamName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for GenericParamNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for GenericParamNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for GenericParamNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ArgNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for ArgNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArgName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for ArgNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ArgNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ArgNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct VarArgNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for VarArgNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::VarArgName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for VarArgNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for VarArgNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for VarArgNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct LongFlagToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for LongFlagToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LongFlag }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for LongFlagToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for LongFlagToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for LongFlagToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ShortFlagToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for ShortFlagToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ShortFlag }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for ShortFlagToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ShortFlagToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ShortFlagToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct NumberToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for NumberToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Number }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for NumberToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for NumberToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for NumberToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for StrctNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for StrctNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctFieldNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for StrctFieldNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctFieldName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for StrctFieldNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctFieldNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctFieldNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EnumVariantNameToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for EnumVariantNameToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumVariantName }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for EnumVariantNameToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for EnumVariantNameToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for EnumVariantNameToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct MapKeyToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for MapKeyToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MapKey }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for MapKeyToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for MapKeyToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for MapKeyToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct AbsFileNameNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for AbsFileNameNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::AbsFileName }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for AbsFileNameNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for AbsFileNameNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for AbsFileNameNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct RelFileNameNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for RelFileNameNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RelFileName }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for RelFileNameNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for RelFileNameNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for RelFileNameNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct SourceFileNode {
+    pub(crate) syntax: SyntaxNode,
 }
 impl AstNode for SourceFileNode {
     fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::SourceFile }
@@ -2791,93 +3438,619 @@ impl AstNode for SourceFileNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for SourceFileNode{
+impl HasSyntaxKind for SourceFileNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for SourceFileNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for SourceFileNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CliLineToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for CliLineToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::CliLine }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for CliLineToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for CliLineToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for CliLineToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct OffsetToken {
+    pub(crate) syntax: SyntaxToken,
+}
+impl AstToken for OffsetToken {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Offset }
+    fn cast(syntax: SyntaxToken) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+}
+impl HasSyntaxKind for OffsetToken{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for OffsetToken{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for OffsetToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for StrctStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for StrctStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct RedirStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for RedirStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RedirStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for RedirStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for RedirStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for RedirStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UseStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for UseStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::UseStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for UseStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for UseStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for UseStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct PluginUseStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for PluginUseStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::PluginUseStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for PluginUseStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for PluginUseStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for PluginUseStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctFieldNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for StrctFieldNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctField }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for StrctFieldNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctFieldNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctFieldNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctCtorExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for StrctCtorExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctCtorExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for StrctCtorExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctCtorExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctCtorExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct StrctFieldCtorStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for StrctFieldCtorStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctFieldCtorStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for StrctFieldCtorStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for StrctFieldCtorStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StrctFieldCtorStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EnumStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for EnumStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for EnumStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for EnumStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for EnumStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EnumVariantNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for EnumVariantNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumVariant }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for EnumVariantNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for EnumVariantNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for EnumVariantNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EnumCtorExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for EnumCtorExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumCtorExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for EnumCtorExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for EnumCtorExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for EnumCtorExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ImplStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for ImplStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ImplStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for ImplStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ImplStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ImplStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct LetStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for LetStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LetStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for LetStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for LetStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::LetStmtRule;
+impl HasRule for LetStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(LetStmtRule{})
+    }
+}
+impl Display for LetStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct FnStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for FnStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for FnStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for FnStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for FnStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct GenericParamsNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for GenericParamsNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::GenericParams }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for GenericParamsNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for SourceFileNode{
+impl HasTextRange for GenericParamsNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for SourceFileNode {
+impl Display for GenericParamsNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CliLineToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct RetStmtNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for CliLineToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::CliLine }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for RetStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RetStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for CliLineToken{
+impl HasSyntaxKind for RetStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for CliLineToken{
+impl HasTextRange for RetStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for CliLineToken {
+
+use lu_parser::grammar::RetStmtRule;
+impl HasRule for RetStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(RetStmtRule{})
+    }
+}
+impl Display for RetStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct OffsetToken {
-    pub(crate) syntax: SyntaxToken,
+pub struct IfElifElseStmtNode {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstToken for OffsetToken {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::Offset }
-    fn cast(syntax: SyntaxToken) -> Option<Self> {
+impl AstNode for IfElifElseStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfElifElseStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxToken { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for OffsetToken{
+impl HasSyntaxKind for IfElifElseStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for OffsetToken{
+impl HasTextRange for IfElifElseStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for OffsetToken {
+impl Display for IfElifElseStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctStmtNode {
+pub struct IfOptElifOptStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for StrctStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctStmt }
+impl AstNode for IfOptElifOptStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfOptElifOptStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2887,29 +4060,29 @@ impl AstNode for StrctStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctStmtNode{
+impl HasSyntaxKind for IfOptElifOptStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctStmtNode{
+impl HasTextRange for IfOptElifOptStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctStmtNode {
+impl Display for IfOptElifOptStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RedirStmtNode {
+pub struct IfElifStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for RedirStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RedirStmt }
+impl AstNode for IfElifStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfElifStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2919,29 +4092,29 @@ impl AstNode for RedirStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for RedirStmtNode{
+impl HasSyntaxKind for IfElifStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RedirStmtNode{
+impl HasTextRange for IfElifStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for RedirStmtNode {
+impl Display for IfElifStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct UseStmtNode {
+pub struct ElseStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for UseStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::UseStmt }
+impl AstNode for ElseStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElseStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2951,29 +4124,29 @@ impl AstNode for UseStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for UseStmtNode{
+impl HasSyntaxKind for ElseStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for UseStmtNode{
+impl HasTextRange for ElseStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for UseStmtNode {
+impl Display for ElseStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct PluginUseStmtNode {
+pub struct IfBlockNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for PluginUseStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::PluginUseStmt }
+impl AstNode for IfBlockNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfBlock }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2983,29 +4156,203 @@ impl AstNode for PluginUseStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for PluginUseStmtNode{
+impl HasSyntaxKind for IfBlockNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for PluginUseStmtNode{
+impl HasTextRange for IfBlockNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for PluginUseStmtNode {
+impl Display for IfBlockNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctFieldNode {
+pub struct ElifBlockNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for StrctFieldNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctField }
+impl AstNode for ElifBlockNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElifBlock }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for ElifBlockNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ElifBlockNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ElifBlockNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ElseBlockNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for ElseBlockNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElseBlock }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for ElseBlockNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ElseBlockNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for ElseBlockNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ForStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for ForStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ForStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for ForStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ForStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::ForStmtRule;
+impl HasRule for ForStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(ForStmtRule{})
+    }
+}
+impl Display for ForStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct WhileStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for WhileStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::WhileStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for WhileStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for WhileStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::WhileStmtRule;
+impl HasRule for WhileStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(WhileStmtRule{})
+    }
+}
+impl Display for WhileStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct BreakStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for BreakStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BreakStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for BreakStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for BreakStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for BreakStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ContinueStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for ContinueStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ContinueStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3015,29 +4362,29 @@ impl AstNode for StrctFieldNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctFieldNode{
+impl HasSyntaxKind for ContinueStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctFieldNode{
+impl HasTextRange for ContinueStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctFieldNode {
+impl Display for ContinueStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctCtorExprNode {
+pub struct TryStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for StrctCtorExprNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctCtorExpr }
+impl AstNode for TryStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::TryStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3047,29 +4394,36 @@ impl AstNode for StrctCtorExprNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctCtorExprNode{
+impl HasSyntaxKind for TryStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctCtorExprNode{
+impl HasTextRange for TryStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctCtorExprNode {
+
+use lu_parser::grammar::TryStmtRule;
+impl HasRule for TryStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(TryStmtRule{})
+    }
+}
+impl Display for TryStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct StrctFieldCtorStmtNode {
+pub struct CatchStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for StrctFieldCtorStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctFieldCtorStmt }
+impl AstNode for CatchStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::CatchStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3079,29 +4433,29 @@ impl AstNode for StrctFieldCtorStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for StrctFieldCtorStmtNode{
+impl HasSyntaxKind for CatchStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for StrctFieldCtorStmtNode{
+impl HasTextRange for CatchStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for StrctFieldCtorStmtNode {
+impl Display for CatchStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct LetStmtNode {
+pub struct MatchStmtNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for LetStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LetStmt }
+impl AstNode for MatchStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MatchStmt }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3111,36 +4465,36 @@ impl AstNode for LetStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for LetStmtNode{
+impl HasSyntaxKind for MatchStmtNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for LetStmtNode{
+impl HasTextRange for MatchStmtNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
 
-use lu_parser::grammar::LetStmtRule;
-impl HasRule for LetStmtNode{
+use lu_parser::grammar::MatchStmtRule;
+impl HasRule for MatchStmtNode{
     fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(LetStmtRule{})
+        Box::new(MatchStmtRule{})
     }
 }
-impl Display for LetStmtNode {
+impl Display for MatchStmtNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct FnStmtNode {
+pub struct MatchArmNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for FnStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::FnStmt }
+impl AstNode for MatchArmNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MatchArm }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3150,29 +4504,29 @@ impl AstNode for FnStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for FnStmtNode{
+impl HasSyntaxKind for MatchArmNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for FnStmtNode{
+impl HasTextRange for MatchArmNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for FnStmtNode {
+impl Display for MatchArmNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct RetStmtNode {
+pub struct LiteralPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for RetStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RetStmt }
+impl AstNode for LiteralPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::LiteralPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3182,36 +4536,29 @@ impl AstNode for RetStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for RetStmtNode{
+impl HasSyntaxKind for LiteralPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for RetStmtNode{
+impl HasTextRange for LiteralPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-
-use lu_parser::grammar::RetStmtRule;
-impl HasRule for RetStmtNode{
-    fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(RetStmtRule{})
-    }
-}
-impl Display for RetStmtNode {
+impl Display for LiteralPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct IfElifElseStmtNode {
+pub struct BindingPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for IfElifElseStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfElifElseStmt }
+impl AstNode for BindingPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BindingPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3221,29 +4568,29 @@ impl AstNode for IfElifElseStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for IfElifElseStmtNode{
+impl HasSyntaxKind for BindingPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for IfElifElseStmtNode{
+impl HasTextRange for BindingPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for IfElifElseStmtNode {
+impl Display for BindingPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct IfOptElifOptStmtNode {
+pub struct NonePatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for IfOptElifOptStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfOptElifOptStmt }
+impl AstNode for NonePatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::NonePattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3253,29 +4600,29 @@ impl AstNode for IfOptElifOptStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for IfOptElifOptStmtNode{
+impl HasSyntaxKind for NonePatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for IfOptElifOptStmtNode{
+impl HasTextRange for NonePatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for IfOptElifOptStmtNode {
+impl Display for NonePatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct IfElifStmtNode {
+pub struct SomePatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for IfElifStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfElifStmt }
+impl AstNode for SomePatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::SomePattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3285,29 +4632,29 @@ impl AstNode for IfElifStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for IfElifStmtNode{
+impl HasSyntaxKind for SomePatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for IfElifStmtNode{
+impl HasTextRange for SomePatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for IfElifStmtNode {
+impl Display for SomePatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ElseStmtNode {
+pub struct StrctPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for ElseStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElseStmt }
+impl AstNode for StrctPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3317,29 +4664,29 @@ impl AstNode for ElseStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for ElseStmtNode{
+impl HasSyntaxKind for StrctPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ElseStmtNode{
+impl HasTextRange for StrctPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ElseStmtNode {
+impl Display for StrctPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct IfBlockNode {
+pub struct StrctFieldPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for IfBlockNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::IfBlock }
+impl AstNode for StrctFieldPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::StrctFieldPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3349,29 +4696,29 @@ impl AstNode for IfBlockNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for IfBlockNode{
+impl HasSyntaxKind for StrctFieldPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for IfBlockNode{
+impl HasTextRange for StrctFieldPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for IfBlockNode {
+impl Display for StrctFieldPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ElifBlockNode {
+pub struct EnumPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for ElifBlockNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElifBlock }
+impl AstNode for EnumPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::EnumPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3381,29 +4728,29 @@ impl AstNode for ElifBlockNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for ElifBlockNode{
+impl HasSyntaxKind for EnumPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ElifBlockNode{
+impl HasTextRange for EnumPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ElifBlockNode {
+impl Display for EnumPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ElseBlockNode {
+pub struct ArrayPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for ElseBlockNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ElseBlock }
+impl AstNode for ArrayPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3413,29 +4760,29 @@ impl AstNode for ElseBlockNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for ElseBlockNode{
+impl HasSyntaxKind for ArrayPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ElseBlockNode{
+impl HasTextRange for ArrayPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-impl Display for ElseBlockNode {
+impl Display for ArrayPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ForStmtNode {
+pub struct ArrayRestPatternNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for ForStmtNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ForStmt }
+impl AstNode for ArrayRestPatternNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayRestPattern }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3445,25 +4792,18 @@ impl AstNode for ForStmtNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for ForStmtNode{
+impl HasSyntaxKind for ArrayRestPatternNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ForStmtNode{
+impl HasTextRange for ArrayRestPatternNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-
-use lu_parser::grammar::ForStmtRule;
-impl HasRule for ForStmtNode{
-    fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(ForStmtRule{})
-    }
-}
-impl Display for ForStmtNode {
+impl Display for ArrayRestPatternNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
@@ -3547,6 +4887,45 @@ impl Display for PipedCmdsStmtNode {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct BackgroundStmtNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for BackgroundStmtNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::BackgroundStmt }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for BackgroundStmtNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for BackgroundStmtNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::BackgroundStmtRule;
+impl HasRule for BackgroundStmtNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(BackgroundStmtRule{})
+    }
+}
+impl Display for BackgroundStmtNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct BlockStmtNode {
     pub(crate) syntax: SyntaxNode,
@@ -3925,21 +5304,163 @@ impl HasTextRange for ValuePathExprNode{
 use lu_parser::grammar::ValuePathExprRule;
 impl HasRule for ValuePathExprNode{
     fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(ValuePathExprRule{})
+        Box::new(ValuePathExprRule{})
+    }
+}
+impl Display for ValuePathExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UnaryExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for UnaryExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::UnaryExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for UnaryExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for UnaryExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for UnaryExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct RangeExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for RangeExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::RangeExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for RangeExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for RangeExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for RangeExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ArrayExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for ArrayExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for ArrayExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for ArrayExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::ArrayExprRule;
+impl HasRule for ArrayExprNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(ArrayExprRule{})
+    }
+}
+impl Display for ArrayExprNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct MapExprNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstNode for MapExprNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MapExpr }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl HasSyntaxKind for MapExprNode{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        self.syntax().kind()
+    }
+}
+impl HasTextRange for MapExprNode{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+
+use lu_parser::grammar::MapExprRule;
+impl HasRule for MapExprNode{
+    fn get_belonging_rule() -> Box<dyn Rule>{
+        Box::new(MapExprRule{})
     }
 }
-impl Display for ValuePathExprNode {
+impl Display for MapExprNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct ArrayExprNode {
+pub struct MapEntryNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for ArrayExprNode {
-    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::ArrayExpr }
+impl AstNode for MapEntryNode {
+    fn can_cast(kind: SyntaxKind) -> bool { kind == SyntaxKind::MapEntry }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3949,25 +5470,18 @@ impl AstNode for ArrayExprNode {
     }
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl HasSyntaxKind for ArrayExprNode{
+impl HasSyntaxKind for MapEntryNode{
     fn get_syntax_kind(&self) -> SyntaxKind{
         self.syntax().kind()
     }
 }
-impl HasTextRange for ArrayExprNode{
+impl HasTextRange for MapEntryNode{
     fn get_text_range(&self) -> TextRange{
         self.syntax().text_range()
     }
 }
 
-
-use lu_parser::grammar::ArrayExprRule;
-impl HasRule for ArrayExprNode{
-    fn get_belonging_rule() -> Box<dyn Rule>{
-        Box::new(ArrayExprRule{})
-    }
-}
-impl Display for ArrayExprNode {
+impl Display for MapEntryNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.text())
     }
@@ -4083,11 +5597,16 @@ pub enum ValueExprElement {
     NumberExpr(NumberExprNode),
     BooleanExpr(BooleanExprNode),
     MathExpr(MathExprNode),
+    UnaryExpr(UnaryExprNode),
+    RangeExpr(RangeExprNode),
+    ParentherizedExpr(ParentherizedExprNode),
     StringExpr(StringExprNode),
     ValuePathExpr(ValuePathExprNode),
     StrctCtorExpr(StrctCtorExprNode),
+    EnumCtorExpr(EnumCtorExprNode),
     OptionalExpr(OptionalExprNode),
     ArrayExpr(ArrayExprNode),
+    MapExpr(MapExprNode),
     TableExpr(TableExprNode),
     CmdStmt(CmdStmtNode),
     FileName(FileNameElement),
@@ -4111,11 +5630,16 @@ impl AstElement for ValueExprElement {
         
         
         
+        
+        
+        
+        
+        
         FileNameElement::can_cast(kind) ||
         
         
         match kind{
-            BareWord | ClosureExpr | NumberExpr | BooleanExpr | MathExpr | StringExpr | ValuePathExpr | StrctCtorExpr | OptionalExpr | ArrayExpr | TableExpr | CmdStmt | FileName => true,
+            BareWord | ClosureExpr | NumberExpr | BooleanExpr | MathExpr | UnaryExpr | RangeExpr | ParentherizedExpr | StringExpr | ValuePathExpr | StrctCtorExpr | EnumCtorExpr | OptionalExpr | ArrayExpr | MapExpr | TableExpr | CmdStmt | FileName => true,
             _ => false,
         }
     }
@@ -4132,6 +5656,11 @@ impl AstElement for ValueExprElement {
         
         
         
+        
+        
+        
+        
+        
         if let Some(casted) = FileNameElement::cast(syntax.clone()){
                 return Some(Self::FileName(casted));
             }
@@ -4143,11 +5672,16 @@ impl AstElement for ValueExprElement {
             NumberExpr => ValueExprElement::NumberExpr(NumberExprNode { syntax: syntax.into_node().unwrap() }),
             BooleanExpr => ValueExprElement::BooleanExpr(BooleanExprNode { syntax: syntax.into_node().unwrap() }),
             MathExpr => ValueExprElement::MathExpr(MathExprNode { syntax: syntax.into_node().unwrap() }),
+            UnaryExpr => ValueExprElement::UnaryExpr(UnaryExprNode { syntax: syntax.into_node().unwrap() }),
+            RangeExpr => ValueExprElement::RangeExpr(RangeExprNode { syntax: syntax.into_node().unwrap() }),
+            ParentherizedExpr => ValueExprElement::ParentherizedExpr(ParentherizedExprNode { syntax: syntax.into_node().unwrap() }),
             StringExpr => ValueExprElement::StringExpr(StringExprNode { syntax: syntax.into_node().unwrap() }),
             ValuePathExpr => ValueExprElement::ValuePathExpr(ValuePathExprNode { syntax: syntax.into_node().unwrap() }),
             StrctCtorExpr => ValueExprElement::StrctCtorExpr(StrctCtorExprNode { syntax: syntax.into_node().unwrap() }),
+            EnumCtorExpr => ValueExprElement::EnumCtorExpr(EnumCtorExprNode { syntax: syntax.into_node().unwrap() }),
             OptionalExpr => ValueExprElement::OptionalExpr(OptionalExprNode { syntax: syntax.into_node().unwrap() }),
             ArrayExpr => ValueExprElement::ArrayExpr(ArrayExprNode { syntax: syntax.into_node().unwrap() }),
+            MapExpr => ValueExprElement::MapExpr(MapExprNode { syntax: syntax.into_node().unwrap() }),
             TableExpr => ValueExprElement::TableExpr(TableExprNode { syntax: syntax.into_node().unwrap() }),
             CmdStmt => ValueExprElement::CmdStmt(CmdStmtNode { syntax: syntax.into_node().unwrap() }),
             _ => return None,
@@ -4173,6 +5707,15 @@ impl AstElement for ValueExprElement {
             ValueExprElement::MathExpr(it) => it.syntax.clone().into(),
             
             
+            ValueExprElement::UnaryExpr(it) => it.syntax.clone().into(),
+            
+            
+            ValueExprElement::RangeExpr(it) => it.syntax.clone().into(),
+            
+            
+            ValueExprElement::ParentherizedExpr(it) => it.syntax.clone().into(),
+            
+            
             ValueExprElement::StringExpr(it) => it.syntax.clone().into(),
             
             
@@ -4182,12 +5725,18 @@ impl AstElement for ValueExprElement {
             ValueExprElement::StrctCtorExpr(it) => it.syntax.clone().into(),
             
             
+            ValueExprElement::EnumCtorExpr(it) => it.syntax.clone().into(),
+            
+            
             ValueExprElement::OptionalExpr(it) => it.syntax.clone().into(),
             
             
             ValueExprElement::ArrayExpr(it) => it.syntax.clone().into(),
             
             
+            ValueExprElement::MapExpr(it) => it.syntax.clone().into(),
+            
+            
             ValueExprElement::TableExpr(it) => it.syntax.clone().into(),
             
             
@@ -4207,11 +5756,16 @@ impl HasSyntaxKind for ValueExprElement{
             ValueExprElement::NumberExpr(it) => it.get_syntax_kind(),
             ValueExprElement::BooleanExpr(it) => it.get_syntax_kind(),
             ValueExprElement::MathExpr(it) => it.get_syntax_kind(),
+            ValueExprElement::UnaryExpr(it) => it.get_syntax_kind(),
+            ValueExprElement::RangeExpr(it) => it.get_syntax_kind(),
+            ValueExprElement::ParentherizedExpr(it) => it.get_syntax_kind(),
             ValueExprElement::StringExpr(it) => it.get_syntax_kind(),
             ValueExprElement::ValuePathExpr(it) => it.get_syntax_kind(),
             ValueExprElement::StrctCtorExpr(it) => it.get_syntax_kind(),
+            ValueExprElement::EnumCtorExpr(it) => it.get_syntax_kind(),
             ValueExprElement::OptionalExpr(it) => it.get_syntax_kind(),
             ValueExprElement::ArrayExpr(it) => it.get_syntax_kind(),
+            ValueExprElement::MapExpr(it) => it.get_syntax_kind(),
             ValueExprElement::TableExpr(it) => it.get_syntax_kind(),
             ValueExprElement::CmdStmt(it) => it.get_syntax_kind(),
             ValueExprElement::FileName(it) => it.get_syntax_kind(),
@@ -4242,11 +5796,18 @@ impl Display for ValueExprElement {
 pub enum StatementElement {
     RetStmt(RetStmtNode),
     ForStmt(ForStmtNode),
+    WhileStmt(WhileStmtNode),
+    BreakStmt(BreakStmtNode),
+    ContinueStmt(ContinueStmtNode),
+    TryStmt(TryStmtNode),
+    MatchStmt(MatchStmtNode),
     LetStmt(LetStmtNode),
     FnStmt(FnStmtNode),
+    ImplStmt(ImplStmtNode),
     IfElifElseStmt(IfElifElseStmtNode),
     CmdStmt(CmdStmtNode),
     PipedCmdsStmt(PipedCmdsStmtNode),
+    BackgroundStmt(BackgroundStmtNode),
     ValueExpr(ValueExprElement),
     }
 
@@ -4263,11 +5824,18 @@ impl AstElement for StatementElement {
         
         
         
+        
+        
+        
+        
+        
+        
+        
         ValueExprElement::can_cast(kind) ||
         
         
         match kind{
-            RetStmt | ForStmt | LetStmt | FnStmt | IfElifElseStmt | CmdStmt | PipedCmdsStmt | ValueExpr => true,
+            RetStmt | ForStmt | WhileStmt | BreakStmt | ContinueStmt | TryStmt | MatchStmt | LetStmt | FnStmt | ImplStmt | IfElifElseStmt | CmdStmt | PipedCmdsStmt | BackgroundStmt | ValueExpr => true,
             _ => false,
         }
     }
@@ -4279,6 +5847,13 @@ impl AstElement for StatementElement {
         
         
         
+        
+        
+        
+        
+        
+        
+        
         if let Some(casted) = ValueExprElement::cast(syntax.clone()){
                 return Some(Self::ValueExpr(casted));
             }
@@ -4287,11 +5862,18 @@ impl AstElement for StatementElement {
         let res = match syntax.kind() {
             RetStmt => StatementElement::RetStmt(RetStmtNode { syntax: syntax.into_node().unwrap() }),
             ForStmt => StatementElement::ForStmt(ForStmtNode { syntax: syntax.into_node().unwrap() }),
+            WhileStmt => StatementElement::WhileStmt(WhileStmtNode { syntax: syntax.into_node().unwrap() }),
+            BreakStmt => StatementElement::BreakStmt(BreakStmtNode { syntax: syntax.into_node().unwrap() }),
+            ContinueStmt => StatementElement::ContinueStmt(ContinueStmtNode { syntax: syntax.into_node().unwrap() }),
+            TryStmt => StatementElement::TryStmt(TryStmtNode { syntax: syntax.into_node().unwrap() }),
+            MatchStmt => StatementElement::MatchStmt(MatchStmtNode { syntax: syntax.into_node().unwrap() }),
             LetStmt => StatementElement::LetStmt(LetStmtNode { syntax: syntax.into_node().unwrap() }),
             FnStmt => StatementElement::FnStmt(FnStmtNode { syntax: syntax.into_node().unwrap() }),
+            ImplStmt => StatementElement::ImplStmt(ImplStmtNode { syntax: syntax.into_node().unwrap() }),
             IfElifElseStmt => StatementElement::IfElifElseStmt(IfElifElseStmtNode { syntax: syntax.into_node().unwrap() }),
             CmdStmt => StatementElement::CmdStmt(CmdStmtNode { syntax: syntax.into_node().unwrap() }),
             PipedCmdsStmt => StatementElement::PipedCmdsStmt(PipedCmdsStmtNode { syntax: syntax.into_node().unwrap() }),
+            BackgroundStmt => StatementElement::BackgroundStmt(BackgroundStmtNode { syntax: syntax.into_node().unwrap() }),
             _ => return None,
         };
         Some(res)
@@ -4306,12 +5888,30 @@ impl AstElement for StatementElement {
             StatementElement::ForStmt(it) => it.syntax.clone().into(),
             
             
+            StatementElement::WhileStmt(it) => it.syntax.clone().into(),
+            
+            
+            StatementElement::BreakStmt(it) => it.syntax.clone().into(),
+            
+            
+            StatementElement::ContinueStmt(it) => it.syntax.clone().into(),
+            
+            
+            StatementElement::TryStmt(it) => it.syntax.clone().into(),
+            
+            
+            StatementElement::MatchStmt(it) => it.syntax.clone().into(),
+            
+            
             StatementElement::LetStmt(it) => it.syntax.clone().into(),
             
             
             StatementElement::FnStmt(it) => it.syntax.clone().into(),
             
             
+            StatementElement::ImplStmt(it) => it.syntax.clone().into(),
+            
+            
             StatementElement::IfElifElseStmt(it) => it.syntax.clone().into(),
             
             
@@ -4321,6 +5921,9 @@ impl AstElement for StatementElement {
             StatementElement::PipedCmdsStmt(it) => it.syntax.clone().into(),
             
             
+            StatementElement::BackgroundStmt(it) => it.syntax.clone().into(),
+            
+            
             StatementElement::ValueExpr(it) => it.syntax().clone().into(),
             
             }
@@ -4331,11 +5934,18 @@ impl HasSyntaxKind for StatementElement{
         match self {
             StatementElement::RetStmt(it) => it.get_syntax_kind(),
             StatementElement::ForStmt(it) => it.get_syntax_kind(),
+            StatementElement::WhileStmt(it) => it.get_syntax_kind(),
+            StatementElement::BreakStmt(it) => it.get_syntax_kind(),
+            StatementElement::ContinueStmt(it) => it.get_syntax_kind(),
+            StatementElement::TryStmt(it) => it.get_syntax_kind(),
+            StatementElement::MatchStmt(it) => it.get_syntax_kind(),
             StatementElement::LetStmt(it) => it.get_syntax_kind(),
             StatementElement::FnStmt(it) => it.get_syntax_kind(),
+            StatementElement::ImplStmt(it) => it.get_syntax_kind(),
             StatementElement::IfElifElseStmt(it) => it.get_syntax_kind(),
             StatementElement::CmdStmt(it) => it.get_syntax_kind(),
             StatementElement::PipedCmdsStmt(it) => it.get_syntax_kind(),
+            StatementElement::BackgroundStmt(it) => it.get_syntax_kind(),
             StatementElement::ValueExpr(it) => it.get_syntax_kind(),
             }
     }
@@ -4437,6 +6047,8 @@ pub enum OperatorExprElement {
     MinusSign(MinusSignToken),
     MultSign(MultSignToken),
     DivSign(DivSignToken),
+    ModSign(ModSignToken),
+    PowSign(PowSignToken),
     LessThanSign(LessThanSignToken),
     LessOrEqualSign(LessOrEqualSignToken),
     EqualitySign(EqualitySignToken),
@@ -4473,9 +6085,11 @@ impl AstElement for OperatorExprElement {
         
         
         
+        
+        
         
         match kind{
-            AsKeyword | AndKeyword | OrKeyword | PlusSign | MinusSign | MultSign | DivSign | LessThanSign | LessOrEqualSign | EqualitySign | InequalitySign | BiggerThanSign | BiggerOrEqualSign | DivAssignSign | MulAssignSign | AddAssignSign | MinAssignSign | AssignSign => true,
+            AsKeyword | AndKeyword | OrKeyword | PlusSign | MinusSign | MultSign | DivSign | ModSign | PowSign | LessThanSign | LessOrEqualSign | EqualitySign | InequalitySign | BiggerThanSign | BiggerOrEqualSign | DivAssignSign | MulAssignSign | AddAssignSign | MinAssignSign | AssignSign => true,
             _ => false,
         }
     }
@@ -4498,6 +6112,8 @@ impl AstElement for OperatorExprElement {
         
         
         
+        
+        
         
         let res = match syntax.kind() {
             AsKeyword => OperatorExprElement::AsKeyword(AsKeywordToken { syntax: syntax.into_token().unwrap() }),
@@ -4507,6 +6123,8 @@ impl AstElement for OperatorExprElement {
             MinusSign => OperatorExprElement::MinusSign(MinusSignToken { syntax: syntax.into_token().unwrap() }),
             MultSign => OperatorExprElement::MultSign(MultSignToken { syntax: syntax.into_token().unwrap() }),
             DivSign => OperatorExprElement::DivSign(DivSignToken { syntax: syntax.into_token().unwrap() }),
+            ModSign => OperatorExprElement::ModSign(ModSignToken { syntax: syntax.into_token().unwrap() }),
+            PowSign => OperatorExprElement::PowSign(PowSignToken { syntax: syntax.into_token().unwrap() }),
             LessThanSign => OperatorExprElement::LessThanSign(LessThanSignToken { syntax: syntax.into_token().unwrap() }),
             LessOrEqualSign => OperatorExprElement::LessOrEqualSign(LessOrEqualSignToken { syntax: syntax.into_token().unwrap() }),
             EqualitySign => OperatorExprElement::EqualitySign(EqualitySignToken { syntax: syntax.into_token().unwrap() }),
@@ -4547,6 +6165,12 @@ impl AstElement for OperatorExprElement {
             OperatorExprElement::DivSign(it) => it.syntax.clone().into(),
             
             
+            OperatorExprElement::ModSign(it) => it.syntax.clone().into(),
+            
+            
+            OperatorExprElement::PowSign(it) => it.syntax.clone().into(),
+            
+            
             OperatorExprElement::LessThanSign(it) => it.syntax.clone().into(),
             
             
@@ -4592,6 +6216,8 @@ impl HasSyntaxKind for OperatorExprElement{
             OperatorExprElement::MinusSign(it) => it.get_syntax_kind(),
             OperatorExprElement::MultSign(it) => it.get_syntax_kind(),
             OperatorExprElement::DivSign(it) => it.get_syntax_kind(),
+            OperatorExprElement::ModSign(it) => it.get_syntax_kind(),
+            OperatorExprElement::PowSign(it) => it.get_syntax_kind(),
             OperatorExprElement::LessThanSign(it) => it.get_syntax_kind(),
             OperatorExprElement::LessOrEqualSign(it) => it.get_syntax_kind(),
             OperatorExprElement::EqualitySign(it) => it.get_syntax_kind(),
@@ -4619,6 +6245,69 @@ impl Display for OperatorExprElement {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
+pub enum UnaryOperatorElement {
+    MinusSign(MinusSignToken),
+    NotKeyword(NotKeywordToken),
+    }
+
+impl UnaryOperatorElement {
+}
+
+impl AstElement for UnaryOperatorElement {
+    fn can_cast(kind: SyntaxKind) -> bool { 
+        
+        
+        
+        match kind{
+            MinusSign | NotKeyword => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxElement) -> Option<Self> {
+        
+        
+        
+        let res = match syntax.kind() {
+            MinusSign => UnaryOperatorElement::MinusSign(MinusSignToken { syntax: syntax.into_token().unwrap() }),
+            NotKeyword => UnaryOperatorElement::NotKeyword(NotKeywordToken { syntax: syntax.into_token().unwrap() }),
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    fn syntax(&self) -> SyntaxElement {
+        match self {
+            
+            UnaryOperatorElement::MinusSign(it) => it.syntax.clone().into(),
+            
+            
+            UnaryOperatorElement::NotKeyword(it) => it.syntax.clone().into(),
+            
+            }
+    }
+}
+impl HasSyntaxKind for UnaryOperatorElement{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        match self {
+            UnaryOperatorElement::MinusSign(it) => it.get_syntax_kind(),
+            UnaryOperatorElement::NotKeyword(it) => it.get_syntax_kind(),
+            }
+    }
+}
+
+impl HasTextRange for UnaryOperatorElement{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for UnaryOperatorElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
 pub enum LuTypeSpecifierElement {
     NumberKeyword(NumberKeywordToken),
@@ -4627,10 +6316,12 @@ pub enum LuTypeSpecifierElement {
     PathKeyword(PathKeywordToken),
     BoolKeyword(BoolKeywordToken),
     StringKeyword(StringKeywordToken),
+    RangeKeyword(RangeKeywordToken),
     GenericType(GenericTypeToken),
     BareWord(BareWordToken),
     StrctName(StrctNameToken),
     ArrayType(ArrayTypeNode),
+    MapType(MapTypeNode),
     FnType(FnTypeNode),
     }
 
@@ -4651,8 +6342,10 @@ impl AstElement for LuTypeSpecifierElement {
         
         
         
+        
+        
         match kind{
-            NumberKeyword | AnyKeyword | NilKeyword | PathKeyword | BoolKeyword | StringKeyword | GenericType | BareWord | StrctName | ArrayType | FnType => true,
+            NumberKeyword | AnyKeyword | NilKeyword | PathKeyword | BoolKeyword | StringKeyword | RangeKeyword | GenericType | BareWord | StrctName | ArrayType | MapType | FnType => true,
             _ => false,
         }
     }
@@ -4669,6 +6362,8 @@ impl AstElement for LuTypeSpecifierElement {
         
         
         
+        
+        
         let res = match syntax.kind() {
             NumberKeyword => LuTypeSpecifierElement::NumberKeyword(NumberKeywordToken { syntax: syntax.into_token().unwrap() }),
             AnyKeyword => LuTypeSpecifierElement::AnyKeyword(AnyKeywordToken { syntax: syntax.into_token().unwrap() }),
@@ -4676,10 +6371,12 @@ impl AstElement for LuTypeSpecifierElement {
             PathKeyword => LuTypeSpecifierElement::PathKeyword(PathKeywordToken { syntax: syntax.into_token().unwrap() }),
             BoolKeyword => LuTypeSpecifierElement::BoolKeyword(BoolKeywordToken { syntax: syntax.into_token().unwrap() }),
             StringKeyword => LuTypeSpecifierElement::StringKeyword(StringKeywordToken { syntax: syntax.into_token().unwrap() }),
+            RangeKeyword => LuTypeSpecifierElement::RangeKeyword(RangeKeywordToken { syntax: syntax.into_token().unwrap() }),
             GenericType => LuTypeSpecifierElement::GenericType(GenericTypeToken { syntax: syntax.into_token().unwrap() }),
             BareWord => LuTypeSpecifierElement::BareWord(BareWordToken { syntax: syntax.into_token().unwrap() }),
             StrctName => LuTypeSpecifierElement::StrctName(StrctNameToken { syntax: syntax.into_token().unwrap() }),
             ArrayType => LuTypeSpecifierElement::ArrayType(ArrayTypeNode { syntax: syntax.into_node().unwrap() }),
+            MapType => LuTypeSpecifierElement::MapType(MapTypeNode { syntax: syntax.into_node().unwrap() }),
             FnType => LuTypeSpecifierElement::FnType(FnTypeNode { syntax: syntax.into_node().unwrap() }),
             _ => return None,
         };
@@ -4707,6 +6404,9 @@ impl AstElement for LuTypeSpecifierElement {
             LuTypeSpecifierElement::StringKeyword(it) => it.syntax.clone().into(),
             
             
+            LuTypeSpecifierElement::RangeKeyword(it) => it.syntax.clone().into(),
+            
+            
             LuTypeSpecifierElement::GenericType(it) => it.syntax.clone().into(),
             
             
@@ -4719,6 +6419,9 @@ impl AstElement for LuTypeSpecifierElement {
             LuTypeSpecifierElement::ArrayType(it) => it.syntax.clone().into(),
             
             
+            LuTypeSpecifierElement::MapType(it) => it.syntax.clone().into(),
+            
+            
             LuTypeSpecifierElement::FnType(it) => it.syntax.clone().into(),
             
             }
@@ -4733,10 +6436,12 @@ impl HasSyntaxKind for LuTypeSpecifierElement{
             LuTypeSpecifierElement::PathKeyword(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::BoolKeyword(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::StringKeyword(it) => it.get_syntax_kind(),
+            LuTypeSpecifierElement::RangeKeyword(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::GenericType(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::BareWord(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::StrctName(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::ArrayType(it) => it.get_syntax_kind(),
+            LuTypeSpecifierElement::MapType(it) => it.get_syntax_kind(),
             LuTypeSpecifierElement::FnType(it) => it.get_syntax_kind(),
             }
     }
@@ -4758,6 +6463,7 @@ impl Display for LuTypeSpecifierElement {
 pub enum CmdArgElement {
     ShortFlag(ShortFlagToken),
     LongFlag(LongFlagToken),
+    DoubleMinus(DoubleMinusToken),
     ValueExpr(ValueExprElement),
     }
 
@@ -4769,17 +6475,19 @@ impl AstElement for CmdArgElement {
         
         
         
+        
         ValueExprElement::can_cast(kind) ||
         
         
         match kind{
-            ShortFlag | LongFlag | ValueExpr => true,
+            ShortFlag | LongFlag | DoubleMinus | ValueExpr => true,
             _ => false,
         }
     }
     fn cast(syntax: SyntaxElement) -> Option<Self> {
         
         
+        
         if let Some(casted) = ValueExprElement::cast(syntax.clone()){
                 return Some(Self::ValueExpr(casted));
             }
@@ -4788,6 +6496,7 @@ impl AstElement for CmdArgElement {
         let res = match syntax.kind() {
             ShortFlag => CmdArgElement::ShortFlag(ShortFlagToken { syntax: syntax.into_token().unwrap() }),
             LongFlag => CmdArgElement::LongFlag(LongFlagToken { syntax: syntax.into_token().unwrap() }),
+            DoubleMinus => CmdArgElement::DoubleMinus(DoubleMinusToken { syntax: syntax.into_token().unwrap() }),
             _ => return None,
         };
         Some(res)
@@ -4802,6 +6511,9 @@ impl AstElement for CmdArgElement {
             CmdArgElement::LongFlag(it) => it.syntax.clone().into(),
             
             
+            CmdArgElement::DoubleMinus(it) => it.syntax.clone().into(),
+            
+            
             CmdArgElement::ValueExpr(it) => it.syntax().clone().into(),
             
             }
@@ -4812,6 +6524,7 @@ impl HasSyntaxKind for CmdArgElement{
         match self {
             CmdArgElement::ShortFlag(it) => it.get_syntax_kind(),
             CmdArgElement::LongFlag(it) => it.get_syntax_kind(),
+            CmdArgElement::DoubleMinus(it) => it.get_syntax_kind(),
             CmdArgElement::ValueExpr(it) => it.get_syntax_kind(),
             }
     }
@@ -4895,7 +6608,7 @@ impl Display for FlagElement {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
 pub enum RedirToValueElement {
     ValuePathExpr(ValuePathExprNode),
-    BareWord(BareWordToken),
+    FileName(FileNameElement),
     }
 
 impl RedirToValueElement {
@@ -4905,19 +6618,23 @@ impl AstElement for RedirToValueElement {
     fn can_cast(kind: SyntaxKind) -> bool { 
         
         
+        FileNameElement::can_cast(kind) ||
+        
         
         match kind{
-            ValuePathExpr | BareWord => true,
+            ValuePathExpr | FileName => true,
             _ => false,
         }
     }
     fn cast(syntax: SyntaxElement) -> Option<Self> {
         
+        if let Some(casted) = FileNameElement::cast(syntax.clone()){
+                return Some(Self::FileName(casted));
+            }
         
         
         let res = match syntax.kind() {
             ValuePathExpr => RedirToValueElement::ValuePathExpr(ValuePathExprNode { syntax: syntax.into_node().unwrap() }),
-            BareWord => RedirToValueElement::BareWord(BareWordToken { syntax: syntax.into_token().unwrap() }),
             _ => return None,
         };
         Some(res)
@@ -4929,7 +6646,7 @@ impl AstElement for RedirToValueElement {
             RedirToValueElement::ValuePathExpr(it) => it.syntax.clone().into(),
             
             
-            RedirToValueElement::BareWord(it) => it.syntax.clone().into(),
+            RedirToValueElement::FileName(it) => it.syntax().clone().into(),
             
             }
     }
@@ -4938,7 +6655,7 @@ impl HasSyntaxKind for RedirToValueElement{
     fn get_syntax_kind(&self) -> SyntaxKind{
         match self {
             RedirToValueElement::ValuePathExpr(it) => it.get_syntax_kind(),
-            RedirToValueElement::BareWord(it) => it.get_syntax_kind(),
+            RedirToValueElement::FileName(it) => it.get_syntax_kind(),
             }
     }
 }
@@ -5184,6 +6901,172 @@ impl Display for IfElifElseStmtPartElement {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
+pub enum MatchPatternElement {
+    LiteralPattern(LiteralPatternNode),
+    BindingPattern(BindingPatternNode),
+    NonePattern(NonePatternNode),
+    SomePattern(SomePatternNode),
+    StrctPattern(StrctPatternNode),
+    EnumPattern(EnumPatternNode),
+    }
+
+impl MatchPatternElement {
+}
+
+impl AstElement for MatchPatternElement {
+    fn can_cast(kind: SyntaxKind) -> bool { 
+        
+        
+        
+        
+        
+        
+        
+        match kind{
+            LiteralPattern | BindingPattern | NonePattern | SomePattern | StrctPattern | EnumPattern => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxElement) -> Option<Self> {
+        
+        
+        
+        
+        
+        
+        
+        let res = match syntax.kind() {
+            LiteralPattern => MatchPatternElement::LiteralPattern(LiteralPatternNode { syntax: syntax.into_node().unwrap() }),
+            BindingPattern => MatchPatternElement::BindingPattern(BindingPatternNode { syntax: syntax.into_node().unwrap() }),
+            NonePattern => MatchPatternElement::NonePattern(NonePatternNode { syntax: syntax.into_node().unwrap() }),
+            SomePattern => MatchPatternElement::SomePattern(SomePatternNode { syntax: syntax.into_node().unwrap() }),
+            StrctPattern => MatchPatternElement::StrctPattern(StrctPatternNode { syntax: syntax.into_node().unwrap() }),
+            EnumPattern => MatchPatternElement::EnumPattern(EnumPatternNode { syntax: syntax.into_node().unwrap() }),
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    fn syntax(&self) -> SyntaxElement {
+        match self {
+            
+            MatchPatternElement::LiteralPattern(it) => it.syntax.clone().into(),
+            
+            
+            MatchPatternElement::BindingPattern(it) => it.syntax.clone().into(),
+            
+            
+            MatchPatternElement::NonePattern(it) => it.syntax.clone().into(),
+            
+            
+            MatchPatternElement::SomePattern(it) => it.syntax.clone().into(),
+            
+            
+            MatchPatternElement::StrctPattern(it) => it.syntax.clone().into(),
+            
+            
+            MatchPatternElement::EnumPattern(it) => it.syntax.clone().into(),
+            
+            }
+    }
+}
+impl HasSyntaxKind for MatchPatternElement{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        match self {
+            MatchPatternElement::LiteralPattern(it) => it.get_syntax_kind(),
+            MatchPatternElement::BindingPattern(it) => it.get_syntax_kind(),
+            MatchPatternElement::NonePattern(it) => it.get_syntax_kind(),
+            MatchPatternElement::SomePattern(it) => it.get_syntax_kind(),
+            MatchPatternElement::StrctPattern(it) => it.get_syntax_kind(),
+            MatchPatternElement::EnumPattern(it) => it.get_syntax_kind(),
+            }
+    }
+}
+
+impl HasTextRange for MatchPatternElement{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for MatchPatternElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
+pub enum StringExprPartElement {
+    StringContent(StringContentToken),
+    ValuePathExpr(ValuePathExprNode),
+    ParentherizedExpr(ParentherizedExprNode),
+    }
+
+impl StringExprPartElement {
+}
+
+impl AstElement for StringExprPartElement {
+    fn can_cast(kind: SyntaxKind) -> bool { 
+        
+        
+        
+        
+        match kind{
+            StringContent | ValuePathExpr | ParentherizedExpr => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxElement) -> Option<Self> {
+        
+        
+        
+        
+        let res = match syntax.kind() {
+            StringContent => StringExprPartElement::StringContent(StringContentToken { syntax: syntax.into_token().unwrap() }),
+            ValuePathExpr => StringExprPartElement::ValuePathExpr(ValuePathExprNode { syntax: syntax.into_node().unwrap() }),
+            ParentherizedExpr => StringExprPartElement::ParentherizedExpr(ParentherizedExprNode { syntax: syntax.into_node().unwrap() }),
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    fn syntax(&self) -> SyntaxElement {
+        match self {
+            
+            StringExprPartElement::StringContent(it) => it.syntax.clone().into(),
+            
+            
+            StringExprPartElement::ValuePathExpr(it) => it.syntax.clone().into(),
+            
+            
+            StringExprPartElement::ParentherizedExpr(it) => it.syntax.clone().into(),
+            
+            }
+    }
+}
+impl HasSyntaxKind for StringExprPartElement{
+    fn get_syntax_kind(&self) -> SyntaxKind{
+        match self {
+            StringExprPartElement::StringContent(it) => it.get_syntax_kind(),
+            StringExprPartElement::ValuePathExpr(it) => it.get_syntax_kind(),
+            StringExprPartElement::ParentherizedExpr(it) => it.get_syntax_kind(),
+            }
+    }
+}
+
+impl HasTextRange for StringExprPartElement{
+    fn get_text_range(&self) -> TextRange{
+        self.syntax().text_range()
+    }
+}
+
+impl Display for StringExprPartElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, EnumAsInner)]
 pub enum PipeOrValueExprElement {
     PipedCmdsStmt(PipedCmdsStmtNode),
@@ -0,0 +1,20 @@
+use crate::{AstNode, AstNodeChildren};
+
+use super::{support, FnStmtNode, ImplStmtNode, StrctNameToken};
+
+/// The name under which the method of a strct is called (`Ip.to_string`)
+pub fn method_cmd_name(strct_name: &str, method_name: &str) -> String {
+    format!("{}.{}", strct_name, method_name)
+}
+
+impl ImplStmtNode {
+    /// The name of the strct the methods are implemented for
+    pub fn strct_name(&self) -> Option<StrctNameToken> {
+        support::token_child(self.syntax())
+    }
+
+    /// The methods of the strct
+    pub fn fn_stmts(&self) -> AstNodeChildren<FnStmtNode> {
+        support::node_children(self.syntax())
+    }
+}
@@ -1,11 +1,11 @@
 use lu_error::SourceCodeItem;
 use rowan::TextRange;
 
-use crate::{AstNode, AstToken};
+use crate::{AstElement, AstNode, AstToken};
 
 use super::{
-    addr_of_mod_node_contained_in, support, LetKeywordToken, LetStmtNode, LuTypeNode,
-    PipeOrValueExprElement, VarDeclNameToken,
+    addr_of_mod_node_contained_in, support, ArrayPatternNode, LetKeywordToken, LetStmtNode,
+    LuTypeNode, MatchPatternElement, PipeOrValueExprElement, VarDeclNameToken,
 };
 
 impl LetStmtNode {
@@ -17,6 +17,16 @@ impl LetStmtNode {
         support::token_child(self.syntax())
     }
 
+    /// The pattern of a destructuring let with a struct (`let Ip{a b} = ...`)
+    pub fn pattern(&self) -> Option<MatchPatternElement> {
+        support::element_child(self.syntax())
+    }
+
+    /// The pattern of a destructuring let with an array (`let [first ...rest] = ...`)
+    pub fn array_pattern(&self) -> Option<ArrayPatternNode> {
+        support::node_child(self.syntax())
+    }
+
     /// Returns the rhs of the assignment
     pub fn value(&self) -> Option<PipeOrValueExprElement> {
         support::element_child(self.syntax())
@@ -38,6 +48,10 @@ impl LetStmtNode {
             ty.syntax().text_range()
         } else if let Some(name) = self.var_token() {
             name.syntax().text_range()
+        } else if let Some(pattern) = self.pattern() {
+            pattern.syntax().text_range()
+        } else if let Some(pattern) = self.array_pattern() {
+            pattern.syntax().text_range()
         } else {
             start.clone()
         };
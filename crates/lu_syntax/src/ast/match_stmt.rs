@@ -0,0 +1,131 @@
+use lu_error::SourceCodeItem;
+use rowan::TextRange;
+
+use crate::{AstElement, AstElementChildren, AstNode, AstNodeChildren, AstToken};
+
+use super::{
+    addr_of_mod_node_contained_in, support, ArrayPatternNode, ArrayRestPatternNode,
+    BindingPatternNode, BlockStmtNode, LiteralPatternNode, MatchArmNode, MatchKeywordToken,
+    MatchPatternElement, MatchStmtNode, SomePatternNode, StrctFieldNameToken,
+    StrctFieldPatternNode, StrctNameToken, StrctPatternNode, ValueExprElement, VarDeclNameToken,
+};
+
+/// Name of the binding pattern, which matches everything without binding the value
+pub const WILDCARD_PATTERN: &str = "_";
+
+impl MatchStmtNode {
+    /// From match till end of the matched value
+    pub fn decl_item(&self) -> SourceCodeItem {
+        let start = support::token_child::<MatchKeywordToken>(self.syntax())
+            .unwrap()
+            .syntax()
+            .text_range()
+            .start();
+        let end = self
+            .matched_value()
+            .map(|val| val.syntax().text_range().end())
+            .unwrap_or_else(|| self.syntax().text_range().end());
+        let text_range = TextRange::new(start, end);
+
+        SourceCodeItem::new(
+            text_range.into(),
+            self.text_at(&text_range).to_string(),
+            addr_of_mod_node_contained_in(self.syntax().clone()),
+        )
+    }
+
+    /// The value matched against the arms
+    pub fn matched_value(&self) -> Option<ValueExprElement> {
+        support::element_child(self.syntax())
+    }
+    pub fn arms(&self) -> AstNodeChildren<MatchArmNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl MatchArmNode {
+    pub fn pattern(&self) -> Option<MatchPatternElement> {
+        support::element_child(self.syntax())
+    }
+    pub fn block(&self) -> Option<BlockStmtNode> {
+        support::node_child(self.syntax())
+    }
+}
+
+impl MatchPatternElement {
+    /// Returns whether the pattern matches every value (of the matched ty)
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            MatchPatternElement::BindingPattern(_) => true,
+            // Ip{a b: _} always matches an Ip
+            MatchPatternElement::StrctPattern(n) => n.fields().all(|field| {
+                field
+                    .pattern()
+                    .is_none_or(|pattern| pattern.is_irrefutable())
+            }),
+            _ => false,
+        }
+    }
+}
+
+impl LiteralPatternNode {
+    pub fn value(&self) -> Option<ValueExprElement> {
+        support::element_child(self.syntax())
+    }
+}
+
+impl BindingPatternNode {
+    pub fn var_name(&self) -> Option<VarDeclNameToken> {
+        support::token_child(self.syntax())
+    }
+    /// `_` matches everything, but doesn't bind the value
+    pub fn is_wildcard(&self) -> bool {
+        self.var_name()
+            .map(|name| name.text() == WILDCARD_PATTERN)
+            .unwrap_or(true)
+    }
+}
+
+impl SomePatternNode {
+    /// The pattern the inner value of the optional is matched against
+    pub fn inner_pattern(&self) -> Option<MatchPatternElement> {
+        support::element_child(self.syntax())
+    }
+}
+
+impl StrctPatternNode {
+    pub fn name(&self) -> Option<StrctNameToken> {
+        support::token_child(self.syntax())
+    }
+    pub fn fields(&self) -> AstNodeChildren<StrctFieldPatternNode> {
+        support::node_children(self.syntax())
+    }
+}
+
+impl StrctFieldPatternNode {
+    pub fn field_name(&self) -> StrctFieldNameToken {
+        support::token_child(self.syntax()).expect("Always Some")
+    }
+    /// The pattern the field is matched against. If None, the field is bound to a var with the
+    /// name of the field
+    pub fn pattern(&self) -> Option<MatchPatternElement> {
+        support::element_child(self.syntax())
+    }
+}
+
+impl ArrayPatternNode {
+    /// The patterns of the leading elements (without the rest)
+    pub fn elems(&self) -> AstElementChildren<MatchPatternElement> {
+        support::element_children(self.syntax())
+    }
+    /// `...rest` binding all remaining elements
+    pub fn rest(&self) -> Option<ArrayRestPatternNode> {
+        support::node_child(self.syntax())
+    }
+}
+
+impl ArrayRestPatternNode {
+    pub fn var_name(&self) -> Option<VarDeclNameToken> {
+        support::token_child(self.syntax())
+    }
+}
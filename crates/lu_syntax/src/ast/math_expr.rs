@@ -3,7 +3,7 @@ use crate::{AstElement, AstNode, AstToken};
 
 use super::{
     support, BlockStmtNode, ConditionElement, ElifKeywordToken, LuTypeNode, MathExprNode,
-    OperatorExprElement, ValueExprElement,
+    OperatorExprElement, UnaryExprNode, UnaryOperatorElement, ValueExprElement,
 };
 
 impl MathExprNode {
@@ -20,17 +20,19 @@ impl MathExprNode {
         support::element_children(self.syntax()).skip(1).next()
     }
 
-    // TODO remove this, use rhs_safe
-    pub fn rhs(&self) -> ValueExprElement {
-        // TODO CHECK Is this always working?
-        support::element_children(self.syntax())
-            .skip(1)
-            .next()
-            .unwrap()
+    pub fn operator(&self) -> OperatorExprElement {
+        // Always some by parsing
+        support::element_child(self.syntax()).unwrap()
     }
+}
 
-    pub fn operator(&self) -> OperatorExprElement {
+impl UnaryExprNode {
+    pub fn operator(&self) -> UnaryOperatorElement {
         // Always some by parsing
         support::element_child(self.syntax()).unwrap()
     }
+
+    pub fn operand(&self) -> Option<ValueExprElement> {
+        support::element_child(self.syntax())
+    }
 }
@@ -1,15 +1,17 @@
-use crate::{AstNode, AstNodeChildren, AstToken};
-use lu_syntax_elements::constants::{IN_ARG_NAME, RET_ARG_NAME};
+use crate::{AstNode, AstNodeChildren, AstToken, SyntaxKind};
+use lu_syntax_elements::constants::{IN_ARG_NAME, RET_ARG_NAME, SELF_ARG_NAME};
 
 use super::{
     support, ArgNameToken, ArgSignatureNode, FlagSignatureNode, LongFlagToken, LuTypeNode,
-    OptModifierToken, ReqKeywordToken, ShortFlagToken, SignatureNode, VarArgNameToken,
+    OptModifierToken, ReqKeywordToken, ShortFlagToken, SignatureNode, ValueExprElement,
+    VarArgNameToken,
 };
 
 impl SignatureNode {
+    /// The in arg. Methods name it `self`
     pub fn in_arg(&self) -> Option<ArgSignatureNode> {
         support::node_children::<ArgSignatureNode>(self.syntax())
-            .filter(|n| n.name() == IN_ARG_NAME)
+            .filter(|n| self.is_in_arg_name(&n.name()))
             .next()
     }
     pub fn ret_arg(&self) -> Option<ArgSignatureNode> {
@@ -21,7 +23,7 @@ impl SignatureNode {
         support::node_children::<ArgSignatureNode>(self.syntax())
             .filter(|n| {
                 let name = n.name();
-                !n.is_var_arg() && name != RET_ARG_NAME && name != IN_ARG_NAME
+                !n.is_var_arg() && name != RET_ARG_NAME && !self.is_in_arg_name(&name)
             })
             .collect()
     }
@@ -35,6 +37,19 @@ impl SignatureNode {
     pub fn flags(&self) -> AstNodeChildren<FlagSignatureNode> {
         support::node_children::<FlagSignatureNode>(self.syntax())
     }
+
+    fn is_in_arg_name(&self, name: &str) -> bool {
+        name == IN_ARG_NAME || (name == SELF_ARG_NAME && self.is_method_signature())
+    }
+
+    /// Whether this is the signature of a fn in an impl block
+    fn is_method_signature(&self) -> bool {
+        self.syntax()
+            .parent()
+            .filter(|fn_stmt| fn_stmt.kind() == SyntaxKind::FnStmt)
+            .and_then(|fn_stmt| fn_stmt.parent())
+            .is_some_and(|parent| parent.kind() == SyntaxKind::ImplStmt)
+    }
 }
 
 impl ArgSignatureNode {
@@ -58,6 +73,11 @@ impl ArgSignatureNode {
     pub fn type_(&self) -> Option<LuTypeNode> {
         support::node_child::<LuTypeNode>(self.syntax())
     }
+
+    /// The value of the arg, if not passed (`name: str = "world"`)
+    pub fn default_value(&self) -> Option<ValueExprElement> {
+        support::element_child(self.syntax())
+    }
 }
 
 impl FlagSignatureNode {
@@ -74,20 +94,35 @@ impl FlagSignatureNode {
     pub fn is_required(&self) -> bool {
         support::token_child::<ReqKeywordToken>(self.syntax()).is_some()
     }
+
+    /// The value of the flag, if not passed (`--times: num = 1`)
+    pub fn default_value(&self) -> Option<ValueExprElement> {
+        support::element_child(self.syntax())
+    }
 }
 
 impl ShortFlagToken {
+    /// The name of a short flag in a signature. Those always consist of a single char
     pub fn flag_name(&self) -> char {
-        assert!(
-            self.text().len() == 2,
-            "TODO handle short flags containing multiple flags"
-        );
         self.text().chars().nth(1).unwrap()
     }
+
+    /// The names of all passed short flags. Multiple short flags may be combined (`-la` is the
+    /// same as `-l -a`)
+    pub fn flag_names(&self) -> Vec<char> {
+        self.text().chars().skip(1).collect()
+    }
 }
 
 impl LongFlagToken {
     pub fn flag_name(&self) -> String {
         self.text()[2..].to_string()
     }
+
+    /// Whether the value of the flag is passed as `--flag=value`
+    pub fn has_assigned_value(&self) -> bool {
+        self.syntax()
+            .next_sibling_or_token()
+            .is_some_and(|next| next.kind() == SyntaxKind::AssignSign)
+    }
 }
@@ -0,0 +1,25 @@
+use crate::AstNode;
+
+use super::{support, BlockStmtNode, CatchStmtNode, TryStmtNode, VarDeclNameToken};
+
+impl TryStmtNode {
+    pub fn try_block(&self) -> Option<BlockStmtNode> {
+        support::node_child(self.syntax())
+    }
+    pub fn catch_stmt(&self) -> Option<CatchStmtNode> {
+        support::node_child(self.syntax())
+    }
+}
+
+impl CatchStmtNode {
+    /// The variable holding the caught error
+    /// Example:
+    /// catch err ...
+    /// returns err
+    pub fn var_name(&self) -> Option<VarDeclNameToken> {
+        support::token_child(self.syntax())
+    }
+    pub fn block(&self) -> Option<BlockStmtNode> {
+        support::node_child(self.syntax())
+    }
+}
@@ -2,8 +2,8 @@
 use crate::{AstNode, AstToken};
 
 use super::{
-    support, ArrayTypeNode, FnTypeNode, LuTypeNode, LuTypeSpecifierElement, OptModifierToken,
-    SignatureNode,
+    support, ArrayTypeNode, FnTypeNode, LuTypeNode, LuTypeSpecifierElement, MapTypeNode,
+    OptModifierToken, SignatureNode,
 };
 
 impl LuTypeNode {
@@ -21,6 +21,13 @@ impl ArrayTypeNode {
     }
 }
 
+impl MapTypeNode {
+    /// The ty of the values of the map
+    pub fn inner_type(&self) -> Option<LuTypeNode> {
+        support::node_child(self.syntax())
+    }
+}
+
 impl FnTypeNode {
     pub fn signature(&self) -> Option<SignatureNode> {
         support::node_child(self.syntax())
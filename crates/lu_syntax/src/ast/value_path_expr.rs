@@ -1,9 +1,21 @@
 use lu_error::SourceCodeItem;
+use rowan::{TextRange, TextSize};
 
-use crate::{AstNode, AstToken};
+use crate::{ast::addr_of_mod_node_contained_in, AstNode, AstToken, SyntaxKind};
 
 use super::{support, BareWordToken, ValuePathExprNode};
 
+/// A part of a value path following the var name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValuePathPart {
+    /// `$strct.field`
+    Field(String),
+    /// `$arr.0` or `$arr.-1` (counted from the end)
+    Index(i64),
+    /// `$arr.1..3` or `$arr.1..` (until the end). The end is exclusive
+    Slice { start: i64, end: Option<i64> },
+}
+
 impl ValuePathExprNode {
     /// Returns: $part1.part2.part3 => [part1, part2, part3]
     pub fn var_name_parts(&self) -> Vec<String> {
@@ -19,4 +31,104 @@ impl ValuePathExprNode {
             .map(|n| (n.text().to_string(), n.to_item()))
             .collect()
     }
+
+    /// The name of the var, the path starts with
+    pub fn var_name_with_item(&self) -> (String, SourceCodeItem) {
+        self.var_name_parts_with_item()
+            .into_iter()
+            .next()
+            .expect("ValuePath always starts with a var name")
+    }
+
+    /// Returns all parts after the var name
+    /// $var.field.0.1..3 => [Field(field), Index(0), Slice{1, Some(3)}]
+    pub fn parts_with_item(&self) -> Vec<(ValuePathPart, SourceCodeItem)> {
+        let sf_addr = addr_of_mod_node_contained_in(self.syntax().clone());
+        let item_of =
+            |range: TextRange, text: &str| SourceCodeItem::new(range.into(), text, sf_addr);
+
+        let mut parts: Vec<(ValuePathPart, SourceCodeItem)> = Vec::new();
+        let mut seen_var_name = false;
+        let mut prev_was_point = false;
+        let mut in_slice = false;
+        for token in self
+            .syntax()
+            .children_with_tokens()
+            .filter_map(|elem| elem.into_token())
+        {
+            match token.kind() {
+                SyntaxKind::BareWord if !seen_var_name => seen_var_name = true,
+                SyntaxKind::BareWord => {
+                    parts.push((
+                        ValuePathPart::Field(token.text().to_string()),
+                        item_of(token.text_range(), token.text()),
+                    ));
+                }
+                SyntaxKind::Point => {
+                    // Two adjacent points start a slice
+                    in_slice = prev_was_point;
+                    prev_was_point = !prev_was_point;
+                    continue;
+                }
+                SyntaxKind::Number if in_slice => {
+                    let (start_part, start_item) = parts.pop().expect("Slice has a start");
+                    let start = match start_part {
+                        ValuePathPart::Index(start) => start,
+                        _ => unreachable!("Slice always starts with a number"),
+                    };
+                    let range = TextRange::new(start_item.range.start(), token.text_range().end());
+                    let text = self.text_at(&range).to_string();
+                    parts.push((
+                        ValuePathPart::Slice {
+                            start,
+                            end: Some(parse_index(token.text())),
+                        },
+                        item_of(range, &text),
+                    ));
+                    in_slice = false;
+                }
+                SyntaxKind::Number => {
+                    // $arr.0.1 is lexed as $arr and the number 0.1. Every index is separated
+                    // here
+                    let mut offset = token.text_range().start();
+                    for index in token.text().split('.') {
+                        let range = TextRange::at(offset, TextSize::of(index));
+                        parts.push((
+                            ValuePathPart::Index(parse_index(index)),
+                            item_of(range, index),
+                        ));
+                        offset = range.end() + TextSize::of('.');
+                    }
+                }
+                _ => {}
+            }
+            prev_was_point = false;
+        }
+
+        if in_slice {
+            // $arr.1.. is a slice till the end
+            let (start_part, start_item) = parts.pop().expect("Slice has a start");
+            if let ValuePathPart::Index(start) = start_part {
+                let range =
+                    TextRange::new(start_item.range.start(), self.syntax().text_range().end());
+                let text = self.text_at(&range).to_string();
+                parts.push((
+                    ValuePathPart::Slice { start, end: None },
+                    item_of(range, &text),
+                ));
+            }
+        }
+
+        parts
+    }
+}
+
+/// Parses an index of a value path. Indices not fitting into an i64 are saturated, as they are
+/// out of bounds of every array and get reported as such when indexing
+fn parse_index(text: &str) -> i64 {
+    text.parse().unwrap_or(if text.starts_with('-') {
+        i64::MIN
+    } else {
+        i64::MAX
+    })
 }
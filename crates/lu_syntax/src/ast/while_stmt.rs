@@ -0,0 +1,42 @@
+use rowan::TextRange;
+
+use crate::{AstElement, AstNode, AstToken};
+
+use super::{
+    support, BlockStmtNode, BreakKeywordToken, BreakStmtNode, ConditionElement,
+    ContinueKeywordToken, ContinueStmtNode, WhileKeywordToken, WhileStmtNode,
+};
+
+impl WhileStmtNode {
+    pub fn condition(&self) -> Option<ConditionElement> {
+        support::element_child(self.syntax())
+    }
+    pub fn block(&self) -> Option<BlockStmtNode> {
+        support::node_child(self.syntax())
+    }
+
+    pub fn text_till_block(&self) -> String {
+        let start = support::token_child::<WhileKeywordToken>(self.syntax())
+            .unwrap()
+            .syntax()
+            .text_range();
+        let end = self
+            .condition()
+            .map(|cond| cond.syntax().text_range())
+            .unwrap_or(start);
+        let range = TextRange::new(start.start(), end.end());
+        self.text_at(&range).to_string()
+    }
+}
+
+impl BreakStmtNode {
+    pub fn break_kw(&self) -> BreakKeywordToken {
+        support::token_child(self.syntax()).unwrap()
+    }
+}
+
+impl ContinueStmtNode {
+    pub fn continue_kw(&self) -> ContinueKeywordToken {
+        support::token_child(self.syntax()).unwrap()
+    }
+}
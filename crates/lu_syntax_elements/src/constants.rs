@@ -11,3 +11,6 @@ pub const VAR_ARG_START: &str = "...";
 
 // TODO this should prob go into different crate?
 pub const VAR_ARGS_DEF_NAME: &str = "args";
+
+/// Name of the in arg of methods (`fn to_string (self: Ip)`)
+pub const SELF_ARG_NAME: &str = "self";
@@ -10,7 +10,11 @@ use pretty_env_logger::env_logger;
 
 use lu_cmds::builtin;
 use lu_interpreter::InterpreterCfg;
-use lu_interpreter_structs::{ScopeFrame, ScopeFrameTag, Value, Variable};
+use lu_interpreter_structs::{
+    init_err_strct,
+    special_scope_vars::{init_job_table, init_status_var},
+    ScopeFrame, ScopeFrameTag, Value, Variable,
+};
 pub use temp_file::TempFile as TmpFile;
 
 pub fn init_logger() {
@@ -46,6 +50,9 @@ fn make_test_global_frame(pwd: String) -> ScopeFrame<Variable> {
         Value::FileName(pwd.clone()),
         lu_source_code_item!().into(),
     ));
+    init_status_var(&mut frame);
+    init_err_strct(&mut frame);
+    init_job_table(&mut frame);
     std::env::set_var("PWD", pwd.clone());
     std::env::set_current_dir(pwd).expect("Must work");
     frame
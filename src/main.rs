@@ -68,7 +68,9 @@ fn ret_code_main() -> i32 {
             if let Err(e) = lu_error_reporting::report_to_term(&errs, &scope) {
                 eprintln!("Ups: An error happend, while printing errors: {}", e)
             }
-            return 1;
+            if errs.iter().any(|e| !e.is_warning()) {
+                return 1;
+            }
         }
         let mut scope = new_amtx(scope);
         match Interpreter::eval(&mut scope) {
@@ -102,6 +104,10 @@ fn make_global_frame() -> ScopeFrame<Variable> {
         frame.insert_var(Variable::new(key, val, lu_source_code_item!().into()));
     }
 
+    init_status_var(&mut frame);
+    init_err_strct(&mut frame);
+    init_job_table(&mut frame);
+
     // insert builtin cmds
     for cmd in builtin::all_builtin_cmds() {
         frame.insert_var(Variable::new_func(cmd));
@@ -0,0 +1,22 @@
+use lu_test_support::*;
+
+#[test]
+fn caught_error_has_file_line_and_column() {
+    let tmp_file_path = make_tmp_file(
+        r#"
+try
+    let x = 1
+    error "boom"
+catch err
+    echo $err.line $err.column
+    echo ($err.file == "")
+end
+"#
+        .as_bytes(),
+    );
+    let (code, cout, cerr) =
+        binary::run_binary(&[&tmp_file_path.path().to_string_lossy().to_string()]);
+    assert_eq!(code, 0);
+    assert_eq!(cerr, "");
+    assert_eq!(cout, "4 5\nfalse\n");
+}
@@ -45,3 +45,18 @@ fn external_cmd_not_printing_when_is_rhs_of_let() {
     assert_eq!(cerr, "");
     assert_eq!(cout, "");
 }
+
+#[test]
+fn external_cmd_writes_directly_to_stdout_when_output_unused() {
+    let tmp_file_path = make_tmp_file(
+        r#"
+        printf "no newline"
+        "#
+        .as_bytes(),
+    );
+    let (code, cout, cerr) =
+        binary::run_binary(&[&tmp_file_path.path().to_string_lossy().to_string()]);
+    assert_eq!(code, 0);
+    assert_eq!(cerr, "");
+    assert_eq!(cout, "no newline");
+}